@@ -0,0 +1,777 @@
+use crate::error::{AppError, AppResult};
+use crate::fs_utils::{FileLock, atomic_write, create_private_dir_all};
+use crate::{t, warn};
+use chrono::{DateTime, Duration, Local, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// 备份ID中的时间戳格式（精确到毫秒）
+const TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%S_%3f";
+/// 旧版备份ID的时间戳格式（精确到秒）
+const LEGACY_TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%S";
+
+/// 同一时间戳下尝试的备份文件名数量上限
+const MAX_NAME_ATTEMPTS: u32 = 1000;
+
+/// 备份条目
+#[derive(Debug, Clone)]
+pub struct BackupEntry {
+    /// 备份ID（文件名去掉前缀和扩展名）
+    pub id: String,
+    pub path: PathBuf,
+    pub created_at: Option<DateTime<Utc>>,
+    pub size: u64,
+}
+
+impl BackupEntry {
+    /// 格式化的本地创建时间
+    pub fn created_display(&self) -> String {
+        self.created_at
+            .map(|t| {
+                t.with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_else(|| t!("backup.unknown_time"))
+    }
+}
+
+/// 备份保留策略
+///
+/// 最新的一份备份总是保留；其余备份只要满足任一保留条件即保留，
+/// 最后再按总大小上限从最旧的备份开始删除。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionPolicy {
+    /// 保留最近的 N 份备份
+    pub keep_last: usize,
+    /// 保留最近 M 天内每天最新的一份备份
+    pub keep_daily_days: u32,
+    /// 备份总大小上限（字节），None 表示不限制
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_total_size: Option<u64>,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            keep_last: 20,
+            keep_daily_days: 7,
+            max_total_size: None,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// 计算需要删除的备份，`entries` 需按时间从新到旧排序
+    pub fn select_expired(&self, entries: &[BackupEntry], now: DateTime<Utc>) -> Vec<BackupEntry> {
+        let mut keep = vec![false; entries.len()];
+
+        for (index, flag) in keep.iter_mut().enumerate() {
+            // 最新的备份总是保留
+            *flag = index == 0 || index < self.keep_last;
+        }
+
+        if self.keep_daily_days > 0 {
+            let window = Duration::days(i64::from(self.keep_daily_days));
+            let mut seen_days = HashSet::new();
+            for (index, entry) in entries.iter().enumerate() {
+                if let Some(created_at) = entry.created_at
+                    && now.signed_duration_since(created_at) < window
+                    && seen_days.insert(created_at.with_timezone(&Local).date_naive())
+                {
+                    keep[index] = true;
+                }
+            }
+        }
+
+        if let Some(max_total_size) = self.max_total_size {
+            let mut total: u64 = entries
+                .iter()
+                .zip(&keep)
+                .filter(|(_, kept)| **kept)
+                .map(|(entry, _)| entry.size)
+                .sum();
+            // 从最旧的开始删除，直到总大小不超过上限
+            for index in (1..entries.len()).rev() {
+                if total <= max_total_size {
+                    break;
+                }
+                if keep[index] {
+                    keep[index] = false;
+                    total -= entries[index].size;
+                }
+            }
+        }
+
+        entries
+            .iter()
+            .zip(keep)
+            .filter(|(_, kept)| !kept)
+            .map(|(entry, _)| entry.clone())
+            .collect()
+    }
+}
+
+/// 解析大小字符串，支持 B/KB/MB/GB 后缀（不区分大小写），例如 `512KB`、`10MB`
+pub fn parse_size(input: &str) -> AppResult<u64> {
+    let trimmed = input.trim();
+    let upper = trimmed.to_ascii_uppercase();
+    let (number, unit) = match upper.find(|c: char| !c.is_ascii_digit()) {
+        Some(index) => upper.split_at(index),
+        None => (upper.as_str(), ""),
+    };
+
+    let multiplier: u64 = match unit.trim() {
+        "" | "B" => 1,
+        "K" | "KB" => 1024,
+        "M" | "MB" => 1024 * 1024,
+        "G" | "GB" => 1024 * 1024 * 1024,
+        _ => {
+            return Err(AppError::InvalidConfig(t!(
+                "backup.invalid_size",
+                size = trimmed
+            )));
+        }
+    };
+
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .ok_or_else(|| AppError::InvalidConfig(t!("backup.invalid_size", size = trimmed)))
+}
+
+/// 配置文件备份目录管理
+///
+/// 备份文件命名为 `<prefix>_<时间戳>.json`，例如 `config_backup_20250101_120000_123.json`，
+/// 同一毫秒内的多次备份会追加序号后缀
+pub struct BackupStore {
+    dir: PathBuf,
+    prefix: &'static str,
+    retention: RetentionPolicy,
+}
+
+impl BackupStore {
+    pub fn new(dir: PathBuf, prefix: &'static str) -> Self {
+        Self {
+            dir,
+            prefix,
+            retention: RetentionPolicy::default(),
+        }
+    }
+
+    /// 设置备份保留策略
+    pub fn with_retention(mut self, retention: RetentionPolicy) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// 备份目录路径
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 为源文件创建一份备份
+    ///
+    /// 源文件内容与最新备份完全相同时跳过，返回 None
+    pub fn create(&self, source: &Path) -> AppResult<Option<BackupEntry>> {
+        if !self.dir.exists() {
+            create_private_dir_all(&self.dir)?;
+        }
+
+        let content = fs::read(source)?;
+        if let Some(latest) = self.list()?.first()
+            && fs::read(&latest.path)? == content
+        {
+            return Ok(None);
+        }
+
+        let timestamp = Utc::now().format(TIMESTAMP_FORMAT).to_string();
+        let (path, mut file) = self.create_unique(&timestamp)?;
+        if let Err(e) = file.write_all(&content).and_then(|_| file.sync_all()) {
+            let _ = fs::remove_file(&path);
+            return Err(e.into());
+        }
+
+        self.entry_for(&path)
+            .map(Some)
+            .ok_or_else(|| AppError::Config(t!("backup.invalid_file", path = path.display())))
+    }
+
+    /// 以独占方式创建备份文件，名称冲突时追加序号
+    ///
+    /// 备份包含配置中的明文密钥，Unix 上仅当前用户可读写（0600）
+    fn create_unique(&self, timestamp: &str) -> AppResult<(PathBuf, File)> {
+        for seq in 0..MAX_NAME_ATTEMPTS {
+            let id = if seq == 0 {
+                timestamp.to_string()
+            } else {
+                format!("{timestamp}_{seq}")
+            };
+            let path = self.dir.join(self.file_name(&id));
+            let mut options = OpenOptions::new();
+            options.write(true).create_new(true);
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::OpenOptionsExt;
+                options.mode(0o600);
+            }
+            match options.open(&path) {
+                Ok(file) => return Ok((path, file)),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Err(AppError::Config(t!(
+            "backup.name_exhausted",
+            timestamp = timestamp,
+            dir = self.dir.display()
+        )))
+    }
+
+    /// 按保留策略清理过期备份，返回被删除的备份
+    pub fn prune(&self) -> AppResult<Vec<BackupEntry>> {
+        let expired = self.plan_prune()?;
+        for entry in &expired {
+            fs::remove_file(&entry.path)?;
+        }
+        Ok(expired)
+    }
+
+    /// 计算按保留策略将被清理的备份，不做删除
+    pub fn plan_prune(&self) -> AppResult<Vec<BackupEntry>> {
+        Ok(self.retention.select_expired(&self.list()?, Utc::now()))
+    }
+
+    /// 列出所有备份（按时间从新到旧排序）
+    pub fn list(&self) -> AppResult<Vec<BackupEntry>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries: Vec<BackupEntry> = fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| self.entry_for(&entry.path()))
+            .collect();
+
+        // 按创建时间倒序，时间相同时按ID倒序（序号后缀更大的更新）
+        entries.sort_by(|a, b| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| b.id.len().cmp(&a.id.len()))
+                .then_with(|| b.id.cmp(&a.id))
+        });
+        Ok(entries)
+    }
+
+    /// 查找备份，支持备份ID、完整文件名或 `latest`
+    pub fn find(&self, id: &str) -> AppResult<BackupEntry> {
+        let entries = self.list()?;
+
+        if id == "latest" {
+            return entries
+                .into_iter()
+                .next()
+                .ok_or_else(|| AppError::Config(t!("backup.none")));
+        }
+
+        let id = id.strip_prefix(&format!("{}_", self.prefix)).unwrap_or(id);
+        let id = id.strip_suffix(".json").unwrap_or(id);
+
+        entries
+            .into_iter()
+            .find(|entry| entry.id == id)
+            .ok_or_else(|| AppError::Config(t!("backup.not_found", id)))
+    }
+
+    /// 读取备份内容
+    pub fn read(&self, entry: &BackupEntry) -> AppResult<String> {
+        Ok(fs::read_to_string(&entry.path)?)
+    }
+
+    fn file_name(&self, id: &str) -> String {
+        format!("{}_{id}.json", self.prefix)
+    }
+
+    /// 解析备份文件路径，非本存储的文件返回 None
+    fn entry_for(&self, path: &Path) -> Option<BackupEntry> {
+        let file_name = path.file_name()?.to_str()?;
+        let id = file_name
+            .strip_prefix(self.prefix)?
+            .strip_prefix('_')?
+            .strip_suffix(".json")?;
+
+        let metadata = fs::metadata(path).ok()?;
+        if !metadata.is_file() {
+            return None;
+        }
+
+        let created_at = parse_timestamp(id)
+            .map(|naive| Utc.from_utc_datetime(&naive))
+            .or_else(|| metadata.modified().ok().map(DateTime::<Utc>::from));
+
+        Some(BackupEntry {
+            id: id.to_string(),
+            path: path.to_path_buf(),
+            created_at,
+            size: metadata.len(),
+        })
+    }
+}
+
+/// 支持备份、恢复与撤销的配置文件
+pub trait BackupTarget {
+    /// 显示名称，例如 "CCR 配置"
+    fn label(&self) -> String;
+
+    /// 配置文件路径
+    fn config_path(&self) -> &Path;
+
+    /// 配置文件对应的备份存储
+    fn backup_store(&self) -> &BackupStore;
+
+    /// 验证待恢复的配置内容
+    fn validate_content(&self, content: &str) -> AppResult<()>;
+
+    /// 获取配置文件锁
+    fn lock(&self) -> AppResult<FileLock> {
+        FileLock::acquire(self.config_path())
+    }
+
+    /// 备份当前配置文件并按保留策略清理过期备份
+    ///
+    /// 配置文件不存在或与最新备份相同时返回 None
+    fn backup_current(&self) -> AppResult<Option<BackupEntry>> {
+        if !self.config_path().exists() {
+            return Ok(None);
+        }
+
+        let entry = self.backup_store().create(self.config_path())?;
+        // 清理失败不影响本次写入
+        if entry.is_some()
+            && let Err(e) = self.backup_store().prune()
+        {
+            warn!("{}", t!("backup.prune_failed", e));
+        }
+        Ok(entry)
+    }
+
+    /// 按保留策略清理过期备份，`dry_run` 时只返回将被清理的备份
+    fn prune_backups(&self, dry_run: bool) -> AppResult<Vec<BackupEntry>> {
+        if dry_run {
+            self.backup_store().plan_prune()
+        } else {
+            self.backup_store().prune()
+        }
+    }
+
+    /// 列出所有备份（按时间从新到旧排序）
+    fn list_backups(&self) -> AppResult<Vec<BackupEntry>> {
+        self.backup_store().list()
+    }
+
+    /// 查找备份，支持备份ID、完整文件名或 `latest`
+    fn find_backup(&self, id: &str) -> AppResult<BackupEntry> {
+        self.backup_store().find(id)
+    }
+
+    /// 读取备份内容
+    fn read_backup(&self, entry: &BackupEntry) -> AppResult<String> {
+        self.backup_store().read(entry)
+    }
+
+    /// 备份目录路径
+    fn backup_dir(&self) -> &Path {
+        self.backup_store().dir()
+    }
+
+    /// 读取当前配置文件的原始内容
+    fn read_current(&self) -> AppResult<String> {
+        Ok(fs::read_to_string(self.config_path())?)
+    }
+
+    /// 验证备份内容是否为有效的配置
+    fn validate_backup(&self, entry: &BackupEntry) -> AppResult<()> {
+        self.validate_content(&self.read_backup(entry)?)
+            .map_err(|e| AppError::InvalidConfig(t!("backup.invalid", id = entry.id, e)))
+    }
+
+    /// 从备份恢复配置文件
+    /// 恢复前会验证备份内容，并为当前配置创建备份
+    fn restore_backup(&self, id: &str) -> AppResult<BackupEntry> {
+        let _lock = self.lock()?;
+        let entry = self.find_backup(id)?;
+        let content = self.read_backup(&entry)?;
+        self.validate_backup(&entry)?;
+
+        self.backup_current()?;
+        // 原样写回备份内容，保留其中的全部字段与格式
+        atomic_write(self.config_path(), content.as_bytes())?;
+        Ok(entry)
+    }
+
+    /// 删除内容满足条件的备份，返回被删除的备份
+    ///
+    /// 无法解析为 JSON 的备份会被跳过
+    fn remove_backups_where(
+        &self,
+        matches: &dyn Fn(&Value) -> bool,
+    ) -> AppResult<Vec<BackupEntry>> {
+        let _lock = self.lock()?;
+        let mut removed = Vec::new();
+        for entry in self.list_backups()? {
+            let Ok(value) = serde_json::from_str::<Value>(&self.read_backup(&entry)?) else {
+                continue;
+            };
+            if matches(&value) {
+                fs::remove_file(&entry.path)?;
+                removed.push(entry);
+            }
+        }
+        Ok(removed)
+    }
+
+    /// 撤销最近一次修改：恢复最新的备份并将其从备份列表中移除
+    fn undo(&self) -> AppResult<BackupEntry> {
+        let _lock = self.lock()?;
+        let entry = self.find_backup("latest")?;
+        let content = self.read_backup(&entry)?;
+        self.validate_backup(&entry)?;
+
+        atomic_write(self.config_path(), content.as_bytes())?;
+        fs::remove_file(&entry.path)?;
+        Ok(entry)
+    }
+}
+
+/// 从备份ID中解析时间戳，兼容旧版精确到秒的格式
+fn parse_timestamp(id: &str) -> Option<NaiveDateTime> {
+    id.get(..19)
+        .and_then(|ts| NaiveDateTime::parse_from_str(ts, TIMESTAMP_FORMAT).ok())
+        .or_else(|| {
+            id.get(..15)
+                .and_then(|ts| NaiveDateTime::parse_from_str(ts, LEGACY_TIMESTAMP_FORMAT).ok())
+        })
+}
+
+/// JSON 差异项
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonChange {
+    Added {
+        path: String,
+        value: Value,
+    },
+    Removed {
+        path: String,
+        value: Value,
+    },
+    Changed {
+        path: String,
+        old: Value,
+        new: Value,
+    },
+}
+
+/// 计算两个 JSON 文档之间的结构化差异
+///
+/// 元素均为带 `name` 字段对象的数组（如 Providers）按名称匹配，其余数组按下标比较。
+pub fn diff_json(old: &Value, new: &Value) -> Vec<JsonChange> {
+    let mut changes = Vec::new();
+    diff_value("", old, new, &mut changes);
+    changes
+}
+
+fn diff_value(path: &str, old: &Value, new: &Value, changes: &mut Vec<JsonChange>) {
+    match (old, new) {
+        (Value::Object(old_map), Value::Object(new_map)) => {
+            for (key, old_value) in old_map {
+                let child = join_key(path, key);
+                match new_map.get(key) {
+                    Some(new_value) => diff_value(&child, old_value, new_value, changes),
+                    None => changes.push(JsonChange::Removed {
+                        path: child,
+                        value: old_value.clone(),
+                    }),
+                }
+            }
+            for (key, new_value) in new_map {
+                if !old_map.contains_key(key) {
+                    changes.push(JsonChange::Added {
+                        path: join_key(path, key),
+                        value: new_value.clone(),
+                    });
+                }
+            }
+        }
+        (Value::Array(old_items), Value::Array(new_items))
+            if is_named_list(old_items) && is_named_list(new_items) =>
+        {
+            let name_of = |v: &Value| v["name"].as_str().unwrap_or_default().to_string();
+            for old_item in old_items {
+                let name = name_of(old_item);
+                let child = format!("{path}[{name}]");
+                match new_items.iter().find(|item| name_of(item) == name) {
+                    Some(new_item) => diff_value(&child, old_item, new_item, changes),
+                    None => changes.push(JsonChange::Removed {
+                        path: child,
+                        value: old_item.clone(),
+                    }),
+                }
+            }
+            for new_item in new_items {
+                let name = name_of(new_item);
+                if !old_items.iter().any(|item| name_of(item) == name) {
+                    changes.push(JsonChange::Added {
+                        path: format!("{path}[{name}]"),
+                        value: new_item.clone(),
+                    });
+                }
+            }
+        }
+        (Value::Array(old_items), Value::Array(new_items)) => {
+            let len = old_items.len().max(new_items.len());
+            for index in 0..len {
+                let child = format!("{path}[{index}]");
+                match (old_items.get(index), new_items.get(index)) {
+                    (Some(o), Some(n)) => diff_value(&child, o, n, changes),
+                    (Some(o), None) => changes.push(JsonChange::Removed {
+                        path: child,
+                        value: o.clone(),
+                    }),
+                    (None, Some(n)) => changes.push(JsonChange::Added {
+                        path: child,
+                        value: n.clone(),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        _ => {
+            if old != new {
+                changes.push(JsonChange::Changed {
+                    path: path.to_string(),
+                    old: old.clone(),
+                    new: new.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn join_key(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn is_named_list(items: &[Value]) -> bool {
+    !items.is_empty() && items.iter().all(|item| item["name"].is_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_backup_create_list_find() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("config.json");
+        fs::write(&source, "{}").unwrap();
+
+        let store = BackupStore::new(dir.path().join("backups"), "config_backup");
+        let entry = store.create(&source).unwrap().unwrap();
+
+        let entries = store.list().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, entry.id);
+        assert!(entries[0].created_at.is_some());
+
+        assert_eq!(store.find(&entry.id).unwrap().path, entry.path);
+        assert_eq!(store.find("latest").unwrap().path, entry.path);
+        let file_name = format!("config_backup_{}.json", entry.id);
+        assert_eq!(store.find(&file_name).unwrap().path, entry.path);
+        assert!(store.find("20000101_000000").is_err());
+
+        // 兼容旧版精确到秒的备份ID
+        assert!(parse_timestamp("20250101_120000_123_2").is_some());
+        assert!(parse_timestamp("20250101_120000").is_some());
+        assert!(parse_timestamp("manual").is_none());
+    }
+
+    #[test]
+    fn test_backup_dedup_and_unique_names() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("config.json");
+        let store = BackupStore::new(dir.path().join("backups"), "config_backup");
+
+        fs::write(&source, "{\"v\": 1}").unwrap();
+        let first = store.create(&source).unwrap().unwrap();
+        // 内容未变化时跳过备份
+        assert!(store.create(&source).unwrap().is_none());
+
+        // 快速连续的备份不会互相覆盖
+        let mut ids = vec![first.id];
+        for v in 2..6 {
+            fs::write(&source, format!("{{\"v\": {v}}}")).unwrap();
+            ids.push(store.create(&source).unwrap().unwrap().id);
+        }
+
+        let entries = store.list().unwrap();
+        assert_eq!(entries.len(), 5);
+        let listed: Vec<String> = entries.iter().rev().map(|e| e.id.clone()).collect();
+        assert_eq!(listed, ids);
+        assert_eq!(store.read(&entries[0]).unwrap(), "{\"v\": 5}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_backup_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("config.json");
+        fs::write(&source, "{\"api_key\": \"sk-secret\"}").unwrap();
+        fs::set_permissions(&source, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let store = BackupStore::new(dir.path().join("backups"), "config_backup");
+        let entry = store.create(&source).unwrap().unwrap();
+
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&entry.path), 0o600);
+        assert_eq!(mode(store.dir()), 0o700);
+    }
+
+    fn entry(id: &str, created_at: DateTime<Utc>, size: u64) -> BackupEntry {
+        BackupEntry {
+            id: id.to_string(),
+            path: PathBuf::from(id),
+            created_at: Some(created_at),
+            size,
+        }
+    }
+
+    fn ids(entries: &[BackupEntry]) -> Vec<&str> {
+        entries.iter().map(|e| e.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_retention_policy() {
+        let now = Utc.with_ymd_and_hms(2025, 6, 10, 12, 0, 0).unwrap();
+        let hours = |h: i64| now - Duration::hours(h);
+        let entries = vec![
+            entry("a", hours(1), 100),
+            entry("b", hours(2), 100),
+            entry("c", hours(3), 100),
+            entry("d", hours(30), 100),
+            entry("e", hours(31), 100),
+            entry("f", hours(24 * 10), 100),
+        ];
+
+        let policy = RetentionPolicy {
+            keep_last: 2,
+            keep_daily_days: 0,
+            max_total_size: None,
+        };
+        assert_eq!(
+            ids(&policy.select_expired(&entries, now)),
+            ["c", "d", "e", "f"]
+        );
+
+        // 每天保留最新的一份，超出天数窗口的不保留
+        let minutes = |m: i64| now - Duration::minutes(m);
+        let daily = vec![
+            entry("a", minutes(40), 100),
+            entry("b", minutes(40) - Duration::seconds(1), 100),
+            entry("c", minutes(24 * 60 + 40), 100),
+            entry("d", minutes(2 * 24 * 60 + 40), 100),
+            entry("e", minutes(10 * 24 * 60), 100),
+        ];
+        let policy = RetentionPolicy {
+            keep_last: 1,
+            keep_daily_days: 7,
+            max_total_size: None,
+        };
+        assert_eq!(ids(&policy.select_expired(&daily, now)), ["b", "e"]);
+
+        // 总大小上限从最旧的开始删除，但总保留最新的一份
+        let policy = RetentionPolicy {
+            keep_last: 10,
+            keep_daily_days: 0,
+            max_total_size: Some(250),
+        };
+        assert_eq!(
+            ids(&policy.select_expired(&entries, now)),
+            ["c", "d", "e", "f"]
+        );
+        let policy = RetentionPolicy {
+            keep_last: 0,
+            keep_daily_days: 0,
+            max_total_size: Some(0),
+        };
+        assert_eq!(policy.select_expired(&entries, now).len(), 5);
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("512").unwrap(), 512);
+        assert_eq!(parse_size("4kb").unwrap(), 4096);
+        assert_eq!(parse_size("10MB").unwrap(), 10 * 1024 * 1024);
+        assert_eq!(parse_size(" 1 G ").unwrap(), 1024 * 1024 * 1024);
+        assert!(parse_size("ten").is_err());
+        assert!(parse_size("5TB").is_err());
+    }
+
+    #[test]
+    fn test_diff_json() {
+        let old = json!({
+            "LOG": true,
+            "Providers": [
+                {"name": "a", "models": ["m1"]},
+                {"name": "b", "models": ["m2"]}
+            ],
+            "Router": {"default": "a,m1", "think": "b,m2"}
+        });
+        let new = json!({
+            "LOG": true,
+            "Providers": [
+                {"name": "b", "models": ["m2", "m3"]},
+                {"name": "c", "models": ["m4"]}
+            ],
+            "Router": {"default": "b,m2"}
+        });
+
+        let changes = diff_json(&old, &new);
+
+        assert!(changes.contains(&JsonChange::Removed {
+            path: "Providers[a]".to_string(),
+            value: json!({"name": "a", "models": ["m1"]}),
+        }));
+        assert!(changes.contains(&JsonChange::Added {
+            path: "Providers[b].models[1]".to_string(),
+            value: json!("m3"),
+        }));
+        assert!(changes.contains(&JsonChange::Added {
+            path: "Providers[c]".to_string(),
+            value: json!({"name": "c", "models": ["m4"]}),
+        }));
+        assert!(changes.contains(&JsonChange::Changed {
+            path: "Router.default".to_string(),
+            old: json!("a,m1"),
+            new: json!("b,m2"),
+        }));
+        assert!(changes.contains(&JsonChange::Removed {
+            path: "Router.think".to_string(),
+            value: json!("b,m2"),
+        }));
+        assert_eq!(changes.len(), 5);
+        assert!(diff_json(&old, &old).is_empty());
+    }
+}
@@ -1,8 +1,13 @@
-use crate::config::{CcrConfig, CcrProvider, CcrRouter, Config, RouterProfile};
+use crate::backup::{BackupStore, BackupTarget, RetentionPolicy};
+use crate::config::{
+    CcrConfig, CcrProvider, CcrRouter, Config, RouteIssue, RouteValidation, RouterProfile,
+};
 use crate::error::{AppError, AppResult};
-use chrono::Utc;
+use crate::fs_utils::{FileLock, atomic_write, create_private_dir_all};
+use crate::{t, verbose, warn};
+use serde::Serialize;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Provider操作类型枚举
 #[derive(Debug, Clone, PartialEq)]
@@ -15,10 +20,76 @@ pub enum ProviderOperation {
     Remove,
 }
 
+/// 删除 Provider 时对引用它的路由的处理方式
+#[derive(Debug, Clone)]
+pub enum ProviderRemoval {
+    /// 清空引用它的可选路由
+    Cascade,
+    /// 改为使用另一个 Provider（可指定模型，否则沿用同名模型或该 Provider 的第一个模型）
+    Reassign {
+        provider: String,
+        models: Vec<String>,
+        model: Option<String>,
+    },
+}
+
+impl ProviderRemoval {
+    /// 解析 `--reassign <provider[,model]>`，校验目标 Provider 与模型
+    pub fn reassign(target: &str, removed: &str, providers: &[CcrProvider]) -> AppResult<Self> {
+        let (provider, model) = match target.split_once(',') {
+            Some((provider, model)) => (provider.trim(), Some(model.trim().to_string())),
+            None => (target.trim(), None),
+        };
+        if provider == removed {
+            return Err(AppError::InvalidConfig(t!(
+                "provider_remove.reassign_self",
+                name = removed
+            )));
+        }
+        let target = providers
+            .iter()
+            .find(|p| p.name == provider)
+            .ok_or_else(|| AppError::Config(t!("provider.not_found", name = provider)))?;
+        if let Some(model) = &model
+            && !target.models.contains(model)
+        {
+            return Err(AppError::InvalidConfig(t!(
+                "provider_remove.unknown_model",
+                provider,
+                model
+            )));
+        }
+
+        Ok(ProviderRemoval::Reassign {
+            provider: target.name.clone(),
+            models: target.models.clone(),
+            model,
+        })
+    }
+
+    /// 处理单个 Router 中引用被删除 Provider 的路由，返回修改的路由数
+    pub fn apply(&self, router: &mut CcrRouter, removed: &str) -> usize {
+        match self {
+            ProviderRemoval::Cascade => router.clear_provider_routes(removed).len(),
+            ProviderRemoval::Reassign {
+                provider,
+                models,
+                model,
+            } => router
+                .reassign_provider(removed, provider, |original| match model {
+                    Some(model) => model.clone(),
+                    None if models.iter().any(|m| m == original) => original.to_string(),
+                    None => models.first().cloned().unwrap_or_default(),
+                })
+                .len(),
+        }
+    }
+}
+
 /// CCR 配置文件直接管理器
 pub struct CcrConfigManager {
     config_path: PathBuf,
-    backup_dir: PathBuf,
+    backups: BackupStore,
 }
 
 impl CcrConfigManager {
@@ -26,22 +97,36 @@ impl CcrConfigManager {
     pub fn new() -> AppResult<Self> {
         let config_path = Self::get_ccr_config_path()?;
         let backup_dir = Self::get_backup_dir()?;
+        // 保留策略来自 ccode 配置，读取失败时使用默认策略
+        let retention = Config::load()
+            .ok()
+            .and_then(|config| config.backup_retention)
+            .unwrap_or_default();
+
+        Ok(Self::with_paths(config_path, backup_dir)?.with_retention(retention))
+    }
 
-        // 确保备份目录存在
+    /// 使用指定的配置文件与备份目录创建管理器
+    pub fn with_paths(config_path: PathBuf, backup_dir: PathBuf) -> AppResult<Self> {
         if !backup_dir.exists() {
-            fs::create_dir_all(&backup_dir)?;
+            create_private_dir_all(&backup_dir)?;
         }
 
         Ok(Self {
             config_path,
-            backup_dir,
+            backups: BackupStore::new(backup_dir, "config_backup"),
         })
     }
 
+    /// 设置备份保留策略
+    pub fn with_retention(mut self, retention: RetentionPolicy) -> Self {
+        self.backups = self.backups.with_retention(retention);
+        self
+    }
+
     /// 获取 CCR 配置文件路径
     fn get_ccr_config_path() -> AppResult<PathBuf> {
-        let home_dir =
-            dirs::home_dir().ok_or_else(|| AppError::Config("无法获取用户主目录".to_string()))?;
+        let home_dir = dirs::home_dir().ok_or_else(|| AppError::Config(t!("ccr.no_home_dir")))?;
 
         let ccr_dir = home_dir.join(".claude-code-router");
 
@@ -55,12 +140,16 @@ impl CcrConfigManager {
 
     /// 获取备份目录路径
     fn get_backup_dir() -> AppResult<PathBuf> {
-        let home_dir =
-            dirs::home_dir().ok_or_else(|| AppError::Config("无法获取用户主目录".to_string()))?;
+        let home_dir = dirs::home_dir().ok_or_else(|| AppError::Config(t!("ccr.no_home_dir")))?;
 
         Ok(home_dir.join(".claude-code-router").join("backups"))
     }
 
+    /// 获取 CCR 配置文件锁，保护跨进程的读取-修改-写入过程
+    pub fn lock(&self) -> AppResult<FileLock> {
+        FileLock::acquire(&self.config_path)
+    }
+
     /// 读取 CCR 配置文件
     pub fn load_config(&self) -> AppResult<CcrConfig> {
         if !self.config_path.exists() {
@@ -70,7 +159,7 @@ impl CcrConfigManager {
 
         let content = fs::read_to_string(&self.config_path)?;
         let config: CcrConfig = serde_json::from_str(&content)
-            .map_err(|e| AppError::Config(format!("解析 CCR 配置文件失败: {e}")))?;
+            .map_err(|e| AppError::Config(t!("ccr.parse_failed", e)))?;
 
         Ok(config)
     }
@@ -81,37 +170,54 @@ impl CcrConfigManager {
         // 验证配置
         config.validate()?;
 
+        // 写入配置文件
+        let _lock = self.lock()?;
+        self.write_config(config)?;
+
+        verbose!("{}", t!("ccr.saved", path = self.config_path.display()));
+        Ok(())
+    }
+
+    /// 备份现有配置文件后原子写入新配置
+    fn write_config(&self, config: &CcrConfig) -> AppResult<()> {
         // 如果配置文件已存在，先创建备份
         if self.config_path.exists() {
             self.create_backup()?;
         }
 
-        // 写入配置文件
-        let content = serde_json::to_string_pretty(config)?;
-        fs::write(&self.config_path, content)?;
+        self.write_config_unbacked(config)
+    }
 
-        println!("✅ CCR 配置文件已保存: {}", self.config_path.display());
-        Ok(())
+    /// 不备份现有配置，直接原子写入新配置
+    fn write_config_unbacked(&self, config: &CcrConfig) -> AppResult<()> {
+        let content = serde_json::to_string_pretty(config)?;
+        atomic_write(&self.config_path, content.as_bytes())
     }
 
-    /// 创建配置文件备份
+    /// 创建配置文件备份，并按保留策略清理过期备份
+    ///
+    /// 配置与最新备份相同时不重复备份，返回最新备份的ID
     pub fn create_backup(&self) -> AppResult<String> {
         if !self.config_path.exists() {
-            return Err(AppError::Config(
-                "CCR 配置文件不存在，无法创建备份".to_string(),
-            ));
+            return Err(AppError::Config(t!("ccr.backup_missing_config")));
         }
 
-        // 生成备份文件名（带时间戳）
-        let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
-        let backup_filename = format!("config_backup_{timestamp}.json");
-        let backup_path = self.backup_dir.join(&backup_filename);
+        // 复制配置文件到备份目录（文件名带时间戳）
+        let Some(entry) = self.backups.create(&self.config_path)? else {
+            return Ok(self.backups.find("latest")?.id);
+        };
+        verbose!("{}", t!("ccr.backup_created", path = entry.path.display()));
 
-        // 复制配置文件到备份目录
-        fs::copy(&self.config_path, &backup_path)?;
+        // 清理失败不影响本次写入
+        match self.backups.prune() {
+            Ok(removed) if !removed.is_empty() => {
+                verbose!("{}", t!("ccr.backup_pruned", count = removed.len()));
+            }
+            Ok(_) => {}
+            Err(e) => warn!("{}", t!("backup.prune_failed", e)),
+        }
 
-        println!("📦 配置备份已创建: {}", backup_path.display());
-        Ok(backup_filename)
+        Ok(entry.id)
     }
 
     /// 检查配置文件是否存在
@@ -133,6 +239,7 @@ impl CcrConfigManager {
     }
 
     /// 删除 Provider
+    #[allow(dead_code)]
     pub fn remove_provider(&self, name: &str) -> AppResult<()> {
         // 创建一个临时的Provider对象（只需要name字段）
         let temp_provider = CcrProvider {
@@ -142,6 +249,7 @@ impl CcrConfigManager {
             models: Vec::new(),          // 临时值，删除操作不需要验证
             transformer: None,
             provider_type: None,
+            extra: serde_json::Map::new(),
         };
 
         // 使用精确更新方法
@@ -155,7 +263,7 @@ impl CcrConfigManager {
         config
             .get_provider(name)
             .cloned()
-            .ok_or_else(|| AppError::Config(format!("Provider '{name}' 不存在")))
+            .ok_or_else(|| AppError::Config(t!("provider.not_found", name)))
     }
 
     /// 更新 Provider
@@ -177,13 +285,16 @@ impl CcrConfigManager {
         Ok(config.Router)
     }
 
-    /// 应用 Router Profile 配置（只修改 Router 部分）
-    pub fn apply_router_profile(&self, router_profile: &RouterProfile) -> AppResult<()> {
+    /// 应用 Router Profile 配置（只修改 Router 部分），返回未阻止应用的路由警告
+    pub fn apply_router_profile(
+        &self,
+        router_profile: &RouterProfile,
+    ) -> AppResult<Vec<RouteIssue>> {
         // 使用精确更新方法，只修改Router节点
-        self.update_router_only(&router_profile.router)?;
+        let warnings = self.update_router_only(&router_profile.router)?;
 
-        println!("✅ 已应用 Router Profile '{}'", router_profile.name);
-        Ok(())
+        verbose!("{}", t!("ccr.profile_applied", name = router_profile.name));
+        Ok(warnings)
     }
 
     /// 获取配置统计信息
@@ -202,27 +313,6 @@ impl CcrConfigManager {
         })
     }
 
-    /// 验证所有 Router 路由的 Provider 引用
-    pub fn validate_router_references(&self) -> AppResult<Vec<String>> {
-        let config = self.load_config()?;
-        let mut errors = Vec::new();
-
-        let provider_names: std::collections::HashSet<_> =
-            config.Providers.iter().map(|p| p.name.as_str()).collect();
-
-        for (route_name, route_value) in config.Router.get_all_routes() {
-            if let Some(provider_name) = route_value.split(',').next()
-                && !provider_names.contains(provider_name)
-            {
-                errors.push(format!(
-                    "路由 '{route_name}' 引用了不存在的提供商 '{provider_name}'"
-                ));
-            }
-        }
-
-        Ok(errors)
-    }
-
     /// 确保存在可用的Router Profile配置
     /// 优先级：本地配置 → CCR配置自动生成 → 提示创建Provider
     pub fn ensure_router_profile_exists(&self) -> AppResult<RouterProfileStatus> {
@@ -243,8 +333,14 @@ impl CcrConfigManager {
         // 3. 从CCR配置自动生成default router profile
         match self.generate_default_router_profile()? {
             Some(router_profile) => {
+                // 加锁后重新加载，避免覆盖其他进程的并发修改
+                let _lock = Config::lock()?;
+                let mut local_config = Config::load_or_default()?;
+                if !local_config.groups.router.is_empty() {
+                    return Ok(RouterProfileStatus::LocalExists);
+                }
+
                 // 保存到本地配置
-                let mut local_config = local_config;
                 local_config.add_router_profile("default".to_string(), router_profile)?;
                 local_config.save()?;
                 Ok(RouterProfileStatus::GeneratedDefault)
@@ -272,14 +368,21 @@ impl CcrConfigManager {
             if let Some(provider_name) = route_value.split(',').next()
                 && !provider_names.contains(provider_name)
             {
-                println!("⚠️  警告: 路由 '{route_name}' 引用了不存在的提供商 '{provider_name}'");
+                warn!(
+                    "{}",
+                    t!(
+                        "ccr.route_missing_provider_warning",
+                        route_name,
+                        provider_name
+                    )
+                );
             }
         }
 
         let router_profile = RouterProfile::new(
             "default".to_string(),
             ccr_config.Router.clone(),
-            Some("从 claude-code-router 配置自动生成".to_string()),
+            Some(t!("ccr.generated_description")),
         )?;
 
         Ok(Some(router_profile))
@@ -319,10 +422,7 @@ impl CcrConfigManager {
                     return Ok(updated_config.get_router_profile(name)?.clone());
                 }
                 RouterProfileStatus::NeedCreateProvider => {
-                    return Err(AppError::Config(
-                        "暂无 Provider 配置，请先使用 'ccode provider add <name>' 添加 Provider"
-                            .to_string(),
-                    ));
+                    return Err(AppError::Config(t!("ccr.no_providers")));
                 }
                 _ => {}
             }
@@ -333,7 +433,8 @@ impl CcrConfigManager {
 
     /// 添加Router Profile到本地配置
     pub fn add_router_profile(&self, name: String, router_profile: RouterProfile) -> AppResult<()> {
-        let mut config = Config::load().unwrap_or_default();
+        let _lock = Config::lock()?;
+        let mut config = Config::load_or_default()?;
         config.add_router_profile(name, router_profile)?;
         config.save()?;
         Ok(())
@@ -341,25 +442,27 @@ impl CcrConfigManager {
 
     /// 删除Router Profile从本地配置
     pub fn remove_router_profile(&self, name: &str) -> AppResult<()> {
+        let _lock = Config::lock()?;
         let mut config = Config::load()?;
         config.remove_router_profile(name)?;
         config.save()?;
         Ok(())
     }
 
-    /// 设置默认Router Profile并应用到CCR配置
-    pub fn use_router_profile(&self, name: &str) -> AppResult<()> {
+    /// 设置默认Router Profile并应用到CCR配置，返回未阻止应用的路由警告
+    pub fn use_router_profile(&self, name: &str) -> AppResult<Vec<RouteIssue>> {
+        let _lock = Config::lock()?;
         let mut config = Config::load()?;
         let router_profile = config.get_router_profile(name)?.clone();
 
+        // 先应用到claude-code-router配置，路由校验失败时不修改默认配置
+        let warnings = self.apply_router_profile(&router_profile)?;
+
         // 设置为默认
         config.set_default_router(name)?;
         config.save()?;
 
-        // 应用到claude-code-router配置
-        self.apply_router_profile(&router_profile)?;
-
-        Ok(())
+        Ok(warnings)
     }
 
     /// 从CCR配置文件同步Providers信息到本地缓存
@@ -374,9 +477,9 @@ impl CcrConfigManager {
 
         // 同步逻辑：这里主要用于信息展示和验证
         // Provider的管理仍然通过ccode命令进行，这里只是读取最新状态
-        println!(
-            "🔄 同步Provider信息: 发现 {} 个Provider",
-            ccr_config.Providers.len()
+        verbose!(
+            "{}",
+            t!("ccr.sync_providers", count = ccr_config.Providers.len())
         );
 
         Ok(())
@@ -392,40 +495,27 @@ impl CcrConfigManager {
 
     /// 仅更新CCR配置文件的Router节点
     /// 这是精确更新的核心方法，只修改Router部分而保持其他配置不变
-    pub fn update_router_only(&self, router: &CcrRouter) -> AppResult<()> {
+    ///
+    /// 路由引用按当前的 [`RouteValidation`] 模式校验，返回未阻止更新的警告。
+    pub fn update_router_only(&self, router: &CcrRouter) -> AppResult<Vec<RouteIssue>> {
         router.validate()?;
 
+        let _lock = self.lock()?;
         let mut config = self.load_config()?;
 
-        // 验证Router配置中的Provider引用是否有效
-        let provider_names: std::collections::HashSet<_> =
-            config.Providers.iter().map(|p| p.name.as_str()).collect();
+        // 验证Router配置中的Provider与模型引用
+        let warnings = router.validate_references(&config.Providers, RouteValidation::current())?;
 
-        for (route_name, route_value) in router.get_all_routes() {
-            if let Some(provider_name) = route_value.split(',').next()
-                && !provider_names.contains(provider_name)
-            {
-                return Err(AppError::InvalidConfig(format!(
-                    "路由 '{}' 引用了不存在的提供商 '{}'",
-                    route_name, provider_name
-                )));
-            }
-        }
-
-        // 如果配置文件已存在，先创建备份
-        if self.config_path.exists() {
-            self.create_backup()?;
-        }
-
-        // 仅更新Router节点
-        config.Router = router.clone();
+        // 仅更新Router节点，保留Router中ccode未识别的字段
+        let mut new_router = router.clone();
+        new_router.preserve_unknown_fields(&config.Router);
+        config.Router = new_router;
 
         // 保存配置
-        let content = serde_json::to_string_pretty(&config)?;
-        std::fs::write(&self.config_path, content)?;
+        self.write_config(&config)?;
 
-        println!("✅ 已更新 CCR Router 配置");
-        Ok(())
+        verbose!("{}", t!("ccr.router_updated"));
+        Ok(warnings)
     }
 
     /// 仅更新CCR配置文件中的单个Provider
@@ -435,15 +525,16 @@ impl CcrConfigManager {
         provider: &CcrProvider,
         operation: ProviderOperation,
     ) -> AppResult<()> {
+        let _lock = self.lock()?;
         let mut config = self.load_config()?;
 
         match operation {
             ProviderOperation::Add => {
                 provider.validate()?;
                 if config.Providers.iter().any(|p| p.name == provider.name) {
-                    return Err(AppError::Config(format!(
-                        "Provider '{}' 已存在",
-                        provider.name
+                    return Err(AppError::Config(t!(
+                        "provider.already_exists",
+                        name = provider.name
                     )));
                 }
                 config.Providers.push(provider.clone());
@@ -457,9 +548,9 @@ impl CcrConfigManager {
                 {
                     *existing = provider.clone();
                 } else {
-                    return Err(AppError::Config(format!(
-                        "Provider '{}' 不存在",
-                        provider.name
+                    return Err(AppError::Config(t!(
+                        "provider.not_found",
+                        name = provider.name
                     )));
                 }
             }
@@ -469,51 +560,127 @@ impl CcrConfigManager {
                 config.Providers.retain(|p| p.name != provider.name);
 
                 if config.Providers.len() == original_len {
-                    return Err(AppError::Config(format!(
-                        "Provider '{}' 不存在",
-                        provider.name
+                    return Err(AppError::Config(t!(
+                        "provider.not_found",
+                        name = provider.name
                     )));
                 }
             }
         }
 
-        // 如果配置文件已存在，先创建备份
-        if self.config_path.exists() {
-            self.create_backup()?;
+        // 保存配置
+        self.write_config(&config)?;
+
+        verbose!("{}", t!("ccr.provider_updated"));
+        Ok(())
+    }
+
+    /// 删除CCR配置文件中的Provider，并按 `removal` 处理Router节点中引用它的路由
+    ///
+    /// 在持有配置锁时读取最新的Router并就地修改，保留其中ccode未识别的字段；
+    /// 未指定处理方式而Router仍引用该Provider，或清空时默认路由引用它，都会拒绝删除。
+    /// 返回修改的路由数。
+    pub fn remove_provider_with(
+        &self,
+        name: &str,
+        removal: Option<&ProviderRemoval>,
+    ) -> AppResult<usize> {
+        let _lock = self.lock()?;
+        let mut config = self.load_config()?;
+
+        let original_len = config.Providers.len();
+        config.Providers.retain(|p| p.name != name);
+        if config.Providers.len() == original_len {
+            return Err(AppError::Config(t!("provider.not_found", name)));
+        }
+
+        let references = config.Router.provider_routes(name);
+        let changed_routes = match removal {
+            _ if references.is_empty() => 0,
+            None => return Err(AppError::Config(t!("provider_remove.blocked", name))),
+            Some(ProviderRemoval::Cascade)
+                if references.iter().any(|(route, _)| route == "default") =>
+            {
+                return Err(AppError::Config(t!(
+                    "provider_remove.default_route",
+                    name,
+                    profiles = t!("route_ref.ccr_label")
+                )));
+            }
+            Some(removal) => {
+                if let ProviderRemoval::Reassign { provider, .. } = removal
+                    && !config.Providers.iter().any(|p| &p.name == provider)
+                {
+                    return Err(AppError::Config(t!("provider.not_found", name = provider)));
+                }
+                let changed = removal.apply(&mut config.Router, name);
+                config.Router.validate()?;
+                changed
+            }
+        };
+
+        // 保存配置
+        self.write_config(&config)?;
+
+        verbose!("{}", t!("ccr.provider_updated"));
+        Ok(changed_routes)
+    }
+
+    /// 重命名CCR配置文件中的Provider，并同步更新Router节点中引用它的路由
+    pub fn rename_provider_only(&self, old_name: &str, provider: &CcrProvider) -> AppResult<()> {
+        provider.validate()?;
+
+        let _lock = self.lock()?;
+        let mut config = self.load_config()?;
+
+        if config.Providers.iter().any(|p| p.name == provider.name) {
+            return Err(AppError::Config(t!(
+                "provider.already_exists",
+                name = provider.name
+            )));
         }
+        let existing = config
+            .Providers
+            .iter_mut()
+            .find(|p| p.name == old_name)
+            .ok_or_else(|| AppError::Config(t!("provider.not_found", name = old_name)))?;
+        *existing = provider.clone();
+        config.Router.rename_provider(old_name, &provider.name);
 
         // 保存配置
-        let content = serde_json::to_string_pretty(&config)?;
-        std::fs::write(&self.config_path, content)?;
+        self.write_config(&config)?;
 
-        println!("✅ 已更新 CCR Provider 配置");
+        verbose!("{}", t!("ccr.provider_updated"));
         Ok(())
     }
 
     /// 仅更新CCR配置文件的Providers节点
     /// 用于批量Provider更新操作
     #[allow(dead_code)]
-    pub fn update_providers_only(&self, providers: Vec<CcrProvider>) -> AppResult<()> {
+    pub fn update_providers_only(
+        &self,
+        providers: Vec<CcrProvider>,
+        backup: bool,
+    ) -> AppResult<()> {
         // 验证所有Provider
         for provider in &providers {
             provider.validate()?;
         }
 
+        let _lock = self.lock()?;
         let mut config = self.load_config()?;
 
-        // 如果配置文件已存在，先创建备份
-        if self.config_path.exists() {
-            self.create_backup()?;
-        }
-
         // 更新Providers节点
         config.Providers = providers;
 
         // 保存配置
-        let content = serde_json::to_string_pretty(&config)?;
-        std::fs::write(&self.config_path, content)?;
+        if backup {
+            self.write_config(&config)?;
+        } else {
+            self.write_config_unbacked(&config)?;
+        }
 
-        println!("✅ 已更新 CCR Providers 配置");
+        verbose!("{}", t!("ccr.providers_updated"));
         Ok(())
     }
 }
@@ -530,7 +697,7 @@ pub enum RouterProfileStatus {
 }
 
 /// 配置统计信息
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ConfigStats {
     pub provider_count: usize,
     pub current_default_route: String,
@@ -547,31 +714,291 @@ impl ConfigStats {
     pub fn format_display(&self) -> String {
         let mut stats = String::new();
 
-        stats.push_str(&format!("🔗 Provider 数量: {}\n", self.provider_count));
-        stats.push_str(&format!("🎯 默认路由: {}\n", self.current_default_route));
+        let mut line = |text: String| {
+            stats.push_str(&text);
+            stats.push('\n');
+        };
+
+        line(t!("stats.provider_count", count = self.provider_count));
+        line(t!(
+            "stats.default_route",
+            route = self.current_default_route
+        ));
 
         if self.has_background_route {
-            stats.push_str("🔄 后台路由: ✅\n");
+            line(t!("stats.background_route"));
         }
         if self.has_think_route {
-            stats.push_str("💭 思考路由: ✅\n");
+            line(t!("stats.think_route"));
         }
         if self.has_long_context_route {
-            stats.push_str("📜 长上下文路由: ✅\n");
+            line(t!("stats.long_context_route"));
         }
         if self.has_web_search_route {
-            stats.push_str("🔍 网络搜索路由: ✅\n");
+            line(t!("stats.web_search_route"));
         }
 
         if let Some(timeout) = self.api_timeout_ms {
-            stats.push_str(&format!("⏱️  API 超时: {timeout}ms\n"));
+            line(t!("stats.api_timeout", timeout));
         }
 
-        stats.push_str(&format!(
-            "📝 日志记录: {}\n",
-            if self.log_enabled { "启用" } else { "禁用" }
-        ));
+        line(if self.log_enabled {
+            t!("stats.log_enabled")
+        } else {
+            t!("stats.log_disabled")
+        });
 
         stats
     }
 }
+
+impl BackupTarget for CcrConfigManager {
+    fn label(&self) -> String {
+        t!("ccr.label")
+    }
+
+    fn config_path(&self) -> &Path {
+        &self.config_path
+    }
+
+    fn backup_store(&self) -> &BackupStore {
+        &self.backups
+    }
+
+    fn validate_content(&self, content: &str) -> AppResult<()> {
+        let config: CcrConfig = serde_json::from_str(content)
+            .map_err(|e| AppError::InvalidConfig(t!("ccr.invalid_content", e)))?;
+        config.validate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    /// 包含 ccode 未识别字段的 claude-code-router 配置
+    const CONFIG_WITH_EXTRAS: &str = r#"{
+  "LOG": true,
+  "PORT": 3457,
+  "NON_INTERACTIVE_MODE": false,
+  "StatusLine": {
+    "enabled": true,
+    "currentStyle": "default",
+    "default": { "modules": [{ "type": "model", "icon": "🤖" }] }
+  },
+  "Providers": [
+    {
+      "name": "deepseek",
+      "api_base_url": "https://api.deepseek.com/chat/completions",
+      "api_key": "sk-test",
+      "models": ["deepseek-chat", "deepseek-reasoner"],
+      "max_retries": 3,
+      "headers": { "X-Trace": "on" }
+    }
+  ],
+  "Router": {
+    "default": "deepseek,deepseek-chat",
+    "image": "deepseek,deepseek-chat",
+    "longContextThreshold": 60000
+  }
+}"#;
+
+    fn setup(content: &str) -> (tempfile::TempDir, CcrConfigManager) {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        fs::write(&config_path, content).unwrap();
+        let manager =
+            CcrConfigManager::with_paths(config_path, dir.path().join("backups")).unwrap();
+        (dir, manager)
+    }
+
+    fn read_json(manager: &CcrConfigManager) -> Value {
+        serde_json::from_str(&fs::read_to_string(&manager.config_path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_apply_router_profile_preserves_unknown_fields() {
+        let (_dir, manager) = setup(CONFIG_WITH_EXTRAS);
+        let original: Value = serde_json::from_str(CONFIG_WITH_EXTRAS).unwrap();
+
+        let mut router = CcrRouter::new("deepseek,deepseek-reasoner".to_string());
+        router.think = Some("deepseek,deepseek-reasoner".to_string());
+        let profile = RouterProfile::new("prod".to_string(), router, None).unwrap();
+        manager.apply_router_profile(&profile).unwrap();
+
+        let updated = read_json(&manager);
+
+        // 顶层未知字段
+        for key in ["PORT", "NON_INTERACTIVE_MODE", "StatusLine"] {
+            assert_eq!(updated[key], original[key], "顶层字段 {key} 丢失或被修改");
+        }
+
+        // Provider 未知字段
+        assert_eq!(
+            updated["Providers"][0]["max_retries"],
+            original["Providers"][0]["max_retries"]
+        );
+        assert_eq!(
+            updated["Providers"][0]["headers"],
+            original["Providers"][0]["headers"]
+        );
+
+        // Router 未知字段保留，已知字段按 Profile 更新
+        assert_eq!(updated["Router"]["image"], original["Router"]["image"]);
+        assert_eq!(
+            updated["Router"]["default"],
+            json!("deepseek,deepseek-reasoner")
+        );
+        assert_eq!(
+            updated["Router"]["think"],
+            json!("deepseek,deepseek-reasoner")
+        );
+    }
+
+    #[test]
+    fn test_update_provider_preserves_unknown_fields() {
+        let (_dir, manager) = setup(CONFIG_WITH_EXTRAS);
+        let original: Value = serde_json::from_str(CONFIG_WITH_EXTRAS).unwrap();
+
+        let mut provider = manager.get_provider("deepseek").unwrap();
+        provider.api_key = "sk-rotated".to_string();
+        manager.update_provider(provider).unwrap();
+
+        let updated = read_json(&manager);
+        assert_eq!(updated["Providers"][0]["api_key"], json!("sk-rotated"));
+        assert_eq!(
+            updated["Providers"][0]["headers"],
+            original["Providers"][0]["headers"]
+        );
+        assert_eq!(updated["StatusLine"], original["StatusLine"]);
+        assert_eq!(updated["Router"], original["Router"]);
+    }
+
+    #[test]
+    fn test_rename_provider_rewrites_routes() {
+        let (_dir, manager) = setup(CONFIG_WITH_EXTRAS);
+        let original: Value = serde_json::from_str(CONFIG_WITH_EXTRAS).unwrap();
+
+        let mut provider = manager.get_provider("deepseek").unwrap();
+        provider.name = "ds".to_string();
+        manager.rename_provider_only("deepseek", &provider).unwrap();
+
+        let updated = read_json(&manager);
+        assert_eq!(updated["Providers"][0]["name"], json!("ds"));
+        assert_eq!(
+            updated["Providers"][0]["headers"],
+            original["Providers"][0]["headers"]
+        );
+        assert_eq!(updated["Router"]["default"], json!("ds,deepseek-chat"));
+        assert_eq!(updated["Router"]["image"], json!("ds,deepseek-chat"));
+
+        // 目标名称已存在或原 Provider 不存在时拒绝
+        assert!(manager.rename_provider_only("deepseek", &provider).is_err());
+        provider.name = "other".to_string();
+        assert!(manager.rename_provider_only("missing", &provider).is_err());
+    }
+
+    #[test]
+    fn test_remove_provider_with_routes() {
+        let (_dir, manager) = setup(CONFIG_WITH_EXTRAS);
+        let mut config = manager.load_config().unwrap();
+        config.Providers.push(CcrProvider {
+            name: "other".to_string(),
+            api_base_url: "https://api.example.com/v1/chat/completions".to_string(),
+            api_key: "sk-other".to_string(),
+            models: vec!["model".to_string()],
+            transformer: None,
+            provider_type: None,
+            extra: serde_json::Map::new(),
+        });
+        manager.save_config(&config).unwrap();
+
+        // 未指定处理方式或清空默认路由时拒绝删除，配置保持不变
+        let before = manager.read_current().unwrap();
+        assert!(manager.remove_provider_with("deepseek", None).is_err());
+        assert!(
+            manager
+                .remove_provider_with("deepseek", Some(&ProviderRemoval::Cascade))
+                .is_err()
+        );
+        assert_eq!(manager.read_current().unwrap(), before);
+
+        // 改用其他 Provider 时基于最新的 Router 修改，保留未识别的字段
+        let removal = ProviderRemoval::reassign("other", "deepseek", &config.Providers).unwrap();
+        assert_eq!(
+            manager
+                .remove_provider_with("deepseek", Some(&removal))
+                .unwrap(),
+            2
+        );
+
+        let updated = read_json(&manager);
+        assert_eq!(updated["Providers"][0]["name"], json!("other"));
+        assert_eq!(updated["Router"]["default"], json!("other,model"));
+        assert_eq!(updated["Router"]["image"], json!("other,model"));
+        assert_eq!(updated["Router"]["longContextThreshold"], json!(60000));
+        assert!(manager.remove_provider_with("deepseek", None).is_err());
+    }
+
+    #[test]
+    fn test_restore_backup() {
+        let (_dir, manager) = setup(CONFIG_WITH_EXTRAS);
+
+        let backup_id = manager.create_backup().unwrap();
+        let mut provider = manager.get_provider("deepseek").unwrap();
+        provider.api_key = "sk-rotated".to_string();
+        manager.update_provider(provider).unwrap();
+
+        manager.restore_backup(&backup_id).unwrap();
+
+        assert_eq!(manager.read_current().unwrap(), CONFIG_WITH_EXTRAS);
+    }
+
+    #[test]
+    fn test_update_providers_without_backup_and_purge() {
+        let (_dir, manager) = setup(CONFIG_WITH_EXTRAS);
+        manager.create_backup().unwrap();
+
+        let mut providers = manager.list_providers().unwrap();
+        providers[0].api_key = "$CCODE_DEEPSEEK_API_KEY".to_string();
+        manager.update_providers_only(providers, false).unwrap();
+        assert_eq!(manager.list_backups().unwrap().len(), 1);
+
+        let plaintext = |config: &Value| {
+            config["Providers"][0]["api_key"]
+                .as_str()
+                .is_some_and(|key| !key.starts_with('$'))
+        };
+        assert_eq!(manager.remove_backups_where(&plaintext).unwrap().len(), 1);
+        assert!(manager.list_backups().unwrap().is_empty());
+        assert_eq!(
+            read_json(&manager)["Providers"][0]["api_key"],
+            json!("$CCODE_DEEPSEEK_API_KEY")
+        );
+    }
+
+    #[test]
+    fn test_restore_rejects_invalid_backup() {
+        let (_dir, manager) = setup(CONFIG_WITH_EXTRAS);
+        let invalid = manager
+            .backup_dir()
+            .join("config_backup_20000101_000000.json");
+        fs::write(
+            &invalid,
+            r#"{"Providers": [], "Router": {"default": "x,y"}}"#,
+        )
+        .unwrap();
+
+        assert!(manager.restore_backup("20000101_000000").is_err());
+        assert_eq!(manager.read_current().unwrap(), CONFIG_WITH_EXTRAS);
+    }
+
+    #[test]
+    fn test_round_trip_without_changes_is_lossless() {
+        let config: CcrConfig = serde_json::from_str(CONFIG_WITH_EXTRAS).unwrap();
+        let round_tripped = serde_json::to_value(&config).unwrap();
+        let original: Value = serde_json::from_str(CONFIG_WITH_EXTRAS).unwrap();
+        assert_eq!(round_tripped, original);
+    }
+}
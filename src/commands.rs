@@ -1,14 +1,35 @@
-use crate::ccr_config::CcrConfigManager;
-use crate::config::{CcrProvider, CcrRouter, Config, Profile, ProviderType, RouterProfile};
+use crate::backup::{BackupEntry, BackupTarget, JsonChange, diff_json, parse_size};
+use crate::ccr_config::{CcrConfigManager, ProviderRemoval};
+use crate::config::{
+    CcrConfig, CcrProvider, CcrRouter, Config, ConfigBackups, DirectProfile, Profile, ProviderType,
+    RouteIssue, RouteValidation, RouterProfile, check_route, env_names, validate_env_name,
+};
+use crate::console;
+use crate::doctor::Report;
+use crate::editor;
 use crate::error::{AppError, AppResult};
+use crate::launch::{self, ChildEnv, EnvPolicy, LaunchOptions};
+use crate::output::{
+    DirectProfileView, OutputOptions, ProfileEnvView, ProfilesView, ProviderView, ProvidersView,
+    RouterProfileView, RouterProfilesView, RouterView, mask_secret,
+};
+use crate::secrets::{self, RetiredSecrets, SecretBackendKind, SecretRef};
+use crate::shell::Shell;
+use crate::{info, outln, t, warn};
 use chrono::Utc;
-use std::io::{self, Write};
-use std::process::Command;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
 
 /// 读取可选字符串输入的通用函数
 fn read_optional_input(prompt: &str) -> AppResult<Option<String>> {
-    print!("{prompt}");
-    io::stdout().flush().unwrap();
+    console::prompt(prompt);
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
     let input = input.trim();
@@ -20,10 +41,7 @@ fn read_optional_input(prompt: &str) -> AppResult<Option<String>> {
 }
 
 /// 为不同路由类型获取智能推荐
-fn get_route_recommendations(
-    route_key: &str,
-    providers: &[CcrProvider],
-) -> Vec<(String, &'static str)> {
+fn get_route_recommendations(route_key: &str, providers: &[CcrProvider]) -> Vec<(String, String)> {
     let mut recommendations = Vec::new();
 
     match route_key {
@@ -38,14 +56,18 @@ fn get_route_recommendations(
                                 .iter()
                                 .find(|m| m.contains("gpt-3.5") || m.contains("4o-mini"))
                             {
-                                recommendations
-                                    .push((format!("{},{}", provider.name, model), "🚀 快速响应"));
+                                recommendations.push((
+                                    format!("{},{}", provider.name, model),
+                                    t!("recommend.fast"),
+                                ));
                             }
                         }
                         ProviderType::DeepSeek => {
                             if let Some(model) = provider.models.first() {
-                                recommendations
-                                    .push((format!("{},{}", provider.name, model), "💰 高性价比"));
+                                recommendations.push((
+                                    format!("{},{}", provider.name, model),
+                                    t!("recommend.cost_effective"),
+                                ));
                             }
                         }
                         _ => {}
@@ -62,8 +84,10 @@ fn get_route_recommendations(
                             if let Some(model) =
                                 provider.models.iter().find(|m| m.contains("reasoner"))
                             {
-                                recommendations
-                                    .push((format!("{},{}", provider.name, model), "🧠 强大推理"));
+                                recommendations.push((
+                                    format!("{},{}", provider.name, model),
+                                    t!("recommend.reasoning"),
+                                ));
                             }
                         }
                         ProviderType::Qwen => {
@@ -74,7 +98,7 @@ fn get_route_recommendations(
                             {
                                 recommendations.push((
                                     format!("{},{}", provider.name, model),
-                                    "🤔 思维链推理",
+                                    t!("recommend.chain_of_thought"),
                                 ));
                             }
                         }
@@ -84,8 +108,10 @@ fn get_route_recommendations(
                                 .iter()
                                 .find(|m| m.contains("claude") || m.contains("o1"))
                             {
-                                recommendations
-                                    .push((format!("{},{}", provider.name, model), "🔬 逻辑分析"));
+                                recommendations.push((
+                                    format!("{},{}", provider.name, model),
+                                    t!("recommend.logic"),
+                                ));
                             }
                         }
                         _ => {}
@@ -102,7 +128,7 @@ fn get_route_recommendations(
                             if let Some(model) = provider.models.first() {
                                 recommendations.push((
                                     format!("{},{}", provider.name, model),
-                                    "📜 超长上下文",
+                                    t!("recommend.long_context"),
                                 ));
                             }
                         }
@@ -111,7 +137,7 @@ fn get_route_recommendations(
                             {
                                 recommendations.push((
                                     format!("{},{}", provider.name, model),
-                                    "🌐 海量信息处理",
+                                    t!("recommend.massive_input"),
                                 ));
                             }
                         }
@@ -121,7 +147,7 @@ fn get_route_recommendations(
                             {
                                 recommendations.push((
                                     format!("{},{}", provider.name, model),
-                                    "📖 文档分析专家",
+                                    t!("recommend.documents"),
                                 ));
                             }
                         }
@@ -139,14 +165,15 @@ fn get_route_recommendations(
                             if let Some(model) = provider.models.first() {
                                 let route_with_online =
                                     format!("{},{}:online", provider.name, model);
-                                recommendations.push((route_with_online, "🔍 实时搜索"));
+                                recommendations
+                                    .push((route_with_online, t!("recommend.live_search")));
                             }
                         }
                         _ => {
                             if let Some(model) = provider.models.first() {
                                 recommendations.push((
                                     format!("{},{}", provider.name, model),
-                                    "🌐 基础网络查询",
+                                    t!("recommend.basic_search"),
                                 ));
                             }
                         }
@@ -162,40 +189,144 @@ fn get_route_recommendations(
     recommendations
 }
 
-/// 交互式添加配置
-pub fn cmd_add(name: String) -> AppResult<()> {
-    let mut config = Config::load().unwrap_or_default();
+/// 读取一行输入（已去除首尾空白）
+fn read_input(prompt: &str) -> AppResult<String> {
+    console::prompt(prompt);
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+/// 当前时间戳（用于 created_at / updated_at）
+fn current_timestamp() -> String {
+    Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string()
+}
+
+/// 可选字段的命令行取值，空字符串表示清空该字段
+fn optional_value(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// 交互式编辑可选字段：直接回车保持不变，输入 - 清空
+fn prompt_optional_field(label: &str, current: Option<String>) -> AppResult<Option<String>> {
+    let shown = current.clone().unwrap_or_else(|| t!("edit.unset"));
+    info!("{}", t!("edit.current_value", label, value = shown));
+    Ok(match read_optional_input(&t!("edit.prompt_optional"))? {
+        None => current,
+        Some(value) if value == "-" => None,
+        Some(value) => Some(value),
+    })
+}
+
+/// Direct配置的命令行参数（用于非交互式添加）
+#[derive(Debug, Default)]
+pub struct DirectProfileArgs {
+    pub token: Option<String>,
+    pub token_from_env: Option<String>,
+    pub token_from_file: Option<PathBuf>,
+    pub base_url: Option<String>,
+    pub model: Option<String>,
+    pub small_fast_model: Option<String>,
+    pub description: Option<String>,
+    pub non_interactive: bool,
+}
+
+impl DirectProfileArgs {
+    /// 是否通过命令行提供了任意字段
+    fn has_any_field(&self) -> bool {
+        self.has_connection_field() || self.description.is_some()
+    }
+
+    /// 是否提供了 Direct 配置特有的字段（描述以外）
+    fn has_connection_field(&self) -> bool {
+        self.token.is_some()
+            || self.token_from_env.is_some()
+            || self.token_from_file.is_some()
+            || self.base_url.is_some()
+            || self.model.is_some()
+            || self.small_fast_model.is_some()
+    }
+
+    /// 从 --token / --token-from-env / --token-from-file 中解析令牌
+    fn resolve_token(&self) -> AppResult<Option<String>> {
+        if let Some(token) = &self.token {
+            return Ok(Some(token.trim().to_string()));
+        }
+
+        if let Some(var) = &self.token_from_env {
+            let token =
+                std::env::var(var).map_err(|_| AppError::Config(t!("input.env_not_set", var)))?;
+            return Ok(Some(token.trim().to_string()));
+        }
+
+        if let Some(path) = &self.token_from_file {
+            let token = fs::read_to_string(path).map_err(|e| {
+                AppError::Config(t!("add.token_file_failed", path = path.display(), e))
+            })?;
+            return Ok(Some(token.trim().to_string()));
+        }
+
+        Ok(None)
+    }
+}
+
+/// 添加配置（支持交互式与命令行参数两种方式）
+pub fn cmd_add(name: String, args: DirectProfileArgs) -> AppResult<()> {
+    let config = Config::load_or_default()?;
 
     if config.groups.direct.contains_key(&name) {
-        return Err(AppError::Config(format!("配置 '{name}' 已存在")));
+        return Err(AppError::Config(t!("profile.already_exists", name)));
+    }
+
+    // 非交互模式下必需字段缺失时直接报错
+    if args.non_interactive {
+        if args.token.is_none() && args.token_from_env.is_none() && args.token_from_file.is_none() {
+            return Err(AppError::InvalidConfig(t!(
+                "input.missing_flag",
+                flag = "--token"
+            )));
+        }
+        if args.base_url.is_none() {
+            return Err(AppError::InvalidConfig(t!(
+                "input.missing_flag",
+                flag = "--base-url"
+            )));
+        }
     }
 
-    println!("🔧 添加新配置: {name}");
-    println!();
+    // 提供了任意字段参数时，只对缺失的必需字段进行提示
+    let from_flags = args.non_interactive || args.has_any_field();
+
+    info!("{}", t!("add.title", name));
+    info!();
 
     // 获取认证令牌
-    print!("🔑 请输入 ANTHROPIC_AUTH_TOKEN (支持各种第三方API格式): ");
-    io::stdout().flush().unwrap();
-    let mut token = String::new();
-    io::stdin().read_line(&mut token)?;
-    let token = token.trim().to_string();
+    let token = match args.resolve_token()? {
+        Some(token) => token,
+        None => read_input(&t!("add.prompt_token"))?,
+    };
 
     // 获取基础URL
-    print!("📍 请输入 ANTHROPIC_BASE_URL (如: https://api.anthropic.com): ");
-    io::stdout().flush().unwrap();
-    let mut url = String::new();
-    io::stdin().read_line(&mut url)?;
-    let url = url.trim().to_string();
+    let url = match args.base_url.as_deref().map(str::trim) {
+        Some(url) => url.to_string(),
+        None => read_input(&t!("add.prompt_base_url"))?,
+    };
+
+    let (anthropic_model, anthropic_small_fast_model, description) = if from_flags {
+        (args.model, args.small_fast_model, args.description)
+    } else {
+        // 获取可选的模型配置
+        let anthropic_model = read_optional_input(&t!("add.prompt_model"))?;
 
-    // 获取可选的模型配置
-    let anthropic_model = read_optional_input("🤖 请输入 ANTHROPIC_MODEL (可选，直接回车跳过): ")?;
+        // 获取快速模型配置
+        let anthropic_small_fast_model = read_optional_input(&t!("add.prompt_fast_model"))?;
 
-    // 获取快速模型配置
-    let anthropic_small_fast_model =
-        read_optional_input("⚡ 请输入 ANTHROPIC_SMALL_FAST_MODEL (可选，直接回车跳过): ")?;
+        // 获取描述（可选）
+        let description = read_optional_input(&t!("add.prompt_description"))?;
 
-    // 获取描述（可选）
-    let description = read_optional_input("📝 请输入描述 (可选，直接回车跳过): ")?;
+        (anthropic_model, anthropic_small_fast_model, description)
+    };
 
     // 创建配置
     let profile = Profile {
@@ -203,19 +334,26 @@ pub fn cmd_add(name: String) -> AppResult<()> {
         anthropic_base_url: url,
         anthropic_model,
         anthropic_small_fast_model,
+        env: BTreeMap::new(),
         description,
-        created_at: Some(Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string()),
+        created_at: Some(current_timestamp()),
+        updated_at: None,
     };
 
+    // 加锁后重新加载，避免覆盖其他进程在交互期间的修改
+    let _lock = Config::lock()?;
+    let mut config = Config::load_or_default()?;
+
     // 添加并保存配置
     config.add_direct_profile(name.clone(), profile)?;
+    store_direct_token(&mut config, &name)?;
     config.save()?;
 
-    println!();
-    println!("✅ 配置 '{name}' 添加成功！");
+    info!();
+    info!("{}", t!("add.success", name));
 
     if config.groups.direct.len() == 1 {
-        println!("🎯 已自动设为默认配置");
+        info!("{}", t!("add.auto_default"));
     }
 
     Ok(())
@@ -223,17 +361,22 @@ pub fn cmd_add(name: String) -> AppResult<()> {
 
 /// 设置默认配置
 pub fn cmd_use(name: String) -> AppResult<()> {
+    let _lock = Config::lock()?;
     let mut config = Config::load()?;
 
     config.set_default(&name)?;
     config.save()?;
 
-    println!("✅ 已将 '{name}' 设为默认配置");
+    info!("{}", t!("use.success", name));
     Ok(())
 }
 
 /// 启动claude程序
-pub fn cmd_run(name: Option<String>, claude_args: Vec<String>) -> AppResult<()> {
+pub fn cmd_run(
+    name: Option<String>,
+    claude_args: Vec<String>,
+    opts: LaunchOptions,
+) -> AppResult<()> {
     let config = Config::load()?;
 
     let (profile_name, profile) = match name {
@@ -247,119 +390,150 @@ pub fn cmd_run(name: Option<String>, claude_args: Vec<String>) -> AppResult<()>
         }
     };
 
-    println!("🚀 使用配置 '{profile_name}' 启动 claude...");
-    println!("📍 API URL: {}", profile.anthropic_base_url);
+    info!("{}", t!("run.starting", name = profile_name));
+    info!("📍 API URL: {}", profile.anthropic_base_url);
 
     // 显示设置的环境变量
     if let Some(model) = &profile.anthropic_model {
-        println!("🤖 模型: {}", model);
+        info!("{}", t!("profile.model", model));
     }
     if let Some(fast_model) = &profile.anthropic_small_fast_model {
-        println!("⚡ 快速模型: {}", fast_model);
+        info!("{}", t!("profile.fast_model", model = fast_model));
     }
-    println!();
+    if !profile.env.is_empty() {
+        info!("{}", t!("profile.env", names = env_names(&profile.env)));
+    }
+    info!();
 
-    // 设置环境变量并启动claude
-    let mut cmd = Command::new("claude");
-    cmd.env("ANTHROPIC_AUTH_TOKEN", &profile.anthropic_auth_token);
-    cmd.env("ANTHROPIC_BASE_URL", &profile.anthropic_base_url);
+    // 解析密钥引用
+    let token = secrets::resolve(
+        &profile.anthropic_auth_token,
+        config.open_secret_backend()?.as_ref(),
+    )?;
 
-    // 条件设置可选的环境变量
-    if let Some(model) = &profile.anthropic_model {
-        cmd.env("ANTHROPIC_MODEL", model);
+    // 设置环境变量并启动claude
+    let env = direct_profile_env(profile, token, opts.env);
+    if opts.show_env {
+        env.preview("claude");
+        return Ok(());
     }
 
-    if let Some(fast_model) = &profile.anthropic_small_fast_model {
-        cmd.env("ANTHROPIC_SMALL_FAST_MODEL", fast_model);
-    }
+    let mut cmd = Command::new("claude");
+    env.apply(&mut cmd);
 
     // 添加透传的参数
     if !claude_args.is_empty() {
         cmd.args(&claude_args);
-        println!("📄 透传参数: {}", claude_args.join(" "));
+        info!("{}", t!("run.passthrough", args = claude_args.join(" ")));
     } else {
-        println!(
-            "💡 提示: 可以直接在命令后添加参数透传给 claude 命令 (例如: ccode run myprofile --version 或 ccode run myprofile -- --help)"
-        );
+        info!("{}", t!("run.passthrough_hint"));
     }
 
-    match cmd.status() {
-        Ok(status) => {
-            if status.success() {
-                println!("✅ claude 程序正常退出");
-            } else {
-                println!("⚠️  claude 程序异常退出，退出码: {:?}", status.code());
-            }
-        }
-        Err(e) => {
-            if e.kind() == io::ErrorKind::NotFound {
-                return Err(AppError::CommandExecution(
-                    "找不到 'claude' 程序，请确保 claude 已安装并在 PATH 中".to_string(),
-                ));
-            } else {
-                return Err(AppError::CommandExecution(format!("执行 claude 失败: {e}")));
-            }
-        }
+    launch::launch(cmd, "claude", opts.mode, t!("run.claude_not_found"))
+}
+
+/// Direct 配置对应的 claude 环境变量
+/// 解析令牌后生成 Direct 配置的环境
+fn resolve_direct_env(
+    config: &Config,
+    profile: &DirectProfile,
+    policy: EnvPolicy,
+) -> AppResult<ChildEnv> {
+    let token = secrets::resolve(
+        &profile.anthropic_auth_token,
+        config.open_secret_backend()?.as_ref(),
+    )?;
+    Ok(direct_profile_env(profile, token, policy))
+}
+
+fn direct_profile_env(profile: &DirectProfile, token: String, policy: EnvPolicy) -> ChildEnv {
+    let mut env = ChildEnv::new(policy);
+    env.extend(&profile.env);
+    env.set("ANTHROPIC_AUTH_TOKEN", token);
+    env.set("ANTHROPIC_BASE_URL", &profile.anthropic_base_url);
+
+    // 条件设置可选的环境变量
+    if let Some(model) = &profile.anthropic_model {
+        env.set("ANTHROPIC_MODEL", model);
     }
 
-    Ok(())
+    if let Some(fast_model) = &profile.anthropic_small_fast_model {
+        env.set("ANTHROPIC_SMALL_FAST_MODEL", fast_model);
+    }
+    env
 }
 
 /// 删除配置
 pub fn cmd_remove(name: String) -> AppResult<()> {
-    let mut config = Config::load()?;
-
     // 确认删除
-    print!("⚠️  确定要删除配置 '{name}' 吗？(y/N): ");
-    io::stdout().flush().unwrap();
+    console::prompt(&t!("remove.confirm", name));
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
 
     let input = input.trim().to_lowercase();
     if input != "y" && input != "yes" {
-        println!("❌ 取消删除");
+        info!("{}", t!("remove.cancelled"));
         return Ok(());
     }
 
+    let _lock = Config::lock()?;
+    let mut config = Config::load()?;
+    let removed_token = config
+        .groups
+        .direct
+        .get(&name)
+        .map(|profile| profile.anthropic_auth_token.clone());
     config.remove_profile(&name)?; // 这个方法会自动检测组类型
     config.save()?;
+    if let Some(token) = removed_token {
+        discard_secret(&config, &token);
+    }
 
-    println!("✅ 配置 '{name}' 已删除");
+    info!("{}", t!("remove.success", name));
 
     // 如果还有其他配置，显示当前默认配置
     if !config.groups.direct.is_empty() || !config.groups.router.is_empty() {
         if let Some(default_profile) = &config.default_profile {
             if let Some(direct) = &default_profile.direct {
-                println!("🎯 当前默认Direct配置: {direct}");
+                info!("{}", t!("remove.current_default_direct", name = direct));
             }
             if let Some(router) = &default_profile.router {
-                println!("🎯 当前默认Router配置: {router}");
+                info!("{}", t!("remove.current_default_router", name = router));
             }
         }
     } else {
-        println!("📋 暂无配置，请使用 'ccode add <name>' 添加配置");
+        info!("{}", t!("list.empty"));
     }
 
     Ok(())
 }
 
 /// 列出配置（统一接口）
-pub fn cmd_list_with_group(group: Option<String>) -> AppResult<()> {
+pub fn cmd_list_with_group(group: Option<String>, out: OutputOptions) -> AppResult<()> {
     match group.as_deref() {
-        Some("direct") => cmd_list_direct(),
-        Some("ccr") => cmd_list_ccr(),
-        Some(g) => Err(AppError::Config(format!("未知的配置组: {g}"))),
-        None => cmd_list_all(),
+        Some("direct") => cmd_list_direct(out),
+        Some("ccr") => cmd_list_ccr(out),
+        Some(g) => Err(AppError::Config(t!("group.unknown", group = g))),
+        None => cmd_list_all(out),
     }
 }
 
 /// 添加配置（统一接口）
-pub fn cmd_add_with_group(name: String, group: Option<String>) -> AppResult<()> {
+pub fn cmd_add_with_group(
+    name: String,
+    group: Option<String>,
+    args: DirectProfileArgs,
+) -> AppResult<()> {
     match group.as_deref() {
-        Some("direct") => cmd_add_direct(name),
-        Some("ccr") => cmd_add_ccr(name),
-        Some(g) => Err(AppError::Config(format!("未知的配置组: {g}"))),
-        None => cmd_add_direct(name), // 默认使用direct组
+        Some("direct") => cmd_add_direct(name, args),
+        Some("ccr") => {
+            if args.has_any_field() {
+                warn!("{}", t!("add.ccr_ignores_flags"));
+            }
+            cmd_add_ccr(name, RouterProfileArgs::default())
+        }
+        Some(g) => Err(AppError::Config(t!("group.unknown", group = g))),
+        None => cmd_add_direct(name, args), // 默认使用direct组
     }
 }
 
@@ -368,7 +542,7 @@ pub fn cmd_use_with_group(name: String, group: Option<String>) -> AppResult<()>
     match group.as_deref() {
         Some("direct") => cmd_use_direct(name),
         Some("ccr") => cmd_use_ccr(name),
-        Some(g) => Err(AppError::Config(format!("未知的配置组: {g}"))),
+        Some(g) => Err(AppError::Config(t!("group.unknown", group = g))),
         None => cmd_use(name), // 向后兼容
     }
 }
@@ -378,20 +552,21 @@ pub fn cmd_run_with_group(
     name: Option<String>,
     group: Option<String>,
     claude_args: Vec<String>,
+    opts: LaunchOptions,
 ) -> AppResult<()> {
     match group.as_deref() {
-        Some("direct") => cmd_run_direct(name, claude_args),
+        Some("direct") => cmd_run_direct(name, claude_args, opts),
         Some("ccr") => {
             if !claude_args.is_empty() {
-                println!(
-                    "⚠️  注意: CCR 模式不支持透传参数，将忽略: {}",
-                    claude_args.join(" ")
+                warn!(
+                    "{}",
+                    t!("run.ccr_ignores_args", args = claude_args.join(" "))
                 );
             }
-            cmd_run_ccr(name)
+            cmd_run_ccr(name, opts)
         }
-        Some(g) => Err(AppError::Config(format!("未知的配置组: {g}"))),
-        None => cmd_run(name, claude_args), // 向后兼容，默认使用direct模式
+        Some(g) => Err(AppError::Config(t!("group.unknown", group = g))),
+        None => cmd_run(name, claude_args, opts), // 向后兼容，默认使用direct模式
     }
 }
 
@@ -400,77 +575,181 @@ pub fn cmd_remove_with_group(name: String, group: Option<String>) -> AppResult<(
     match group.as_deref() {
         Some("direct") => cmd_remove_direct(name),
         Some("ccr") => cmd_remove_ccr(name),
-        Some(g) => Err(AppError::Config(format!("未知的配置组: {g}"))),
+        Some(g) => Err(AppError::Config(t!("group.unknown", group = g))),
         None => cmd_remove(name), // 向后兼容
     }
 }
 
+/// 编辑配置（统一接口，未指定组时按名称自动识别）
+pub fn cmd_edit_with_group(
+    name: String,
+    group: Option<String>,
+    direct_args: DirectProfileArgs,
+    router_args: RouterProfileArgs,
+    raw: bool,
+    out: OutputOptions,
+) -> AppResult<()> {
+    match resolve_group(&name, group)?.as_str() {
+        "direct" => {
+            if router_args.has_route_field() {
+                return Err(AppError::InvalidConfig(t!("edit.route_flags_for_direct")));
+            }
+            if raw {
+                cmd_edit_direct_raw(name, out)
+            } else {
+                cmd_edit_direct(name, direct_args)
+            }
+        }
+        "ccr" => {
+            if direct_args.has_connection_field() {
+                return Err(AppError::InvalidConfig(t!("edit.direct_flags_for_router")));
+            }
+            if raw {
+                cmd_edit_ccr_raw(name, out)
+            } else {
+                cmd_edit_ccr(name, router_args)
+            }
+        }
+        g => Err(AppError::Config(t!("group.unknown", group = g))),
+    }
+}
+
+/// 重命名配置（统一接口，未指定组时按名称自动识别）
+pub fn cmd_rename_with_group(old: String, new: String, group: Option<String>) -> AppResult<()> {
+    match resolve_group(&old, group)?.as_str() {
+        "direct" => cmd_rename_direct(old, new),
+        "ccr" => cmd_rename_ccr(old, new),
+        g => Err(AppError::Config(t!("group.unknown", group = g))),
+    }
+}
+
+/// 复制配置（统一接口，未指定组时按名称自动识别）
+pub fn cmd_copy_with_group(source: String, target: String, group: Option<String>) -> AppResult<()> {
+    match resolve_group(&source, group)?.as_str() {
+        "direct" => cmd_copy_direct(source, target),
+        "ccr" => cmd_copy_ccr(source, target),
+        g => Err(AppError::Config(t!("group.unknown", group = g))),
+    }
+}
+
+/// 确定配置所在的组：未指定时按名称识别，名称同时存在于两个组时要求使用 --group
+fn resolve_group(name: &str, group: Option<String>) -> AppResult<String> {
+    if let Some(group) = group {
+        return Ok(group);
+    }
+
+    let config = Config::load()?;
+    match (
+        config.groups.direct.contains_key(name),
+        config.groups.router.contains_key(name),
+    ) {
+        (true, true) => Err(AppError::Config(t!("group.ambiguous", name))),
+        (false, true) => Ok("ccr".to_string()),
+        _ => Ok("direct".to_string()),
+    }
+}
+
+/// 检查新名称是否可用作配置或 Provider 名称
+fn check_new_name(name: &str) -> AppResult<()> {
+    if name.trim().is_empty() {
+        return Err(AppError::InvalidConfig(t!("rename.invalid_name", name)));
+    }
+    Ok(())
+}
+
 /// 列出所有配置（显示所有组）
-pub fn cmd_list_all() -> AppResult<()> {
+pub fn cmd_list_all(out: OutputOptions) -> AppResult<()> {
     let config = match Config::load() {
         Ok(config) => config,
-        Err(AppError::ConfigNotFound) => {
-            println!("📋 暂无配置，请使用 'ccode add <name>' 添加配置");
+        Err(AppError::ConfigNotFound) if out.is_text() => {
+            outln!("{}", t!("list.empty"));
             return Ok(());
         }
+        Err(AppError::ConfigNotFound) => Config::default(),
         Err(e) => return Err(e),
     };
 
     let direct_profiles = config.list_direct_profiles();
     let router_profiles = config.list_router_profiles();
 
+    if !out.is_text() {
+        let view = ProfilesView {
+            direct: DirectProfileView::list(direct_profiles, &out),
+            router: RouterProfileView::list(router_profiles, &out),
+        };
+        return out.emit("ProfileList", &view);
+    }
+
     if direct_profiles.is_empty() && router_profiles.is_empty() {
-        println!("📋 暂无配置，请使用 'ccode add <name>' 添加配置");
+        outln!("{}", t!("list.empty"));
         return Ok(());
     }
 
-    println!("📋 所有配置：");
-    println!();
+    outln!("{}", t!("list.all_title"));
+    outln!();
 
     // 显示Direct组配置
     if !direct_profiles.is_empty() {
-        println!("🔗 Direct组配置：");
+        outln!("{}", t!("list.direct_section"));
         for (name, profile, is_default) in direct_profiles {
-            let default_marker = if is_default { " (默认)" } else { "" };
-            println!("  🔧 {name}{default_marker}");
-            println!("     📍 URL: {}", profile.anthropic_base_url);
-            println!(
-                "     🔑 Token: {}...{}",
-                &profile.anthropic_auth_token[..7.min(profile.anthropic_auth_token.len())],
-                &profile.anthropic_auth_token
-                    [profile.anthropic_auth_token.len().saturating_sub(4)..]
+            let default_marker = if is_default {
+                t!("list.default_marker")
+            } else {
+                String::new()
+            };
+            outln!("  🔧 {name}{default_marker}");
+            outln!("     📍 URL: {}", profile.anthropic_base_url);
+            outln!(
+                "     🔑 Token: {}",
+                out.secret(&profile.anthropic_auth_token)
             );
             profile.display_optional_fields("     ");
-            println!();
+            outln!();
         }
     }
 
     // 显示Router组配置
     if !router_profiles.is_empty() {
-        println!("🎯 Router组配置：");
+        outln!("{}", t!("list.router_section"));
         for (name, profile, is_default) in router_profiles {
-            let default_marker = if is_default { " (默认)" } else { "" };
-            println!("  🔧 {name}{default_marker}");
-            println!("     🎯 默认路由: {}", profile.router.default);
+            let default_marker = if is_default {
+                t!("list.default_marker")
+            } else {
+                String::new()
+            };
+            outln!("  🔧 {name}{default_marker}");
+            outln!(
+                "     {}",
+                t!("route.default", route = profile.router.default)
+            );
             if let Some(background) = &profile.router.background {
-                println!("     🔄 后台路由: {background}");
+                outln!("     {}", t!("route.background", route = background));
             }
             if let Some(think) = &profile.router.think {
-                println!("     💭 思考路由: {think}");
+                outln!("     {}", t!("route.think", route = think));
             }
             if let Some(long_context) = &profile.router.long_context {
-                println!("     📜 长上下文路由: {long_context}");
+                outln!("     {}", t!("route.long_context", route = long_context));
             }
             if let Some(web_search) = &profile.router.web_search {
-                println!("     🔍 网络搜索路由: {web_search}");
+                outln!("     {}", t!("route.web_search", route = web_search));
+            }
+            if !profile.env.is_empty() {
+                outln!(
+                    "     {}",
+                    t!("profile.env", names = env_names(&profile.env))
+                );
             }
             if let Some(desc) = &profile.description {
-                println!("     📝 描述: {desc}");
+                outln!("     {}", t!("profile.description", desc));
             }
             if let Some(created) = &profile.created_at {
-                println!("     📅 创建: {created}");
+                outln!("     {}", t!("profile.created", created));
             }
-            println!();
+            if let Some(updated) = &profile.updated_at {
+                outln!("     {}", t!("profile.updated", updated));
+            }
+            outln!();
         }
     }
 
@@ -478,106 +757,332 @@ pub fn cmd_list_all() -> AppResult<()> {
 }
 
 /// 列出Direct组配置
-pub fn cmd_list_direct() -> AppResult<()> {
+pub fn cmd_list_direct(out: OutputOptions) -> AppResult<()> {
     let config = match Config::load() {
         Ok(config) => config,
-        Err(AppError::ConfigNotFound) => {
-            println!("📋 暂无Direct配置，请使用 'ccode add --group direct <name>' 添加配置");
+        Err(AppError::ConfigNotFound) if out.is_text() => {
+            outln!("{}", t!("list.direct_empty"));
             return Ok(());
         }
+        Err(AppError::ConfigNotFound) => Config::default(),
         Err(e) => return Err(e),
     };
 
     let profiles = config.list_direct_profiles();
 
+    if !out.is_text() {
+        return out.emit(
+            "DirectProfileList",
+            &DirectProfileView::list(profiles, &out),
+        );
+    }
+
     if profiles.is_empty() {
-        println!("📋 暂无Direct配置，请使用 'ccode add --group direct <name>' 添加配置");
+        outln!("{}", t!("list.direct_empty"));
         return Ok(());
     }
 
-    println!("📋 Direct组配置：");
-    println!();
+    outln!("{}", t!("list.direct_title"));
+    outln!();
 
     for (name, profile, is_default) in profiles {
-        let default_marker = if is_default { " (默认)" } else { "" };
-        println!("🔧 {name}{default_marker}");
-        println!("   📍 URL: {}", profile.anthropic_base_url);
-        println!(
-            "   🔑 Token: {}...{}",
-            &profile.anthropic_auth_token[..7.min(profile.anthropic_auth_token.len())],
-            &profile.anthropic_auth_token[profile.anthropic_auth_token.len().saturating_sub(4)..]
-        );
+        let default_marker = if is_default {
+            t!("list.default_marker")
+        } else {
+            String::new()
+        };
+        outln!("🔧 {name}{default_marker}");
+        outln!("   📍 URL: {}", profile.anthropic_base_url);
+        outln!("   🔑 Token: {}", out.secret(&profile.anthropic_auth_token));
 
         profile.display_optional_fields("   ");
-        println!();
+        outln!();
     }
 
     Ok(())
 }
 
 /// 添加Direct配置
-pub fn cmd_add_direct(name: String) -> AppResult<()> {
-    cmd_add(name) // 复用现有的逻辑
+pub fn cmd_add_direct(name: String, args: DirectProfileArgs) -> AppResult<()> {
+    cmd_add(name, args) // 复用现有的逻辑
 }
 
 /// 设置默认Direct配置
 pub fn cmd_use_direct(name: String) -> AppResult<()> {
+    let _lock = Config::lock()?;
     let mut config = Config::load()?;
     config.set_default_direct(&name)?;
     config.save()?;
-    println!("✅ 已将 '{name}' 设为默认Direct配置");
+    info!("{}", t!("use.direct_success", name));
     Ok(())
 }
 
 /// 运行Direct配置
-pub fn cmd_run_direct(name: Option<String>, claude_args: Vec<String>) -> AppResult<()> {
-    cmd_run(name, claude_args) // 复用现有的逻辑
+pub fn cmd_run_direct(
+    name: Option<String>,
+    claude_args: Vec<String>,
+    opts: LaunchOptions,
+) -> AppResult<()> {
+    cmd_run(name, claude_args, opts) // 复用现有的逻辑
 }
 
 /// 删除Direct配置
 pub fn cmd_remove_direct(name: String) -> AppResult<()> {
-    let mut config = Config::load()?;
-
     // 确认删除
-    print!("⚠️  确定要删除Direct配置 '{name}' 吗？(y/N): ");
-    io::stdout().flush().unwrap();
+    console::prompt(&t!("remove.direct_confirm", name));
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
 
     let input = input.trim().to_lowercase();
     if input != "y" && input != "yes" {
-        println!("❌ 取消删除");
+        info!("{}", t!("remove.cancelled"));
         return Ok(());
     }
 
+    let _lock = Config::lock()?;
+    let mut config = Config::load()?;
+    let removed_token = config
+        .get_direct_profile(&name)?
+        .anthropic_auth_token
+        .clone();
     config.remove_direct_profile(&name)?;
     config.save()?;
+    discard_secret(&config, &removed_token);
 
-    println!("✅ Direct配置 '{name}' 已删除");
+    info!("{}", t!("remove.direct_success", name));
 
     // 显示当前默认配置
     if !config.groups.direct.is_empty() {
         if let Some(default_profile) = &config.default_profile
             && let Some(direct) = &default_profile.direct
         {
-            println!("🎯 当前默认Direct配置: {direct}");
+            info!("{}", t!("remove.current_default_direct", name = direct));
         }
     } else {
-        println!("📋 暂无Direct配置，请使用 'ccode add --group direct <name>' 添加配置");
+        info!("{}", t!("list.direct_empty"));
+    }
+
+    Ok(())
+}
+
+/// 编辑Direct配置（支持交互式与命令行参数两种方式）
+pub fn cmd_edit_direct(name: String, args: DirectProfileArgs) -> AppResult<()> {
+    let config = Config::load()?;
+    let mut profile = config.get_direct_profile(&name)?.clone();
+    let old_token = profile.anthropic_auth_token.clone();
+
+    info!("{}", t!("edit.title", name));
+    info!();
+
+    if args.has_any_field() {
+        // 只更新命令行中指定的字段，可选字段传入空字符串表示清空
+        if let Some(token) = args.resolve_token()? {
+            profile.anthropic_auth_token = token;
+        }
+        if let Some(url) = &args.base_url {
+            profile.anthropic_base_url = url.trim().to_string();
+        }
+        if let Some(model) = &args.model {
+            profile.anthropic_model = optional_value(model);
+        }
+        if let Some(fast_model) = &args.small_fast_model {
+            profile.anthropic_small_fast_model = optional_value(fast_model);
+        }
+        if let Some(description) = &args.description {
+            profile.description = optional_value(description);
+        }
+    } else {
+        let token = OutputOptions::default().secret(&profile.anthropic_auth_token);
+        info!("{}", t!("edit.current_token", token));
+        if let Some(token) = read_optional_input(&t!("edit.prompt_keep"))? {
+            profile.anthropic_auth_token = token;
+        }
+
+        info!(
+            "{}",
+            t!("edit.current_url", url = profile.anthropic_base_url)
+        );
+        if let Some(url) = read_optional_input(&t!("edit.prompt_keep"))? {
+            profile.anthropic_base_url = url;
+        }
+
+        profile.anthropic_model =
+            prompt_optional_field("ANTHROPIC_MODEL", profile.anthropic_model.take())?;
+        profile.anthropic_small_fast_model = prompt_optional_field(
+            "ANTHROPIC_SMALL_FAST_MODEL",
+            profile.anthropic_small_fast_model.take(),
+        )?;
+        profile.description =
+            prompt_optional_field(&t!("edit.field_description"), profile.description.take())?;
+    }
+
+    save_direct_edit(&name, profile, &old_token)
+}
+
+/// 在外部编辑器中编辑 Direct 配置的 JSON
+///
+/// 明文令牌和像密钥的 `env` 值在临时文件中遮蔽（`--show-secrets` 时写入完整值），
+/// 未修改的遮蔽值保存时还原。
+pub fn cmd_edit_direct_raw(name: String, out: OutputOptions) -> AppResult<()> {
+    let config = Config::load()?;
+    let profile = config.get_direct_profile(&name)?.clone();
+    let old_token = profile.anthropic_auth_token.clone();
+
+    let mut masked = profile.clone();
+    masked.anthropic_auth_token = out.secret(&profile.anthropic_auth_token);
+    masked.env = out.env(&profile.env);
+    let unmask = |mut edited: DirectProfile| {
+        unmask_secret(
+            &mut edited.anthropic_auth_token,
+            &masked.anthropic_auth_token,
+            &profile.anthropic_auth_token,
+        );
+        unmask_env(&mut edited.env, &masked.env, &profile.env);
+        edited
+    };
+
+    let edited = editor::edit_json(&format!("direct-{name}"), &masked, |edited| {
+        config.validate_direct_profile(&unmask(edited.clone()))
+    })?;
+    match edited {
+        Some(edited) => save_direct_edit(&name, unmask(edited), &old_token),
+        None => Ok(()),
+    }
+}
+
+/// `--raw` 编辑后仍为遮蔽值的密钥还原为原值
+fn unmask_secret(edited: &mut String, masked: &str, original: &str) {
+    if edited == masked {
+        *edited = original.to_string();
+    }
+}
+
+/// `--raw` 编辑后仍为遮蔽值的 `env` 变量还原为原值
+fn unmask_env(
+    edited: &mut BTreeMap<String, String>,
+    masked: &BTreeMap<String, String>,
+    original: &BTreeMap<String, String>,
+) {
+    for (name, value) in edited.iter_mut() {
+        if let (Some(masked), Some(original)) = (masked.get(name), original.get(name)) {
+            unmask_secret(value, masked, original);
+        }
+    }
+}
+
+/// 保存编辑后的 Direct 配置，令牌变化时按密钥存储后端重新保存
+fn save_direct_edit(name: &str, mut profile: DirectProfile, old_token: &str) -> AppResult<()> {
+    profile.updated_at = Some(current_timestamp());
+    let token_changed = profile.anthropic_auth_token != old_token;
+
+    // 加锁后重新加载，避免覆盖其他进程在交互期间的修改
+    let _lock = Config::lock()?;
+    let mut config = Config::load()?;
+    config.update_direct_profile(name, profile)?;
+    if token_changed {
+        store_direct_token(&mut config, name)?;
+    }
+    config.save()?;
+
+    // 新令牌没有写回原来的密钥位置时，清理旧密钥
+    if token_changed && config.groups.direct[name].anthropic_auth_token != old_token {
+        discard_secret(&config, old_token);
+    }
+
+    info!();
+    info!("{}", t!("edit.success", name));
+
+    Ok(())
+}
+
+/// 重命名Direct配置，保存在密钥存储后端中的令牌随之改用新名称
+pub fn cmd_rename_direct(old: String, new: String) -> AppResult<()> {
+    check_new_name(&new)?;
+
+    let _lock = Config::lock()?;
+    let mut config = Config::load()?;
+    config.rename_direct_profile(&old, &new)?;
+
+    let old_token = config.groups.direct[&new].anthropic_auth_token.clone();
+    let token = copy_direct_token(&config, &old_token, &new)?;
+    if let Some(profile) = config.groups.direct.get_mut(&new) {
+        profile.anthropic_auth_token = token;
+        profile.updated_at = Some(current_timestamp());
+    }
+    config.save()?;
+
+    if config.groups.direct[&new].anthropic_auth_token != old_token {
+        discard_secret(&config, &old_token);
+    }
+
+    info!("{}", t!("rename.success", old, new));
+    Ok(())
+}
+
+/// 复制Direct配置，保存在密钥存储后端中的令牌单独保存一份
+pub fn cmd_copy_direct(source: String, target: String) -> AppResult<()> {
+    check_new_name(&target)?;
+
+    let _lock = Config::lock()?;
+    let mut config = Config::load()?;
+    let mut profile = config.get_direct_profile(&source)?.clone();
+    profile.created_at = Some(current_timestamp());
+    profile.updated_at = None;
+    config.add_direct_profile(target.clone(), profile)?;
+
+    let token = copy_direct_token(
+        &config,
+        &config.groups.direct[&target].anthropic_auth_token,
+        &target,
+    )?;
+    if let Some(profile) = config.groups.direct.get_mut(&target) {
+        profile.anthropic_auth_token = token;
     }
+    config.save()?;
 
+    info!("{}", t!("copy.success", source, target));
     Ok(())
 }
 
+/// 令牌保存在密钥存储后端时，以指定配置名称另存一份并返回新的引用，其余令牌原样返回
+fn copy_direct_token(config: &Config, token: &str, name: &str) -> AppResult<String> {
+    if secrets::parse_secret_ref(token).is_none() {
+        return Ok(token.to_string());
+    }
+
+    let backend = config.open_secret_backend()?;
+    let value = secrets::resolve(token, backend.as_ref())?;
+    backend.store(&secrets::direct_secret_key(name), &value)
+}
+
 /// 列出CCR配置（Router Profile）
-pub fn cmd_list_ccr() -> AppResult<()> {
+pub fn cmd_list_ccr(out: OutputOptions) -> AppResult<()> {
     let manager = CcrConfigManager::new()?;
 
+    if !out.is_text() {
+        let profiles = manager.get_router_profiles()?;
+        let view = RouterProfilesView {
+            profiles: RouterProfileView::list(
+                profiles
+                    .iter()
+                    .map(|(name, profile, is_default)| (name.clone(), profile, *is_default))
+                    .collect(),
+                &out,
+            ),
+            active_router: if manager.config_exists() {
+                Some(RouterView::from(&manager.get_current_router()?))
+            } else {
+                None
+            },
+        };
+        return out.emit("RouterProfileList", &view);
+    }
+
     // 列出前配置同步 - 读取CCR配置文件，更新provider信息
     manager.sync_config_from_ccr()?;
 
-    println!("📋 CCR配置 (Router Profile) 列表：");
-    println!();
+    outln!("{}", t!("list_ccr.title"));
+    outln!();
 
     // 使用智能获取方法
     let profiles = manager.get_router_profiles()?;
@@ -586,16 +1091,16 @@ pub fn cmd_list_ccr() -> AppResult<()> {
         // 检查具体原因并给出相应提示
         match manager.ensure_router_profile_exists()? {
             crate::ccr_config::RouterProfileStatus::NeedCreateProvider => {
-                println!("❌ 暂无CCR配置");
-                println!();
-                println!("💡 要开始使用CCR，请按以下步骤操作:");
-                println!("   1. ccode provider add <name>     # 添加Provider");
-                println!("   2. ccode add-ccr <name>          # 添加CCR配置");
+                outln!("{}", t!("list_ccr.empty"));
+                outln!();
+                info!("{}", t!("list_ccr.getting_started"));
+                outln!("   {}", t!("list_ccr.step_add_provider"));
+                outln!("   {}", t!("list_ccr.step_add_ccr"));
                 return Ok(());
             }
             _ => {
-                println!("❌ 暂无CCR配置");
-                println!("💡 使用 'ccode add-ccr <name>' 添加CCR配置");
+                outln!("{}", t!("list_ccr.empty"));
+                info!("{}", t!("list_ccr.hint_add"));
                 return Ok(());
             }
         }
@@ -603,129 +1108,326 @@ pub fn cmd_list_ccr() -> AppResult<()> {
 
     // 显示Router Profile列表
     for (name, profile, is_default) in profiles {
-        let default_marker = if is_default { " (默认)" } else { "" };
-        println!("🎯 {name}{default_marker}");
-        println!("   🚀 默认路由: {}", profile.router.default);
+        let default_marker = if is_default {
+            t!("list.default_marker")
+        } else {
+            String::new()
+        };
+        outln!("🎯 {name}{default_marker}");
+        outln!("   {}", t!("route.default", route = profile.router.default));
 
         if let Some(background) = &profile.router.background {
-            println!("   🔄 后台路由: {background}");
+            outln!("   {}", t!("route.background", route = background));
         }
         if let Some(think) = &profile.router.think {
-            println!("   💭 思考路由: {think}");
+            outln!("   {}", t!("route.think", route = think));
         }
         if let Some(long_context) = &profile.router.long_context {
-            println!("   📜 长上下文路由: {long_context}");
+            outln!("   {}", t!("route.long_context", route = long_context));
         }
         if let Some(web_search) = &profile.router.web_search {
-            println!("   🔍 网络搜索路由: {web_search}");
+            outln!("   {}", t!("route.web_search", route = web_search));
+        }
+
+        if !profile.env.is_empty() {
+            outln!("   {}", t!("profile.env", names = env_names(&profile.env)));
         }
 
         if let Some(desc) = &profile.description {
-            println!("   📝 描述: {desc}");
+            outln!("   {}", t!("profile.description", desc));
         }
 
         if let Some(created) = &profile.created_at {
-            println!("   📅 创建: {created}");
+            outln!("   {}", t!("profile.created", created));
+        }
+
+        if let Some(updated) = &profile.updated_at {
+            outln!("   {}", t!("profile.updated", updated));
         }
 
-        println!();
+        outln!();
     }
 
     // 显示当前应用的路由配置
     if manager.config_exists() {
-        println!("📊 当前应用的路由配置：");
+        outln!("{}", t!("list_ccr.active_title"));
         let current_router = manager.get_current_router()?;
-        println!("🎯 默认: {}", current_router.default);
+        outln!(
+            "{}",
+            t!("active_route.default", route = current_router.default)
+        );
         if let Some(background) = &current_router.background {
-            println!("🔄 后台: {background}");
+            outln!("{}", t!("active_route.background", route = background));
         }
         if let Some(think) = &current_router.think {
-            println!("💭 思考: {think}");
+            outln!("{}", t!("active_route.think", route = think));
         }
         if let Some(long_context) = &current_router.long_context {
-            println!("📜 长上下文: {long_context}");
+            outln!("{}", t!("active_route.long_context", route = long_context));
         }
         if let Some(web_search) = &current_router.web_search {
-            println!("🔍 网络搜索: {web_search}");
+            outln!("{}", t!("active_route.web_search", route = web_search));
         }
 
         // 显示Provider统计
         if let Ok(providers) = manager.list_providers() {
-            println!();
-            println!("🔗 可用 Provider: {}", providers.len());
+            outln!();
+            outln!(
+                "{}",
+                t!("list_ccr.available_providers", count = providers.len())
+            );
         }
     } else {
-        println!("⚠️  claude-code-router 配置文件不存在");
-        println!("💡 请先使用 'ccode provider add <name>' 添加 Provider");
+        warn!("{}", t!("list_ccr.no_ccr_config"));
+        info!("{}", t!("hint.add_provider_first"));
     }
 
     Ok(())
 }
 
-/// 添加CCR配置（Router Profile）
-pub fn cmd_add_ccr(name: String) -> AppResult<()> {
-    let manager = CcrConfigManager::new()?;
+/// Router Profile的命令行参数（用于声明式添加）
+#[derive(Debug, Default)]
+pub struct RouterProfileArgs {
+    pub default: Option<String>,
+    pub background: Option<String>,
+    pub think: Option<String>,
+    pub long_context: Option<String>,
+    pub long_context_threshold: Option<u32>,
+    pub web_search: Option<String>,
+    pub description: Option<String>,
+    pub from_file: Option<PathBuf>,
+}
 
-    // 添加前配置同步 - 读取CCR配置文件，同步providers信息
-    manager.sync_config_from_ccr()?;
+impl RouterProfileArgs {
+    /// 是否通过命令行或文件声明了路由配置
+    fn is_declarative(&self) -> bool {
+        self.has_route_field() || self.description.is_some()
+    }
 
-    // 检查是否已存在同名Router Profile
-    let config = Config::load().unwrap_or_default();
-    if config.groups.router.contains_key(&name) {
-        return Err(AppError::Config(format!("Router Profile '{name}' 已存在")));
+    /// 是否提供了路由相关的字段（描述以外）
+    fn has_route_field(&self) -> bool {
+        self.default.is_some()
+            || self.background.is_some()
+            || self.think.is_some()
+            || self.long_context.is_some()
+            || self.long_context_threshold.is_some()
+            || self.web_search.is_some()
+            || self.from_file.is_some()
     }
 
-    // 检查是否有可用的 Providers
-    if !manager.config_exists() {
-        return Err(AppError::Config(
-            "未找到 claude-code-router 配置文件，请先使用 'ccode provider add <name>' 添加 Provider".to_string()
-        ));
+    /// 用命令行参数覆盖路由字段，可选路由传入空字符串表示清空
+    fn apply_overrides(&self, router: &mut CcrRouter) {
+        if let Some(default) = &self.default {
+            router.default = default.trim().to_string();
+        }
+
+        let overrides = [
+            (&self.background, &mut router.background),
+            (&self.think, &mut router.think),
+            (&self.long_context, &mut router.long_context),
+            (&self.web_search, &mut router.web_search),
+        ];
+        for (arg, field) in overrides {
+            if let Some(value) = arg {
+                *field = optional_value(value);
+            }
+        }
+
+        if let Some(threshold) = self.long_context_threshold {
+            router.long_context_threshold = Some(threshold);
+        }
     }
+}
 
-    let providers = manager.list_providers()?;
-    if providers.is_empty() {
-        return Err(AppError::Config(
-            "暂无可用的 Provider，请先使用 'ccode provider add <name>' 添加 Provider".to_string(),
-        ));
+/// 检查路由引用的 Provider 与模型是否存在
+/// Provider 不存在或格式错误时返回错误，模型不存在时按路由校验模式给出警告或返回错误
+fn check_route_reference(
+    route_desc: &str,
+    route: &str,
+    providers: &[CcrProvider],
+) -> AppResult<()> {
+    let route_parts: Vec<&str> = route.split(',').collect();
+    if route_parts.len() != 2 {
+        return Err(AppError::InvalidConfig(t!(
+            "route.invalid_format",
+            route_desc
+        )));
     }
 
-    println!("🎯 添加新的CCR配置 (Router Profile): {name}");
-    println!();
+    match check_route(route_desc, route, providers) {
+        Some(issue) if issue.is_error(RouteValidation::current()) => Err(issue.into_error()),
+        Some(issue) => {
+            report_route_warnings(&[issue]);
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
 
-    // 显示可用的 Providers
-    println!("📋 可用的 Providers:");
-    for (index, provider) in providers.iter().enumerate() {
-        println!(
-            "  {}. {} [{}]",
-            index + 1,
-            provider.name,
-            provider
-                .provider_type
-                .as_ref()
-                .map(|t| t.display_name())
-                .unwrap_or("未知类型")
+/// 提示未阻止保存的路由问题
+fn report_route_warnings(warnings: &[RouteIssue]) {
+    for issue in warnings {
+        warn!("{}", t!("route.issue_warning", issue));
+    }
+}
+
+/// 从 --from-file 读取 Router 配置
+/// 文件内容可以是完整的 Router Profile（包含 router 节点），也可以只是 Router 节点
+fn load_router_file(path: &PathBuf) -> AppResult<(CcrRouter, Option<String>)> {
+    let content = fs::read_to_string(path).map_err(|e| {
+        AppError::Config(t!("add_ccr.router_file_failed", path = path.display(), e))
+    })?;
+    let value: serde_json::Value = serde_json::from_str(&content)?;
+
+    match value.get("router") {
+        Some(router) => {
+            let router: CcrRouter = serde_json::from_value(router.clone())?;
+            let description = value
+                .get("description")
+                .and_then(|d| d.as_str())
+                .map(str::to_string);
+            Ok((router, description))
+        }
+        None => Ok((serde_json::from_value(value)?, None)),
+    }
+}
+
+/// 根据命令行参数（及可选的配置文件）构建 Router 配置
+fn build_router_from_args(
+    args: &RouterProfileArgs,
+    providers: &[CcrProvider],
+) -> AppResult<(CcrRouter, Option<String>)> {
+    let (router, mut description) = match &args.from_file {
+        Some(path) => {
+            let (router, description) = load_router_file(path)?;
+            (Some(router), description)
+        }
+        None => (None, None),
+    };
+
+    let mut router = match router {
+        Some(router) => router,
+        None => match &args.default {
+            Some(default) => CcrRouter::new(default.trim().to_string()),
+            None => return Err(AppError::InvalidConfig(t!("add_ccr.missing_default"))),
+        },
+    };
+
+    // 命令行参数覆盖文件中的同名字段
+    args.apply_overrides(&mut router);
+
+    if args.description.is_some() {
+        description = args.description.clone();
+    }
+
+    check_router(&router, providers)?;
+
+    Ok((router, description))
+}
+
+/// 校验路由格式，以及引用的 Provider 与模型是否存在
+fn check_router(router: &CcrRouter, providers: &[CcrProvider]) -> AppResult<()> {
+    router.validate()?;
+
+    let warnings = router.validate_references(providers, RouteValidation::current())?;
+    report_route_warnings(&warnings);
+
+    Ok(())
+}
+
+/// 添加CCR配置（Router Profile，支持交互式与命令行参数两种方式）
+pub fn cmd_add_ccr(name: String, args: RouterProfileArgs) -> AppResult<()> {
+    let manager = CcrConfigManager::new()?;
+
+    // 添加前配置同步 - 读取CCR配置文件，同步providers信息
+    manager.sync_config_from_ccr()?;
+
+    // 检查是否已存在同名Router Profile
+    let config = Config::load().unwrap_or_default();
+    if config.groups.router.contains_key(&name) {
+        return Err(AppError::Config(t!("router_profile.already_exists", name)));
+    }
+
+    // 检查是否有可用的 Providers
+    if !manager.config_exists() {
+        return Err(AppError::Config(t!("add_ccr.no_ccr_config")));
+    }
+
+    let providers = manager.list_providers()?;
+    if providers.is_empty() {
+        return Err(AppError::Config(t!("add_ccr.no_providers")));
+    }
+
+    info!("{}", t!("add_ccr.title", name));
+    info!();
+
+    let (router, description) = if args.is_declarative() {
+        build_router_from_args(&args, &providers)?
+    } else {
+        display_available_providers(&providers);
+        prompt_router_profile(&providers)?
+    };
+
+    // 创建 Router Profile
+    let mut router_profile = RouterProfile::new(name.clone(), router, description)?;
+    router_profile.created_at = Some(current_timestamp());
+
+    // 添加到本地配置
+    manager.add_router_profile(name.clone(), router_profile)?;
+
+    info!("{}", t!("add_ccr.success", name));
+
+    // 检查是否是第一个Router Profile
+    let updated_config = Config::load()?;
+    if updated_config.groups.router.len() == 1 {
+        info!("{}", t!("add_ccr.auto_default"));
+    }
+
+    Ok(())
+}
+
+/// 显示可用的 Providers 及其模型列表
+fn display_available_providers(providers: &[CcrProvider]) {
+    // 显示可用的 Providers
+    info!("{}", t!("providers.available"));
+    for (index, provider) in providers.iter().enumerate() {
+        info!(
+            "  {}. {} [{}]",
+            index + 1,
+            provider.name,
+            provider
+                .provider_type
+                .as_ref()
+                .map(|t| t.display_name())
+                .unwrap_or_else(|| t!("provider_type.unknown_type"))
+        );
+        info!("     📍 API URL: {}", provider.api_base_url);
+        info!(
+            "     {}",
+            t!("providers.model_list", count = provider.models.len())
         );
-        println!("     📍 API URL: {}", provider.api_base_url);
-        println!("     🤖 模型列表 ({} 个):", provider.models.len());
 
         // 显示所有模型，如果模型过多则分组显示
         if provider.models.len() <= 8 {
             for (model_idx, model) in provider.models.iter().enumerate() {
-                println!("        {}. {}", model_idx + 1, model);
+                info!("        {}. {}", model_idx + 1, model);
             }
         } else {
             // 显示前6个模型和最后2个模型
             for (model_idx, model) in provider.models.iter().take(6).enumerate() {
-                println!("        {}. {}", model_idx + 1, model);
+                info!("        {}. {}", model_idx + 1, model);
             }
-            println!("        ... ({} 个模型)", provider.models.len() - 8);
+            info!(
+                "        {}",
+                t!("providers.more_models", count = provider.models.len() - 8)
+            );
             for (model_idx, model) in provider
                 .models
                 .iter()
                 .skip(provider.models.len() - 2)
                 .enumerate()
             {
-                println!(
+                info!(
                     "        {}. {}",
                     provider.models.len() - 2 + model_idx + 1,
                     model
@@ -737,139 +1439,101 @@ pub fn cmd_add_ccr(name: String) -> AppResult<()> {
         if let Some(provider_type) = &provider.provider_type {
             let hints = provider_type.get_configuration_hints();
             if !hints.is_empty() {
-                println!("     💡 特色功能:");
+                info!("     {}", t!("providers.features"));
                 for hint in hints.iter().take(2) {
                     // 只显示前2个提示避免过长
-                    println!("        {hint}");
+                    info!("        {hint}");
                 }
             }
         }
-        println!();
+        info!();
     }
+}
 
+/// 交互式配置 Router 路由规则
+fn prompt_router_profile(providers: &[CcrProvider]) -> AppResult<(CcrRouter, Option<String>)> {
     // 配置默认路由
-    println!("🎯 配置默认路由 (格式: provider,model):");
+    info!("{}", t!("add_ccr.default_route_title"));
 
     // 提供智能推荐
     if !providers.is_empty() {
-        println!("💡 智能推荐路由:");
+        info!("{}", t!("add_ccr.recommended"));
         let mut recommendations = Vec::new();
 
-        for provider in &providers {
+        for provider in providers {
             if let Some(first_model) = provider.models.first() {
                 let route = format!("{},{}", provider.name, first_model);
                 let reason = if let Some(provider_type) = &provider.provider_type {
                     match provider_type {
-                        crate::config::ProviderType::OpenAI => "🔑 最稳定兼容",
-                        crate::config::ProviderType::OpenRouter => "🌐 多模型支持",
-                        crate::config::ProviderType::DeepSeek => "🧠 强大的推理能力",
-                        crate::config::ProviderType::Gemini => "🚀 Google最新技术",
-                        crate::config::ProviderType::Qwen => "🎨 中文优化",
-                        crate::config::ProviderType::Custom => "⚙️ 自定义配置",
+                        crate::config::ProviderType::OpenAI => t!("recommend.openai"),
+                        crate::config::ProviderType::OpenRouter => t!("recommend.openrouter"),
+                        crate::config::ProviderType::DeepSeek => t!("recommend.deepseek"),
+                        crate::config::ProviderType::Gemini => t!("recommend.gemini"),
+                        crate::config::ProviderType::Qwen => t!("recommend.qwen"),
+                        crate::config::ProviderType::Custom => t!("recommend.custom"),
                     }
                 } else {
-                    "💻 通用类型"
+                    t!("recommend.generic")
                 };
                 recommendations.push((route, reason));
             }
         }
 
         for (index, (route, reason)) in recommendations.iter().enumerate() {
-            println!("  {}. {} - {}", index + 1, route, reason);
+            info!("  {}. {} - {}", index + 1, route, reason);
         }
-        println!();
+        info!();
     }
 
-    print!("默认路由: ");
-    io::stdout().flush().unwrap();
+    console::prompt(&format!("{}: ", t!("route_desc.default")));
     let mut default_route = String::new();
     io::stdin().read_line(&mut default_route)?;
     let default_route = default_route.trim().to_string();
 
-    if default_route.is_empty() || !default_route.contains(',') {
-        return Err(AppError::InvalidConfig(
-            "默认路由格式无效，应为'provider,model'格式".to_string(),
-        ));
-    }
-
-    // 验证路由配置是否有效
-    let route_parts: Vec<&str> = default_route.split(',').collect();
-    if route_parts.len() != 2 {
-        return Err(AppError::InvalidConfig(
-            "路由格式错误，应为'provider,model'格式".to_string(),
-        ));
-    }
-
-    let (provider_name, model_name) = (route_parts[0].trim(), route_parts[1].trim());
-
-    // 验证provider和model是否存在
-    let provider_exists = providers.iter().any(|p| p.name == provider_name);
-    if !provider_exists {
-        return Err(AppError::InvalidConfig(format!(
-            "提供商 '{provider_name}' 不存在"
-        )));
-    }
-
-    let model_exists = providers
-        .iter()
-        .find(|p| p.name == provider_name)
-        .map(|p| p.models.contains(&model_name.to_string()))
-        .unwrap_or(false);
-
-    if !model_exists {
-        println!(
-            "⚠️  警告: 模型 '{model_name}' 在提供商 '{provider_name}' 中不存在，请确认模型名称是否正确"
-        );
-    }
+    check_route_reference(&t!("route_desc.default"), &default_route, providers)?;
 
     // 创建基础 Router 配置
     let mut router = CcrRouter::new(default_route);
 
     // 可选路由配置
     let optional_routes = [
-        ("background", "🔄 后台任务路由"),
-        ("think", "💭 思考任务路由"),
-        ("longContext", "📜 长上下文路由"),
-        ("webSearch", "🔍 网络搜索路由"),
+        ("background", t!("route_desc.background")),
+        ("think", t!("route_desc.think")),
+        ("longContext", t!("route_desc.long_context")),
+        ("webSearch", t!("route_desc.web_search")),
     ];
 
     for (route_key, route_desc) in optional_routes.iter() {
-        println!();
-        println!("{route_desc}:");
+        info!();
+        info!("{route_desc}:");
 
         // 为不同路由类型提供智能推荐
-        let route_recommendations = get_route_recommendations(route_key, &providers);
+        let route_recommendations = get_route_recommendations(route_key, providers);
         if !route_recommendations.is_empty() {
-            println!("💡 推荐选项:");
+            info!("{}", t!("add_ccr.recommended_options"));
             for (index, (route, reason)) in route_recommendations.iter().enumerate() {
-                println!("  {}. {} - {}", index + 1, route, reason);
+                info!("  {}. {} - {}", index + 1, route, reason);
             }
         }
 
-        print!("配置 {route_desc} (直接回车跳过): ");
-        io::stdout().flush().unwrap();
+        console::prompt(&t!("add_ccr.prompt_route", route_desc));
         let mut route_input = String::new();
         io::stdin().read_line(&mut route_input)?;
         let route_input = route_input.trim();
 
         if !route_input.is_empty() {
             if !route_input.contains(',') {
-                println!("⚠️  路由格式应为'provider,model'，跳过此设置");
+                warn!("{}", t!("add_ccr.invalid_route_skip"));
                 continue;
             }
 
-            // 验证路由配置
-            let parts: Vec<&str> = route_input.split(',').collect();
-            if parts.len() == 2 {
-                let (p_name, m_name) = (parts[0].trim(), parts[1].trim());
-                if !providers.iter().any(|p| p.name == p_name) {
-                    println!("⚠️  警告: 提供商 '{p_name}' 不存在");
-                } else if !providers
-                    .iter()
-                    .any(|p| p.name == p_name && p.models.contains(&m_name.to_string()))
-                {
-                    println!("⚠️  警告: 模型 '{m_name}' 在提供商 '{p_name}' 中不存在");
+            // 验证路由配置：会被拒绝的路由直接跳过，避免保存时失败
+            if let Some(issue) = check_route(route_desc, route_input, providers) {
+                if issue.is_error(RouteValidation::current()) {
+                    warn!("{}", t!("add_ccr.route_skipped", issue));
+                    continue;
                 }
+                report_route_warnings(&[issue]);
             }
 
             match *route_key {
@@ -883,8 +1547,7 @@ pub fn cmd_add_ccr(name: String) -> AppResult<()> {
     }
 
     // 配置长上下文阈值
-    print!("⚖️  长上下文阈值 (默认: 60000): ");
-    io::stdout().flush().unwrap();
+    console::prompt(&t!("add_ccr.prompt_threshold"));
     let mut threshold_input = String::new();
     io::stdin().read_line(&mut threshold_input)?;
     let threshold_input = threshold_input.trim();
@@ -895,39 +1558,15 @@ pub fn cmd_add_ccr(name: String) -> AppResult<()> {
                 router.long_context_threshold = Some(threshold);
             }
             Err(_) => {
-                println!("⚠️  无效的阈值格式，使用默认值 60000");
+                warn!("{}", t!("add_ccr.invalid_threshold"));
             }
         }
     }
 
     // 获取描述
-    print!("📝 描述 (可选): ");
-    io::stdout().flush().unwrap();
-    let mut description = String::new();
-    io::stdin().read_line(&mut description)?;
-    let description = description.trim();
-    let description = if description.is_empty() {
-        None
-    } else {
-        Some(description.to_string())
-    };
-
-    // 创建 Router Profile
-    let mut router_profile = RouterProfile::new(name.clone(), router, description)?;
-    router_profile.created_at = Some(Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string());
-
-    // 添加到本地配置
-    manager.add_router_profile(name.clone(), router_profile)?;
-
-    println!("✅ CCR配置 (Router Profile) '{name}' 添加成功！");
-
-    // 检查是否是第一个Router Profile
-    let updated_config = Config::load()?;
-    if updated_config.groups.router.len() == 1 {
-        println!("🎯 已自动设为默认CCR配置");
-    }
+    let description = read_optional_input(&t!("add_ccr.prompt_description"))?;
 
-    Ok(())
+    Ok((router, description))
 }
 
 /// 使用CCR配置（激活Router Profile）
@@ -937,81 +1576,67 @@ pub fn cmd_use_ccr(name: String) -> AppResult<()> {
     // 激活前配置同步 - 读取CCR配置文件，更新provider信息
     manager.sync_config_from_ccr()?;
 
-    println!("🎯 激活CCR配置: {name}");
-    println!();
+    info!("{}", t!("use_ccr.title", name));
+    info!();
 
     // 尝试获取指定的Router Profile（支持智能生成）
     let router_profile = manager.get_router_profile(&name)?;
 
     // 显示要激活的配置信息
-    println!("📋 配置信息:");
-    println!("   🚀 默认路由: {}", router_profile.router.default);
+    info!("{}", t!("use_ccr.details"));
+    info!(
+        "   {}",
+        t!("route.default", route = router_profile.router.default)
+    );
     if let Some(background) = &router_profile.router.background {
-        println!("   🔄 后台路由: {background}");
+        info!("   {}", t!("route.background", route = background));
     }
     if let Some(think) = &router_profile.router.think {
-        println!("   💭 思考路由: {think}");
+        info!("   {}", t!("route.think", route = think));
     }
     if let Some(long_context) = &router_profile.router.long_context {
-        println!("   📜 长上下文路由: {long_context}");
+        info!("   {}", t!("route.long_context", route = long_context));
     }
     if let Some(web_search) = &router_profile.router.web_search {
-        println!("   🔍 网络搜索路由: {web_search}");
-    }
-    println!();
-
-    // 验证Router配置中的Provider引用
-    if manager.config_exists() {
-        let validation_errors = manager.validate_router_references()?;
-        if !validation_errors.is_empty() {
-            println!("⚠️  发现配置问题:");
-            for error in &validation_errors {
-                println!("   • {error}");
-            }
-            print!("是否仍要继续激活此配置？某些路由可能无法工作 (y/N): ");
-            io::stdout().flush().unwrap();
-            let mut input = String::new();
-            io::stdin().read_line(&mut input)?;
-
-            if input.trim().to_lowercase() != "y" {
-                println!("❌ 已取消激活");
-                println!("💡 请使用 'ccode provider add <name>' 添加缺失的 Provider");
-                return Ok(());
-            }
-        }
+        info!("   {}", t!("route.web_search", route = web_search));
     }
+    info!();
 
-    // 使用CcrConfigManager的集成方法进行激活和同步
-    manager.use_router_profile(&name)?;
+    // 使用CcrConfigManager的集成方法进行校验、激活和同步
+    let warnings = manager.use_router_profile(&name)?;
+    report_route_warnings(&warnings);
 
-    println!("✅ 已激活CCR配置 '{name}' 并同步到 claude-code-router");
-    println!("🎯 默认路由: {}", router_profile.router.default);
+    info!("{}", t!("use_ccr.success", name));
+    info!(
+        "{}",
+        t!("route.default", route = router_profile.router.default)
+    );
 
     Ok(())
 }
 
 /// 运行CCR配置（使用原生ccr命令）
-pub fn cmd_run_ccr(name: Option<String>) -> AppResult<()> {
+pub fn cmd_run_ccr(name: Option<String>, opts: LaunchOptions) -> AppResult<()> {
     let ccr_manager = CcrConfigManager::new()?;
 
     // 启动时配置同步 - 读取CCR配置文件，更新provider信息
     ccr_manager.sync_config_from_ccr()?;
 
-    println!("🚀 启动CCR配置...");
-    println!("💡 使用ccr原生命令管理");
-    println!();
+    info!("{}", t!("run_ccr.starting"));
+    info!("{}", t!("run_ccr.native"));
+    info!();
 
     let config = Config::load().unwrap_or_default();
 
     // 检查是否有 Router Profile 配置
     if config.groups.router.is_empty() {
-        println!("❌ 暂无 Router Profile 配置");
+        info!("{}", t!("run_ccr.no_profiles"));
         if !ccr_manager.config_exists() {
-            println!("💡 请先使用以下步骤配置:");
-            println!("   1. ccode provider add <name>  # 添加 Provider");
-            println!("   2. ccode add-ccr <name>       # 添加 Router Profile");
+            info!("{}", t!("run_ccr.setup_steps"));
+            info!("   {}", t!("run_ccr.step_provider"));
+            info!("   {}", t!("run_ccr.step_profile"));
         } else {
-            println!("💡 请使用 'ccode add-ccr <name>' 添加 Router Profile");
+            info!("{}", t!("run_ccr.hint_add"));
         }
         return Ok(());
     }
@@ -1025,79 +1650,225 @@ pub fn cmd_run_ccr(name: Option<String>) -> AppResult<()> {
         None => match config.get_default_router_profile() {
             Ok((default_name, profile)) => (default_name.clone(), profile),
             Err(_) => {
-                println!("❌ 未设置默认 Router Profile");
+                info!("{}", t!("run_ccr.no_default"));
                 let profiles = config.list_router_profiles();
                 if !profiles.is_empty() {
-                    println!("💡 可用的 Router Profile:");
+                    info!("{}", t!("run_ccr.available"));
                     for (name, _, _) in profiles {
-                        println!("   • {name}");
+                        info!("   • {name}");
                     }
-                    println!("使用方法: ccode run-ccr <profile-name>");
-                    println!("或者设置默认: ccode use-ccr <profile-name>");
+                    info!("{}", t!("run_ccr.usage"));
+                    info!("{}", t!("run_ccr.usage_default"));
                 }
                 return Ok(());
             }
         },
     };
 
-    println!("🎯 使用 Router Profile '{profile_name}'");
-    println!("🚀 默认路由: {}", router_profile.router.default);
+    info!("{}", t!("run_ccr.using", profile_name));
+    info!(
+        "{}",
+        t!("route.default", route = router_profile.router.default)
+    );
 
     // 显示路由配置信息
     if let Some(background) = &router_profile.router.background {
-        println!("🔄 后台路由: {background}");
+        info!("{}", t!("route.background", route = background));
     }
     if let Some(think) = &router_profile.router.think {
-        println!("💭 思考路由: {think}");
+        info!("{}", t!("route.think", route = think));
     }
     if let Some(long_context) = &router_profile.router.long_context {
-        println!("📜 长上下文路由: {long_context}");
+        info!("{}", t!("route.long_context", route = long_context));
     }
     if let Some(web_search) = &router_profile.router.web_search {
-        println!("🔍 网络搜索路由: {web_search}");
+        info!("{}", t!("route.web_search", route = web_search));
+    }
+    if !router_profile.env.is_empty() {
+        info!(
+            "{}",
+            t!("profile.env", names = env_names(&router_profile.env))
+        );
     }
-    println!();
+    info!();
 
     // 检查CCR配置文件是否存在
     if !ccr_manager.config_exists() {
-        println!("❌ 未找到 claude-code-router 配置文件");
-        println!("💡 请先使用 'ccode provider add <name>' 添加 Provider");
+        info!("{}", t!("run_ccr.no_ccr_config"));
+        info!("{}", t!("hint.add_provider_first"));
+        return Ok(());
+    }
+
+    let mut env = ChildEnv::new(opts.env);
+    env.extend(&router_profile.env);
+    env.extend(provider_secret_env(&config, &ccr_manager)?);
+    // ccr 启动时从环境读取以 `$VAR` 引用的 Provider 密钥
+    for provider in ccr_manager.list_providers()? {
+        if let Some(var) = provider.api_key.strip_prefix('$') {
+            env.keep(var.trim_matches(['{', '}']));
+        }
+    }
+
+    if opts.show_env {
+        env.preview("ccr code");
         return Ok(());
     }
 
     // 应用 Router Profile 到 claude-code-router 配置文件
-    println!("📄 应用 Router Profile 到配置文件...");
-    ccr_manager.apply_router_profile(router_profile)?;
+    info!("{}", t!("run_ccr.applying"));
+    let warnings = ccr_manager.apply_router_profile(router_profile)?;
+    report_route_warnings(&warnings);
 
     // 直接调用 ccr code 命令
-    println!("🎯 启动 ccr code...");
+    info!("{}", t!("run_ccr.launching"));
     let mut cmd = Command::new("ccr");
     cmd.arg("code");
+    env.apply(&mut cmd);
 
-    match cmd.status() {
-        Ok(status) => {
-            if status.success() {
-                println!("✅ ccr code 程序正常退出");
-            } else {
-                println!("⚠️  ccr code 程序异常退出，退出码: {:?}", status.code());
+    launch::launch(cmd, "ccr code", opts.mode, t!("run_ccr.ccr_not_found"))
+}
+
+/// 在配置的环境变量下运行任意程序
+///
+/// Direct 配置设置与 `ccode run` 相同的变量；Router Profile 先应用到 claude-code-router 并重启服务，
+/// 再将 `ANTHROPIC_BASE_URL` 指向本机的 ccr 服务。
+pub fn cmd_exec(
+    name: String,
+    group: Option<String>,
+    command: Vec<String>,
+    restart: bool,
+    opts: LaunchOptions,
+) -> AppResult<()> {
+    let Some((program, args)) = command.split_first() else {
+        return Err(AppError::Config(t!("exec.no_command")));
+    };
+    let group = resolve_group(&name, group)?;
+    let config = Config::load()?;
+
+    let env = match group.as_str() {
+        "direct" => resolve_direct_env(&config, config.get_direct_profile(&name)?, opts.env)?,
+        "ccr" => {
+            let profile = config.get_router_profile(&name)?;
+            let (ccr_manager, ccr_config) = load_ccr_service_config()?;
+            let env = router_profile_env(profile, &ccr_config, opts.env);
+
+            if !opts.show_env {
+                // ccr 服务由所有会话共享，只在路由变化、服务未运行或明确要求时重启
+                let router_changed = apply_router_to_service(&ccr_manager, profile, &ccr_config)?;
+                if restart || router_changed || !ccr_reachable(&ccr_config) {
+                    restart_ccr_service(&config, &ccr_manager, &ccr_config)?;
+                } else {
+                    info!("{}", t!("exec.ccr_unchanged"));
+                }
             }
+            env
         }
-        Err(e) => {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                return Err(AppError::CommandExecution(
-                    "找不到 'ccr' 程序，请确保 claude-code-router 已安装并在 PATH 中".to_string(),
-                ));
+        g => return Err(AppError::Config(t!("group.unknown", group = g))),
+    };
+
+    if opts.show_env {
+        env.preview(program);
+        return Ok(());
+    }
+
+    info!("{}", t!("exec.starting", name, program));
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+    env.apply(&mut cmd);
+
+    launch::launch(cmd, program, opts.mode, t!("exec.not_found", program))
+}
+
+/// 读取 ccr 服务使用的 claude-code-router 配置，配置文件不存在时报错
+fn load_ccr_service_config() -> AppResult<(CcrConfigManager, CcrConfig)> {
+    let manager = CcrConfigManager::new()?;
+    if !manager.config_exists() {
+        return Err(AppError::Config(t!("exec.no_ccr_config")));
+    }
+    let ccr_config = manager.load_config()?;
+    Ok((manager, ccr_config))
+}
+
+/// RouterProfile 的程序环境：指向本机 ccr 服务并使用 ccr 的客户端密钥
+fn router_profile_env(
+    profile: &RouterProfile,
+    ccr_config: &CcrConfig,
+    policy: EnvPolicy,
+) -> ChildEnv {
+    let mut env = ChildEnv::new(policy);
+    if let Some(timeout) = ccr_config.API_TIMEOUT_MS {
+        env.set("API_TIMEOUT_MS", timeout.to_string());
+    }
+    env.extend(&profile.env);
+    env.set("ANTHROPIC_BASE_URL", ccr_config.endpoint());
+    env.set("ANTHROPIC_AUTH_TOKEN", ccr_config.client_api_key());
+    env
+}
+
+/// 将 RouterProfile 应用到 ccr 配置文件（不重启服务），返回 Router 是否因此变化
+///
+/// `ccr_config` 为应用前读取的配置
+fn apply_router_to_service(
+    manager: &CcrConfigManager,
+    profile: &RouterProfile,
+    ccr_config: &CcrConfig,
+) -> AppResult<bool> {
+    info!("{}", t!("run_ccr.applying"));
+    let warnings = manager.apply_router_profile(profile)?;
+    report_route_warnings(&warnings);
+    Ok(serde_json::to_value(&ccr_config.Router)?
+        != serde_json::to_value(manager.get_current_router()?)?)
+}
+
+/// 重启（或启动）claude-code-router 服务使刚应用的 Router Profile 生效，并等待服务端口可以连接
+fn restart_ccr_service(
+    config: &Config,
+    manager: &CcrConfigManager,
+    ccr_config: &CcrConfig,
+) -> AppResult<()> {
+    info!("{}", t!("exec.restarting_ccr"));
+    // 丢弃 ccr 的标准输出，避免混进被运行程序的输出
+    let status = Command::new("ccr")
+        .arg("restart")
+        .envs(provider_secret_env(config, manager)?)
+        .stdout(Stdio::null())
+        .status()
+        .map_err(|e| {
+            if e.kind() == io::ErrorKind::NotFound {
+                AppError::CommandExecution(t!("run_ccr.ccr_not_found"))
             } else {
-                return Err(AppError::CommandExecution(format!(
-                    "执行 ccr code 失败: {e}"
-                )));
+                AppError::CommandExecution(t!("run.failed", program = "ccr restart", e))
             }
+        })?;
+    if !status.success() {
+        return Err(AppError::CommandExecution(t!(
+            "exec.ccr_restart_failed",
+            status
+        )));
+    }
+
+    for _ in 0..CCR_STARTUP_POLLS {
+        if ccr_reachable(ccr_config) {
+            return Ok(());
         }
+        thread::sleep(CCR_STARTUP_POLL_INTERVAL);
     }
+    Err(AppError::CommandExecution(t!(
+        "exec.ccr_unreachable",
+        endpoint = ccr_config.endpoint()
+    )))
+}
 
-    Ok(())
+/// ccr 服务端口是否可以连接
+fn ccr_reachable(ccr_config: &CcrConfig) -> bool {
+    let (host, port) = ccr_config.service_addr();
+    TcpStream::connect((host.as_str(), port)).is_ok()
 }
 
+/// 等待 ccr 服务启动的轮询次数和间隔（共约 10 秒）
+const CCR_STARTUP_POLLS: u32 = 50;
+const CCR_STARTUP_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
 /// 删除CCR配置（Router Profile）
 pub fn cmd_remove_ccr(name: String) -> AppResult<()> {
     let manager = CcrConfigManager::new()?;
@@ -1108,362 +1879,887 @@ pub fn cmd_remove_ccr(name: String) -> AppResult<()> {
         return Err(AppError::ProfileNotFound(name));
     }
 
-    println!("🗑️  删除CCR配置: {name}");
-    println!();
+    info!("{}", t!("remove_ccr.title", name));
+    info!();
 
     // 显示要删除的配置信息
     if let Ok(router_profile) = config.get_router_profile(&name) {
-        println!("📋 将要删除的配置:");
-        println!("   🚀 默认路由: {}", router_profile.router.default);
+        info!("{}", t!("remove_ccr.details"));
+        info!(
+            "   {}",
+            t!("route.default", route = router_profile.router.default)
+        );
         if let Some(background) = &router_profile.router.background {
-            println!("   🔄 后台路由: {background}");
+            info!("   {}", t!("route.background", route = background));
         }
         if let Some(think) = &router_profile.router.think {
-            println!("   💭 思考路由: {think}");
+            info!("   {}", t!("route.think", route = think));
         }
         if let Some(long_context) = &router_profile.router.long_context {
-            println!("   📜 长上下文路由: {long_context}");
+            info!("   {}", t!("route.long_context", route = long_context));
         }
         if let Some(web_search) = &router_profile.router.web_search {
-            println!("   🔍 网络搜索路由: {web_search}");
+            info!("   {}", t!("route.web_search", route = web_search));
         }
-        println!();
+        info!();
     }
 
     // 如果是默认配置，警告用户
     if let Some(default_profile) = &config.default_profile
         && default_profile.router.as_ref() == Some(&name)
     {
-        println!("⚠️  '{name}' 是当前的默认CCR配置");
-        println!("删除后需要重新设置默认配置");
-        println!();
+        warn!("{}", t!("remove_ccr.is_default", name));
+        warn!("{}", t!("remove_ccr.reset_default"));
+        info!();
     }
 
     // 确认删除
-    print!("确定要删除CCR配置 '{name}' 吗？(y/N): ");
-    io::stdout().flush().unwrap();
+    console::prompt(&t!("remove_ccr.confirm", name));
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
 
     let input = input.trim().to_lowercase();
     if input != "y" && input != "yes" {
-        println!("❌ 取消删除");
+        info!("{}", t!("remove.cancelled"));
         return Ok(());
     }
 
     // 删除Router Profile
     manager.remove_router_profile(&name)?;
 
-    println!("✅ CCR配置 '{name}' 已删除");
+    info!("{}", t!("remove_ccr.success", name));
 
     // 显示当前默认配置状态
     let updated_config = Config::load().unwrap_or_default();
     if !updated_config.groups.router.is_empty() {
         if let Some(default_profile) = &updated_config.default_profile {
             if let Some(router) = &default_profile.router {
-                println!("🎯 当前默认CCR配置: {router}");
+                info!("{}", t!("remove_ccr.current_default", router));
             } else {
-                println!("⚠️  无默认CCR配置，请使用 'ccode use-ccr <name>' 设置");
+                warn!("{}", t!("remove_ccr.no_default"));
             }
         }
     } else {
-        println!("📋 暂无CCR配置，请使用 'ccode add-ccr <name>' 添加配置");
+        info!("{}", t!("remove_ccr.empty"));
     }
 
     Ok(())
 }
 
-/// 列出所有 Providers
-pub fn cmd_provider_list() -> AppResult<()> {
+/// 编辑CCR配置（Router Profile，支持交互式与命令行参数两种方式）
+pub fn cmd_edit_ccr(name: String, args: RouterProfileArgs) -> AppResult<()> {
     let manager = CcrConfigManager::new()?;
 
-    // Provider命令启动时同步配置
+    // 编辑前配置同步 - 读取CCR配置文件，同步providers信息
     manager.sync_config_from_ccr()?;
 
+    let config = Config::load()?;
+    let mut profile = config.get_router_profile(&name)?.clone();
+
     if !manager.config_exists() {
-        println!("📋 暂无 claude-code-router 配置文件");
-        println!("💡 使用 'ccode provider add <name>' 添加第一个 Provider");
-        return Ok(());
+        return Err(AppError::Config(t!("add_ccr.no_ccr_config")));
     }
-
     let providers = manager.list_providers()?;
 
-    if providers.is_empty() {
-        println!("📋 暂无 Provider 配置");
-        println!("💡 使用 'ccode provider add <name>' 添加 Provider");
-        return Ok(());
-    }
-
-    println!("📋 Provider 列表：");
-    println!();
-
-    for provider in providers {
-        println!("🔗 {}", provider.name);
-        println!("   📍 URL: {}", provider.api_base_url);
-        println!(
-            "   🔑 API Key: {}...",
-            &provider.api_key[..7.min(provider.api_key.len())]
-        );
-        println!("   📊 模型数量: {}", provider.models.len());
+    info!("{}", t!("edit_ccr.title", name));
+    info!();
 
-        if let Some(provider_type) = &provider.provider_type {
-            println!("   🏷️  类型: {}", provider_type.display_name());
+    if args.is_declarative() {
+        if let Some(path) = &args.from_file {
+            let (router, description) = load_router_file(path)?;
+            profile.router = router;
+            if description.is_some() {
+                profile.description = description;
+            }
         }
-
-        if provider.models.len() <= 5 {
-            println!("   🤖 模型: {}", provider.models.join(", "));
-        } else {
-            println!(
-                "   🤖 模型: {} 等 {} 个",
-                provider.models[..3].join(", "),
-                provider.models.len()
-            );
+        args.apply_overrides(&mut profile.router);
+        if let Some(description) = &args.description {
+            profile.description = optional_value(description);
         }
-
-        println!();
+    } else {
+        prompt_router_edit(&mut profile)?;
     }
 
-    // 显示配置统计
-    let stats = manager.get_config_stats()?;
-    println!("📊 配置统计：");
-    print!("{}", stats.format_display());
-
-    Ok(())
+    check_router(&profile.router, &providers)?;
+    save_router_edit(&manager, &name, profile)
 }
 
-/// 添加 Provider
-pub fn cmd_provider_add(name: String) -> AppResult<()> {
+/// 在外部编辑器中编辑 Router Profile 的 JSON（像密钥的 `env` 值同样遮蔽）
+pub fn cmd_edit_ccr_raw(name: String, out: OutputOptions) -> AppResult<()> {
     let manager = CcrConfigManager::new()?;
-
-    // Provider命令启动时同步配置
     manager.sync_config_from_ccr()?;
 
-    // 检查 Provider 是否已存在
-    if manager.provider_exists(&name)? {
-        return Err(AppError::Config(format!("Provider '{name}' 已存在")));
-    }
+    let config = Config::load()?;
+    let profile = config.get_router_profile(&name)?.clone();
 
-    println!("🔗 添加新 Provider: {name}");
-    println!();
+    if !manager.config_exists() {
+        return Err(AppError::Config(t!("add_ccr.no_ccr_config")));
+    }
+    let providers = manager.list_providers()?;
 
-    // 选择 Provider 类型
-    println!("📋 选择 Provider 类型:");
-    let provider_types = [
-        ProviderType::OpenAI,
-        ProviderType::OpenRouter,
-        ProviderType::DeepSeek,
-        ProviderType::Gemini,
-        ProviderType::Qwen,
-        ProviderType::Custom,
-    ];
+    let mut masked = profile.clone();
+    masked.env = out.env(&profile.env);
+    let unmask = |mut edited: RouterProfile| {
+        unmask_env(&mut edited.env, &masked.env, &profile.env);
+        edited
+    };
 
-    for (index, provider_type) in provider_types.iter().enumerate() {
-        println!(
-            "  {}) {} ({})",
-            index + 1,
-            provider_type.display_name(),
-            provider_type.url_format_hint()
-        );
+    let edited = editor::edit_json(&format!("router-{name}"), &masked, |edited| {
+        if edited.name != name {
+            return Err(AppError::InvalidConfig(t!("editor.name_changed", name)));
+        }
+        let edited = unmask(edited.clone());
+        edited.validate()?;
+        check_router(&edited.router, &providers)
+    })?;
+    match edited {
+        Some(edited) => save_router_edit(&manager, &name, unmask(edited)),
+        None => Ok(()),
     }
+}
 
-    print!("请选择 [1-6]: ");
-    io::stdout().flush().unwrap();
-    let mut choice = String::new();
-    io::stdin().read_line(&mut choice)?;
-    let choice = choice.trim();
+/// 保存编辑后的 Router Profile，是当前默认配置时同步到 claude-code-router
+fn save_router_edit(
+    manager: &CcrConfigManager,
+    name: &str,
+    mut profile: RouterProfile,
+) -> AppResult<()> {
+    profile.updated_at = Some(current_timestamp());
 
-    let provider_type = match choice {
-        "1" => ProviderType::OpenAI,
-        "2" => ProviderType::OpenRouter,
-        "3" => ProviderType::DeepSeek,
-        "4" => ProviderType::Gemini,
-        "5" => ProviderType::Qwen,
-        "6" => ProviderType::Custom,
-        _ => {
-            println!("❌ 无效选择，默认使用OpenAI兼容类型");
-            ProviderType::OpenAI
-        }
-    };
+    let _lock = Config::lock()?;
+    let mut config = Config::load()?;
+    config.update_router_profile(name, profile.clone())?;
+    config.save()?;
 
-    println!();
-    println!("🔧 配置 {} 类型的Provider:", provider_type.display_name());
+    info!();
+    info!("{}", t!("edit_ccr.success", name));
 
-    // 显示配置提示
-    for hint in provider_type.get_configuration_hints() {
-        println!("  {hint}");
+    let is_default = config
+        .default_profile
+        .as_ref()
+        .and_then(|default_profile| default_profile.router.as_deref())
+        == Some(name);
+    if is_default {
+        // 路由警告已在保存前由 check_router 提示
+        manager.apply_router_profile(&profile)?;
+        info!("{}", t!("edit_ccr.applied"));
     }
-    println!();
-
-    // 获取 API 密钥
-    print!("🔑 请输入 API Key: ");
-    io::stdout().flush().unwrap();
-    let mut api_key = String::new();
-    io::stdin().read_line(&mut api_key)?;
-    let api_key = api_key.trim().to_string();
 
-    // 获取 API URL（可选）
-    println!("📍 API URL 配置:");
-    println!("  默认: {}", provider_type.url_format_hint());
-    print!("  自定义URL (直接回车使用默认): ");
-    io::stdout().flush().unwrap();
-    let mut api_url = String::new();
-    io::stdin().read_line(&mut api_url)?;
-    let api_url = api_url.trim();
-    let api_base_url = if api_url.is_empty() {
-        provider_type.url_format_hint().to_string()
-    } else {
-        api_url.to_string()
-    };
+    Ok(())
+}
 
-    // 获取模型列表
-    println!("🤖 模型配置:");
-    println!(
-        "  默认模型: {}",
-        provider_type.get_default_models().join(", ")
-    );
-    print!("  自定义模型列表 (用逗号分隔，直接回车使用默认): ");
-    io::stdout().flush().unwrap();
-    let mut models_input = String::new();
-    io::stdin().read_line(&mut models_input)?;
-    let models_input = models_input.trim();
-    let models = if models_input.is_empty() {
-        provider_type.get_default_models()
-    } else {
-        models_input
-            .split(',')
-            .map(|s| s.trim().to_string())
-            .collect()
-    };
+/// 重命名 Router Profile
+pub fn cmd_rename_ccr(old: String, new: String) -> AppResult<()> {
+    check_new_name(&new)?;
 
-    println!();
-    println!("🔧 正在创建 Provider...");
+    let _lock = Config::lock()?;
+    let mut config = Config::load()?;
+    config.rename_router_profile(&old, &new)?;
+    if let Some(profile) = config.groups.router.get_mut(&new) {
+        profile.updated_at = Some(current_timestamp());
+    }
+    config.save()?;
 
-    // 创建 Provider
-    let provider = CcrProvider::new(
-        name.clone(),
-        api_base_url,
-        api_key,
-        models,
-        provider_type.clone(),
-    );
+    info!("{}", t!("rename.success", old, new));
+    Ok(())
+}
 
-    // 添加 Provider
-    manager.add_provider(provider)?;
+/// 复制 Router Profile
+pub fn cmd_copy_ccr(source: String, target: String) -> AppResult<()> {
+    check_new_name(&target)?;
 
-    println!("✅ Provider '{name}' 添加成功！");
-    println!("🔗 类型: {}", provider_type.display_name());
+    let _lock = Config::lock()?;
+    let mut config = Config::load()?;
+    let mut profile = config.get_router_profile(&source)?.clone();
+    profile.name = target.clone();
+    profile.created_at = Some(current_timestamp());
+    profile.updated_at = None;
+    config.add_router_profile(target.clone(), profile)?;
+    config.save()?;
 
+    info!("{}", t!("copy.success", source, target));
     Ok(())
 }
 
-/// 删除 Provider
-pub fn cmd_provider_remove(name: String) -> AppResult<()> {
-    let manager = CcrConfigManager::new()?;
+/// 交互式编辑 Router Profile 的路由规则
+fn prompt_router_edit(profile: &mut RouterProfile) -> AppResult<()> {
+    let router = &mut profile.router;
 
-    // Provider命令启动时同步配置
-    manager.sync_config_from_ccr()?;
+    info!("{}", t!("route.default", route = router.default));
+    if let Some(default) = read_optional_input(&t!("edit.prompt_keep"))? {
+        router.default = default;
+    }
 
-    // 检查 Provider 是否存在
-    if !manager.provider_exists(&name)? {
-        return Err(AppError::Config(format!("Provider '{name}' 不存在")));
+    let optional_routes = [
+        (t!("route_desc.background"), &mut router.background),
+        (t!("route_desc.think"), &mut router.think),
+        (t!("route_desc.long_context"), &mut router.long_context),
+        (t!("route_desc.web_search"), &mut router.web_search),
+    ];
+    for (route_desc, field) in optional_routes {
+        info!();
+        *field = prompt_optional_field(&route_desc, field.take())?;
+    }
+
+    info!();
+    let threshold = router
+        .long_context_threshold
+        .map(|threshold| threshold.to_string())
+        .unwrap_or_else(|| t!("edit.unset"));
+    info!(
+        "{}",
+        t!(
+            "edit.current_value",
+            label = t!("edit.field_threshold"),
+            value = threshold
+        )
+    );
+    if let Some(input) = read_optional_input(&t!("edit.prompt_keep"))? {
+        match input.parse::<u32>() {
+            Ok(threshold) => router.long_context_threshold = Some(threshold),
+            Err(_) => warn!("{}", t!("edit.invalid_threshold")),
+        }
     }
 
-    // 检查是否被 Router 引用
-    let validation_errors = manager.validate_router_references()?;
-    let is_referenced = validation_errors.iter().any(|error| error.contains(&name));
+    info!();
+    profile.description =
+        prompt_optional_field(&t!("edit.field_description"), profile.description.take())?;
 
-    if is_referenced {
-        println!("⚠️  警告: Provider '{name}' 正被 Router 配置引用");
-        println!("删除后相关路由将失效，请确认是否继续");
-    }
+    Ok(())
+}
 
-    // 确认删除
-    print!("⚠️  确定要删除 Provider '{name}' 吗？(y/N): ");
-    io::stdout().flush().unwrap();
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
+/// 列出所有 Providers
+pub fn cmd_provider_list(out: OutputOptions) -> AppResult<()> {
+    let manager = CcrConfigManager::new()?;
 
-    let input = input.trim().to_lowercase();
-    if input != "y" && input != "yes" {
-        println!("❌ 取消删除");
+    if !out.is_text() {
+        let view = if manager.config_exists() {
+            ProvidersView {
+                providers: manager
+                    .list_providers()?
+                    .iter()
+                    .map(|provider| ProviderView::new(provider, &out))
+                    .collect(),
+                stats: Some(manager.get_config_stats()?),
+            }
+        } else {
+            ProvidersView {
+                providers: Vec::new(),
+                stats: None,
+            }
+        };
+        return out.emit("ProviderList", &view);
+    }
+
+    // Provider命令启动时同步配置
+    manager.sync_config_from_ccr()?;
+
+    if !manager.config_exists() {
+        outln!("{}", t!("provider_list.no_config"));
+        info!("{}", t!("provider_list.hint_first"));
+        return Ok(());
+    }
+
+    let providers = manager.list_providers()?;
+
+    if providers.is_empty() {
+        outln!("{}", t!("provider_list.empty"));
+        info!("{}", t!("provider_list.hint_add"));
+        return Ok(());
+    }
+
+    outln!("{}", t!("provider_list.title"));
+    outln!();
+
+    for provider in providers {
+        outln!("🔗 {}", provider.name);
+        outln!("   📍 URL: {}", provider.api_base_url);
+        outln!("   🔑 API Key: {}", out.secret(&provider.api_key));
+        outln!(
+            "   {}",
+            t!("provider.model_count", count = provider.models.len())
+        );
+
+        if let Some(provider_type) = &provider.provider_type {
+            outln!(
+                "   {}",
+                t!("provider.type", kind = provider_type.display_name())
+            );
+        }
+
+        if provider.models.len() <= 5 {
+            outln!(
+                "   {}",
+                t!("provider.models", models = provider.models.join(", "))
+            );
+        } else {
+            outln!(
+                "   {}",
+                t!(
+                    "provider.models_truncated",
+                    models = provider.models[..3].join(", "),
+                    count = provider.models.len()
+                )
+            );
+        }
+
+        outln!();
+    }
+
+    // 显示配置统计
+    let stats = manager.get_config_stats()?;
+    outln!("{}", t!("provider_list.stats"));
+    outln!("{}", stats.format_display().trim_end());
+
+    Ok(())
+}
+
+/// Provider的命令行参数（用于非交互式添加）
+#[derive(Debug, Default)]
+pub struct ProviderArgs {
+    pub provider_type: Option<String>,
+    pub api_key: Option<String>,
+    pub api_key_env: Option<String>,
+    pub url: Option<String>,
+    pub models: Option<String>,
+    pub transformer_json: Option<PathBuf>,
+    pub dry_run: bool,
+    pub non_interactive: bool,
+}
+
+impl ProviderArgs {
+    /// 是否通过命令行提供了任意字段
+    fn has_any_field(&self) -> bool {
+        self.provider_type.is_some()
+            || self.api_key.is_some()
+            || self.api_key_env.is_some()
+            || self.url.is_some()
+            || self.models.is_some()
+            || self.transformer_json.is_some()
+    }
+
+    /// 从 --api-key / --api-key-env 中解析 API Key
+    fn resolve_api_key(&self) -> AppResult<Option<String>> {
+        if let Some(api_key) = &self.api_key {
+            return Ok(Some(api_key.trim().to_string()));
+        }
+
+        if let Some(var) = &self.api_key_env {
+            let api_key =
+                std::env::var(var).map_err(|_| AppError::Config(t!("input.env_not_set", var)))?;
+            return Ok(Some(api_key.trim().to_string()));
+        }
+
+        Ok(None)
+    }
+
+    /// 读取 --transformer-json 指定的 transformer 配置
+    fn load_transformer(&self) -> AppResult<Option<serde_json::Value>> {
+        match &self.transformer_json {
+            Some(path) => {
+                let content = fs::read_to_string(path).map_err(|e| {
+                    AppError::Config(t!(
+                        "provider.transformer_file_failed",
+                        path = path.display(),
+                        e
+                    ))
+                })?;
+                Ok(Some(serde_json::from_str(&content)?))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// 解析逗号分隔的模型列表
+fn parse_model_list(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// 交互式选择 Provider 类型
+fn select_provider_type() -> AppResult<ProviderType> {
+    info!("{}", t!("provider_add.select_type"));
+    let provider_types = ProviderType::all();
+
+    for (index, provider_type) in provider_types.iter().enumerate() {
+        info!(
+            "  {}) {} ({})",
+            index + 1,
+            provider_type.display_name(),
+            provider_type.url_format_hint()
+        );
+    }
+
+    let choice = read_input(&t!("provider_add.prompt_type"))?;
+
+    let provider_type = match choice.parse::<usize>() {
+        Ok(index) if (1..=provider_types.len()).contains(&index) => {
+            provider_types[index - 1].clone()
+        }
+        _ => {
+            info!("{}", t!("provider_add.invalid_type"));
+            ProviderType::OpenAI
+        }
+    };
+
+    Ok(provider_type)
+}
+
+/// 添加 Provider（支持交互式与命令行参数两种方式）
+pub fn cmd_provider_add(name: String, args: ProviderArgs) -> AppResult<()> {
+    let manager = CcrConfigManager::new()?;
+
+    // Provider命令启动时同步配置
+    manager.sync_config_from_ccr()?;
+
+    // 检查 Provider 是否已存在
+    if manager.provider_exists(&name)? {
+        return Err(AppError::Config(t!("provider.already_exists", name)));
+    }
+
+    // 非交互模式下必需字段缺失时直接报错
+    if args.non_interactive {
+        if args.provider_type.is_none() {
+            return Err(AppError::InvalidConfig(t!(
+                "input.missing_flag",
+                flag = "--type"
+            )));
+        }
+        if args.api_key.is_none() && args.api_key_env.is_none() {
+            return Err(AppError::InvalidConfig(t!(
+                "input.missing_flag",
+                flag = "--api-key"
+            )));
+        }
+    }
+
+    // 提供了任意字段参数时，只对缺失的必需字段进行提示
+    let from_flags = args.non_interactive || args.has_any_field();
+
+    // 先解析参数，尽早发现错误
+    let provider_type = args
+        .provider_type
+        .as_deref()
+        .map(ProviderType::from_str)
+        .transpose()?;
+    let api_key = args.resolve_api_key()?;
+    let transformer = args.load_transformer()?;
+
+    info!("{}", t!("provider_add.title", name));
+    info!();
+
+    // 选择 Provider 类型
+    let provider_type = match provider_type {
+        Some(provider_type) => provider_type,
+        None => select_provider_type()?,
+    };
+
+    if !from_flags {
+        info!();
+        info!(
+            "{}",
+            t!(
+                "provider_add.configure",
+                kind = provider_type.display_name()
+            )
+        );
+
+        // 显示配置提示
+        for hint in provider_type.get_configuration_hints() {
+            info!("  {hint}");
+        }
+        info!();
+    }
+
+    // 获取 API 密钥
+    let api_key = match api_key {
+        Some(api_key) => api_key,
+        None => read_input(&t!("provider_add.prompt_key"))?,
+    };
+
+    // 获取 API URL（可选）
+    let api_base_url = match args.url {
+        Some(url) => url.trim().to_string(),
+        None if from_flags => provider_type.url_format_hint().to_string(),
+        None => {
+            info!("{}", t!("provider_add.url_title"));
+            info!(
+                "  {}",
+                t!(
+                    "provider_add.default_url",
+                    url = provider_type.url_format_hint()
+                )
+            );
+            let api_url = read_input(&t!("provider_add.prompt_url"))?;
+            if api_url.is_empty() {
+                provider_type.url_format_hint().to_string()
+            } else {
+                api_url
+            }
+        }
+    };
+
+    // 获取模型列表
+    let models = match args.models.as_deref() {
+        Some(models) => parse_model_list(models),
+        None if from_flags => provider_type.get_default_models(),
+        None => {
+            info!("{}", t!("provider_add.models_title"));
+            info!(
+                "  {}",
+                t!(
+                    "provider_add.default_models",
+                    models = provider_type.get_default_models().join(", ")
+                )
+            );
+            let models_input = read_input(&t!("provider_add.prompt_models"))?;
+            if models_input.is_empty() {
+                provider_type.get_default_models()
+            } else {
+                parse_model_list(&models_input)
+            }
+        }
+    };
+
+    info!();
+    info!("{}", t!("provider_add.creating"));
+
+    // 创建 Provider
+    let mut provider = CcrProvider::new(
+        name.clone(),
+        api_base_url,
+        api_key,
+        models,
+        provider_type.clone(),
+    );
+
+    // 显式指定的 transformer 覆盖自动生成的配置
+    if transformer.is_some() {
+        provider.transformer = transformer;
+    }
+
+    if args.dry_run {
+        // 与实际保存时相同地转换密钥，但不写入密钥存储
+        let kind = Config::load_or_default()?
+            .secret_backend
+            .unwrap_or_default();
+        provider.api_key = provider_key_value(&name, &provider.api_key, kind)?;
+        provider.validate()?;
+        if !provider.api_key.starts_with('$') {
+            provider.api_key = mask_secret(&provider.api_key);
+        }
+        info!("{}", t!("provider_add.dry_run"));
+        outln!("{}", serde_json::to_string_pretty(&provider)?);
         return Ok(());
     }
 
-    manager.remove_provider(&name)?;
-    println!("✅ Provider '{name}' 已删除");
+    // 添加 Provider
+    provider.api_key = store_provider_key(&name, &provider.api_key)?;
+    manager.add_provider(provider)?;
+
+    info!("{}", t!("provider_add.success", name));
+    info!(
+        "{}",
+        t!("provider_add.kind", kind = provider_type.display_name())
+    );
+
+    Ok(())
+}
+
+/// 删除 Provider
+///
+/// 仍有 RouterProfile 或 claude-code-router Router 节点引用该 Provider 时，默认阻止删除；
+/// `--cascade` 清空引用它的可选路由，`--reassign` 改为使用其他 Provider。
+pub fn cmd_provider_remove(
+    name: String,
+    force: bool,
+    cascade: bool,
+    reassign: Option<String>,
+) -> AppResult<()> {
+    let manager = CcrConfigManager::new()?;
+
+    // Provider命令启动时同步配置
+    manager.sync_config_from_ccr()?;
+
+    let providers = manager.list_providers()?;
+    let Some(removed) = providers.iter().find(|p| p.name == name) else {
+        return Err(AppError::Config(t!("provider.not_found", name)));
+    };
+
+    // 收集引用该 Provider 的路由
+    let config = Config::load_or_default()?;
+    let mut profile_names: Vec<&String> = config.groups.router.keys().collect();
+    profile_names.sort();
+    let mut references = Vec::new();
+    for profile_name in profile_names {
+        for (route, value) in config.groups.router[profile_name]
+            .router
+            .provider_routes(&name)
+        {
+            references.push((
+                t!("route_ref.profile_label", name = profile_name),
+                route,
+                value,
+            ));
+        }
+    }
+    for (route, value) in manager.get_current_router()?.provider_routes(&name) {
+        references.push((t!("route_ref.ccr_label"), route, value));
+    }
+
+    let mut removal = match (&reassign, cascade) {
+        (Some(target), _) => Some(ProviderRemoval::reassign(target, &name, &providers)?),
+        (None, true) => Some(ProviderRemoval::Cascade),
+        (None, false) => None,
+    };
+
+    if !references.is_empty() {
+        warn!(
+            "{}",
+            t!("provider_remove.referenced", name, count = references.len())
+        );
+        for (label, route, value) in &references {
+            warn!("   • {label} {route}: {value}");
+        }
+        info!();
+
+        // 默认路由不能清空，只能改用其他 Provider
+        let default_refs: Vec<&str> = references
+            .iter()
+            .filter(|(_, route, _)| route == "default")
+            .map(|(label, _, _)| label.as_str())
+            .collect();
+
+        if removal.is_none() {
+            if force {
+                return Err(AppError::Config(t!("provider_remove.blocked", name)));
+            }
+            removal = prompt_provider_removal(&name, &providers, default_refs.is_empty())?;
+            if removal.is_none() {
+                info!("{}", t!("remove.cancelled"));
+                return Ok(());
+            }
+        } else if matches!(removal, Some(ProviderRemoval::Cascade)) && !default_refs.is_empty() {
+            return Err(AppError::Config(t!(
+                "provider_remove.default_route",
+                name,
+                profiles = default_refs.join(", ")
+            )));
+        }
+    } else if !force {
+        // 确认删除
+        let input = read_input(&t!("provider_remove.confirm", name))?.to_lowercase();
+        if input != "y" && input != "yes" {
+            info!("{}", t!("remove.cancelled"));
+            return Ok(());
+        }
+    }
+
+    let removed_key = removed.api_key.clone();
+    let _lock = Config::lock()?;
+    let mut config = Config::load_or_default()?;
+
+    // 引用是在加锁前收集的，重新加载后再次检查
+    let profile_refs: Vec<(&String, Vec<(String, String)>)> = config
+        .groups
+        .router
+        .iter()
+        .map(|(profile_name, profile)| (profile_name, profile.router.provider_routes(&name)))
+        .filter(|(_, routes)| !routes.is_empty())
+        .collect();
+    match &removal {
+        None if !profile_refs.is_empty() => {
+            return Err(AppError::Config(t!("provider_remove.blocked", name)));
+        }
+        Some(ProviderRemoval::Cascade) => {
+            let mut default_refs: Vec<String> = profile_refs
+                .iter()
+                .filter(|(_, routes)| routes.iter().any(|(route, _)| route == "default"))
+                .map(|(profile_name, _)| t!("route_ref.profile_label", name = profile_name))
+                .collect();
+            if !default_refs.is_empty() {
+                default_refs.sort();
+                return Err(AppError::Config(t!(
+                    "provider_remove.default_route",
+                    name,
+                    profiles = default_refs.join(", ")
+                )));
+            }
+        }
+        _ => {}
+    }
+
+    let mut changed_routes = 0;
+    let mut config_changed = false;
+    if let Some(removal) = &removal {
+        for profile in config.groups.router.values_mut() {
+            let changed = removal.apply(&mut profile.router, &name);
+            if changed > 0 {
+                profile.updated_at = Some(current_timestamp());
+                changed_routes += changed;
+                config_changed = true;
+            }
+        }
+    }
+
+    changed_routes += manager.remove_provider_with(&name, removal.as_ref())?;
+
+    if removed_key == secrets::provider_env_ref(&name) {
+        if config.provider_key_refs.remove(&name).is_some() {
+            config_changed = true;
+        } else {
+            discard_secret(
+                &config,
+                &secrets::secret_ref(&secrets::provider_secret_key(&name)),
+            );
+        }
+    }
+    if config_changed {
+        config.save()?;
+    }
 
-    if is_referenced {
-        println!("💡 建议使用 'ccode router list' 检查相关路由配置");
+    info!("{}", t!("provider_remove.success", name));
+    match &removal {
+        Some(ProviderRemoval::Cascade) if changed_routes > 0 => {
+            info!("{}", t!("provider_remove.cleared", count = changed_routes));
+        }
+        Some(ProviderRemoval::Reassign { provider, .. }) if changed_routes > 0 => {
+            info!(
+                "{}",
+                t!(
+                    "provider_remove.reassigned",
+                    count = changed_routes,
+                    provider
+                )
+            );
+        }
+        _ => {}
     }
 
     Ok(())
 }
 
+/// 交互式选择如何处理引用被删除 Provider 的路由，返回 None 表示取消删除
+fn prompt_provider_removal(
+    name: &str,
+    providers: &[CcrProvider],
+    can_cascade: bool,
+) -> AppResult<Option<ProviderRemoval>> {
+    info!("{}", t!("provider_remove.choose"));
+    info!("  1) {}", t!("provider_remove.option_block"));
+    if can_cascade {
+        info!("  2) {}", t!("provider_remove.option_cascade"));
+    } else {
+        info!("  2) {}", t!("provider_remove.option_cascade_unavailable"));
+    }
+    info!("  3) {}", t!("provider_remove.option_reassign"));
+
+    match read_input(&t!("provider_remove.prompt_choice"))?.as_str() {
+        "2" if can_cascade => Ok(Some(ProviderRemoval::Cascade)),
+        "3" => {
+            let others: Vec<&str> = providers
+                .iter()
+                .map(|p| p.name.as_str())
+                .filter(|p| *p != name)
+                .collect();
+            if others.is_empty() {
+                return Err(AppError::Config(t!("provider_remove.no_other_provider")));
+            }
+            info!(
+                "{}",
+                t!("provider_remove.available", providers = others.join(", "))
+            );
+            let target = read_input(&t!("provider_remove.prompt_reassign"))?;
+            Ok(Some(ProviderRemoval::reassign(&target, name, providers)?))
+        }
+        _ => Ok(None),
+    }
+}
+
 /// 显示 Provider 详情
-pub fn cmd_provider_show(name: String) -> AppResult<()> {
+pub fn cmd_provider_show(name: String, out: OutputOptions) -> AppResult<()> {
     let manager = CcrConfigManager::new()?;
 
+    if !out.is_text() {
+        let provider = manager.get_provider(&name)?;
+        return out.emit("Provider", &ProviderView::new(&provider, &out));
+    }
+
     // Provider命令启动时同步配置
     manager.sync_config_from_ccr()?;
 
     let provider = manager.get_provider(&name)?;
 
-    println!("🔗 Provider: {}", provider.name);
-    println!();
-    println!("📍 API URL: {}", provider.api_base_url);
-    println!(
-        "🔑 API Key: {}...",
-        &provider.api_key[..7.min(provider.api_key.len())]
-    );
+    outln!("{}", t!("provider_show.title", name = provider.name));
+    outln!();
+    outln!("📍 API URL: {}", provider.api_base_url);
+    outln!("🔑 API Key: {}", out.secret(&provider.api_key));
 
     if let Some(provider_type) = &provider.provider_type {
-        println!("🏷️  类型: {}", provider_type.display_name());
+        outln!(
+            "{}",
+            t!("provider_show.type", kind = provider_type.display_name())
+        );
     }
 
-    println!("📊 模型数量: {}", provider.models.len());
-    println!("🤖 模型列表:");
+    outln!(
+        "{}",
+        t!("provider_show.model_count", count = provider.models.len())
+    );
+    outln!("{}", t!("provider_show.models"));
     for (index, model) in provider.models.iter().enumerate() {
-        println!("  {}. {}", index + 1, model);
+        outln!("  {}. {}", index + 1, model);
     }
 
     if let Some(transformer) = &provider.transformer {
-        println!("🔄 Transformer 配置:");
-        println!("{}", serde_json::to_string_pretty(transformer)?);
+        outln!("{}", t!("provider_show.transformer"));
+        outln!("{}", serde_json::to_string_pretty(transformer)?);
     }
 
     Ok(())
 }
 
 /// 编辑 Provider
-pub fn cmd_provider_edit(name: String) -> AppResult<()> {
+pub fn cmd_provider_edit(name: String, raw: bool, out: OutputOptions) -> AppResult<()> {
     let manager = CcrConfigManager::new()?;
 
     // Provider命令启动时同步配置
     manager.sync_config_from_ccr()?;
 
     let mut provider = manager.get_provider(&name)?;
+    if raw {
+        return edit_provider_raw(&manager, provider, out);
+    }
 
-    println!("✏️  编辑 Provider: {}", provider.name);
-    println!();
+    info!("{}", t!("provider_edit.title", name = provider.name));
+    info!();
 
     // 编辑 API Key
-    println!(
-        "🔑 当前 API Key: {}...",
-        &provider.api_key[..7.min(provider.api_key.len())]
+    info!(
+        "{}",
+        t!(
+            "provider_edit.current_key",
+            key = OutputOptions::default().secret(&provider.api_key)
+        )
     );
-    print!("新 API Key (直接回车保持不变): ");
-    io::stdout().flush().unwrap();
+    console::prompt(&t!("provider_edit.prompt_key"));
     let mut new_api_key = String::new();
     io::stdin().read_line(&mut new_api_key)?;
     let new_api_key = new_api_key.trim();
     if !new_api_key.is_empty() {
-        provider.api_key = new_api_key.to_string();
+        provider.api_key = store_provider_key(&name, new_api_key)?;
     }
 
     // 编辑 API URL
-    println!("📍 当前 API URL: {}", provider.api_base_url);
-    print!("新 API URL (直接回车保持不变): ");
-    io::stdout().flush().unwrap();
+    info!(
+        "{}",
+        t!("provider_edit.current_url", url = provider.api_base_url)
+    );
+    console::prompt(&t!("provider_edit.prompt_url"));
     let mut new_url = String::new();
     io::stdin().read_line(&mut new_url)?;
     let new_url = new_url.trim();
@@ -1472,9 +2768,14 @@ pub fn cmd_provider_edit(name: String) -> AppResult<()> {
     }
 
     // 编辑模型列表
-    println!("🤖 当前模型: {}", provider.models.join(", "));
-    print!("新模型列表 (用逗号分隔，直接回车保持不变): ");
-    io::stdout().flush().unwrap();
+    info!(
+        "{}",
+        t!(
+            "provider_edit.current_models",
+            models = provider.models.join(", ")
+        )
+    );
+    console::prompt(&t!("provider_edit.prompt_models"));
     let mut new_models = String::new();
     io::stdin().read_line(&mut new_models)?;
     let new_models = new_models.trim();
@@ -1492,7 +2793,1219 @@ pub fn cmd_provider_edit(name: String) -> AppResult<()> {
 
     // 保存更新
     manager.update_provider(provider)?;
-    println!("✅ Provider '{name}' 更新成功！");
+    info!("{}", t!("provider_edit.success", name));
+
+    Ok(())
+}
+
+/// 重命名 Provider，同步更新所有 Router Profile 和 claude-code-router Router 节点中的路由引用
+pub fn cmd_provider_rename(old: String, new: String, yes: bool) -> AppResult<()> {
+    check_new_name(&new)?;
+    if new.contains(',') {
+        return Err(AppError::InvalidConfig(t!(
+            "rename.invalid_name",
+            name = new
+        )));
+    }
+
+    let manager = CcrConfigManager::new()?;
+    manager.sync_config_from_ccr()?;
 
+    let provider = manager.get_provider(&old)?;
+    if manager.provider_exists(&new)? {
+        return Err(AppError::Config(t!("provider.already_exists", name = new)));
+    }
+
+    // 预览需要更新的路由引用
+    let config = Config::load_or_default()?;
+    let mut profile_names: Vec<&String> = config.groups.router.keys().collect();
+    profile_names.sort();
+    let mut references = Vec::new();
+    for name in profile_names {
+        let mut router = config.groups.router[name].router.clone();
+        for change in router.rename_provider(&old, &new) {
+            references.push((t!("route_ref.profile_label", name), change));
+        }
+    }
+    let mut ccr_router = manager.get_current_router()?;
+    for change in ccr_router.rename_provider(&old, &new) {
+        references.push((t!("route_ref.ccr_label"), change));
+    }
+
+    info!("{}", t!("provider_rename.title", old, new));
+    if references.is_empty() {
+        info!("{}", t!("provider_rename.no_references"));
+    } else {
+        info!(
+            "{}",
+            t!("provider_rename.references", count = references.len())
+        );
+        for (label, (route, from, to)) in &references {
+            info!("   • {label} {route}: {from} → {to}");
+        }
+    }
+    info!();
+
+    if !yes {
+        let input = read_input(&t!("provider_rename.confirm"))?.to_lowercase();
+        if input != "y" && input != "yes" {
+            info!("{}", t!("provider_rename.cancelled"));
+            return Ok(());
+        }
+    }
+
+    let _lock = Config::lock()?;
+    let mut config = Config::load_or_default()?;
+
+    // 以 `$CCODE_<NAME>_API_KEY` 引用的密钥随 Provider 名称迁移
+    let mut renamed = provider.clone();
+    renamed.name = new.clone();
+    let mut stale_key = None;
+    if provider.api_key == secrets::provider_env_ref(&old) {
+        renamed.api_key = secrets::provider_env_ref(&new);
+        if let Some(reference) = config.provider_key_refs.remove(&old) {
+            config.provider_key_refs.insert(new.clone(), reference);
+        } else {
+            let backend = config.open_secret_backend()?;
+            let key = secrets::provider_secret_key(&old);
+            backend.store(&secrets::provider_secret_key(&new), &backend.load(&key)?)?;
+            stale_key = Some(key);
+        }
+    }
+
+    manager.rename_provider_only(&old, &renamed)?;
+
+    let mut changed = config.provider_key_refs.contains_key(&new);
+    for profile in config.groups.router.values_mut() {
+        if !profile.router.rename_provider(&old, &new).is_empty() {
+            profile.updated_at = Some(current_timestamp());
+            changed = true;
+        }
+    }
+    if changed {
+        config.save()?;
+    }
+
+    if let Some(key) = stale_key {
+        discard_secret(&config, &secrets::secret_ref(&key));
+    }
+
+    info!("{}", t!("provider_rename.success", old, new));
     Ok(())
 }
+
+/// 在外部编辑器中编辑 Provider 的 JSON，修改后的 API Key 按密钥存储后端保存
+///
+/// 明文 API Key 在临时文件中遮蔽（`--show-secrets` 时写入完整值），未修改的遮蔽值保存时还原。
+fn edit_provider_raw(
+    manager: &CcrConfigManager,
+    provider: CcrProvider,
+    out: OutputOptions,
+) -> AppResult<()> {
+    let name = provider.name.clone();
+    let mut masked = provider.clone();
+    masked.api_key = out.secret(&provider.api_key);
+    let unmask = |mut edited: CcrProvider| {
+        unmask_secret(&mut edited.api_key, &masked.api_key, &provider.api_key);
+        edited
+    };
+
+    let edited = editor::edit_json(&format!("provider-{name}"), &masked, |edited| {
+        if edited.name != name {
+            return Err(AppError::InvalidConfig(t!("editor.name_changed", name)));
+        }
+        // 新填入的 env:/file:/cmd: 引用在保存时才转换为 CCR 的 `$VAR` 形式，这里先单独校验
+        let mut check = unmask(edited.clone());
+        if check.api_key != provider.api_key
+            && let Some(reference) = SecretRef::parse(&check.api_key)
+        {
+            if matches!(reference, SecretRef::Backend(_)) {
+                return Err(AppError::InvalidConfig(t!(
+                    "secrets_cmd.provider_backend_ref"
+                )));
+            }
+            reference.validate()?;
+            check.api_key = secrets::provider_env_ref(&name);
+        }
+        check.validate()
+    })?;
+    let Some(edited) = edited else {
+        return Ok(());
+    };
+    let mut edited = unmask(edited);
+
+    if edited.api_key != provider.api_key {
+        edited.api_key = store_provider_key(&name, edited.api_key.trim())?;
+    }
+    manager.update_provider(edited)?;
+    info!("{}", t!("provider_edit.success", name));
+
+    Ok(())
+}
+
+/// 需要在输出中遮蔽的密钥字段
+const SECRET_KEYS: [&str; 3] = ["api_key", "APIKEY", "ANTHROPIC_AUTH_TOKEN"];
+
+/// 递归遮蔽 JSON 中的密钥字段
+fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                if SECRET_KEYS.contains(&key.as_str())
+                    && let Some(secret) = item.as_str()
+                {
+                    *item = serde_json::Value::String(mask_secret(secret));
+                } else {
+                    redact_json(item);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+/// 格式化文件大小
+fn format_size(size: u64) -> String {
+    if size >= 1024 * 1024 {
+        format!("{:.1} MB", size as f64 / (1024.0 * 1024.0))
+    } else if size >= 1024 {
+        format!("{:.1} KB", size as f64 / 1024.0)
+    } else {
+        format!("{size} B")
+    }
+}
+
+/// 获取备份命令的操作对象：ccode 配置或 CCR 配置
+fn backup_target(ccode: bool) -> AppResult<Box<dyn BackupTarget>> {
+    if ccode {
+        Ok(Box::new(ConfigBackups::new()?))
+    } else {
+        Ok(Box::new(CcrConfigManager::new()?))
+    }
+}
+
+/// 读取备份或当前配置（`current`）的 JSON 内容
+fn load_backup_json(target: &dyn BackupTarget, id: &str) -> AppResult<(String, serde_json::Value)> {
+    if id == "current" {
+        let content = target.read_current()?;
+        return Ok(("current".to_string(), serde_json::from_str(&content)?));
+    }
+
+    let entry = target.find_backup(id)?;
+    let content = target.read_backup(&entry)?;
+    Ok((entry.id, serde_json::from_str(&content)?))
+}
+
+/// 显示 JSON 差异
+///
+/// 差异本身是 `backup diff` 的结果数据，而恢复、撤销前的预览属于提示信息，由调用方选择输出方式
+fn display_json_changes(changes: &[JsonChange], emit: fn(&str)) -> AppResult<()> {
+    let render = |value: &serde_json::Value| -> AppResult<String> {
+        let mut value = value.clone();
+        redact_json(&mut value);
+        Ok(serde_json::to_string(&value)?)
+    };
+
+    for change in changes {
+        match change {
+            JsonChange::Added { path, value } => emit(&format!("  + {path}: {}", render(value)?)),
+            JsonChange::Removed { path, value } => emit(&format!("  - {path}: {}", render(value)?)),
+            JsonChange::Changed { path, old, new } => {
+                // 密钥字段单独遮蔽
+                let (old, new) = match (old.as_str(), new.as_str()) {
+                    (Some(o), Some(n)) if SECRET_KEYS.iter().any(|k| path.ends_with(k)) => (
+                        serde_json::Value::String(mask_secret(o)),
+                        serde_json::Value::String(mask_secret(n)),
+                    ),
+                    _ => (old.clone(), new.clone()),
+                };
+                emit(&format!(
+                    "  ~ {path}: {} → {}",
+                    render(&old)?,
+                    render(&new)?
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 列出配置备份
+pub fn cmd_backup_list(ccode: bool) -> AppResult<()> {
+    let target = backup_target(ccode)?;
+    let backups = target.list_backups()?;
+
+    if backups.is_empty() {
+        outln!("{}", t!("backup_cmd.empty", label = target.label()));
+        return Ok(());
+    }
+
+    outln!(
+        "{}",
+        t!(
+            "backup_cmd.list_title",
+            label = target.label(),
+            count = backups.len()
+        )
+    );
+    outln!(
+        "{}",
+        t!("backup_cmd.dir", dir = target.backup_dir().display())
+    );
+    outln!();
+
+    for (index, backup) in backups.iter().enumerate() {
+        let latest_marker = if index == 0 {
+            t!("backup_cmd.latest_marker")
+        } else {
+            String::new()
+        };
+        outln!("🗂️  {}{latest_marker}", backup.id);
+        outln!(
+            "   {}",
+            t!("backup_cmd.time", time = backup.created_display())
+        );
+        outln!(
+            "   {}",
+            t!("backup_cmd.size", size = format_size(backup.size))
+        );
+    }
+
+    outln!();
+    let flag = if ccode { " --ccode" } else { "" };
+    info!("{}", t!("backup_cmd.list_hint", flag));
+
+    Ok(())
+}
+
+/// 显示配置备份内容
+pub fn cmd_backup_show(id: String, ccode: bool) -> AppResult<()> {
+    let target = backup_target(ccode)?;
+    let entry = target.find_backup(&id)?;
+    let mut content: serde_json::Value = serde_json::from_str(&target.read_backup(&entry)?)?;
+    redact_json(&mut content);
+
+    outln!("{}", t!("backup_cmd.show_title", id = entry.id));
+    outln!("{}", t!("backup_cmd.time", time = entry.created_display()));
+    outln!("{}", t!("backup_cmd.file", path = entry.path.display()));
+    outln!();
+    outln!("{}", serde_json::to_string_pretty(&content)?);
+
+    Ok(())
+}
+
+/// 对比两个配置备份（或备份与当前配置）
+pub fn cmd_backup_diff(id: String, other: Option<String>, ccode: bool) -> AppResult<()> {
+    let target = backup_target(ccode)?;
+    let other = other.unwrap_or_else(|| "current".to_string());
+
+    let (old_id, old) = load_backup_json(target.as_ref(), &id)?;
+    let (new_id, new) = load_backup_json(target.as_ref(), &other)?;
+
+    let changes = diff_json(&old, &new);
+
+    outln!("{}", t!("backup_cmd.diff_title", old_id, new_id));
+    if changes.is_empty() {
+        outln!("{}", t!("backup_cmd.identical"));
+        return Ok(());
+    }
+
+    outln!("{}", t!("backup_cmd.diff_count", count = changes.len()));
+    display_json_changes(&changes, console::data)
+}
+
+/// 从备份恢复配置
+pub fn cmd_backup_restore(id: String, yes: bool, ccode: bool) -> AppResult<()> {
+    let target = backup_target(ccode)?;
+    let entry = target.find_backup(&id)?;
+
+    // 先验证备份内容，避免确认后才发现备份无效
+    target.validate_backup(&entry)?;
+
+    info!(
+        "{}",
+        t!(
+            "backup_cmd.restore_title",
+            label = target.label(),
+            id = entry.id
+        )
+    );
+    info!(
+        "{}",
+        t!("backup_cmd.restore_time", time = entry.created_display())
+    );
+
+    // 显示恢复将带来的变更
+    if target.config_path().exists() {
+        let current: serde_json::Value = serde_json::from_str(&target.read_current()?)?;
+        let backup: serde_json::Value = serde_json::from_str(&target.read_backup(&entry)?)?;
+        let changes = diff_json(&current, &backup);
+
+        if changes.is_empty() {
+            info!("{}", t!("backup_cmd.restore_identical"));
+            return Ok(());
+        }
+
+        info!();
+        info!(
+            "{}",
+            t!("backup_cmd.restore_changes", count = changes.len())
+        );
+        display_json_changes(&changes, console::info)?;
+    }
+    info!();
+
+    if !yes {
+        let input = read_input(&t!("backup_cmd.restore_confirm"))?.to_lowercase();
+        if input != "y" && input != "yes" {
+            info!("{}", t!("backup_cmd.restore_cancelled"));
+            return Ok(());
+        }
+    }
+
+    let (backend, keys) = current_secret_keys(target.as_ref());
+    target.restore_backup(&entry.id)?;
+    retire_secrets(backend, keys.iter().map(String::as_str));
+    sweep_retired_secrets();
+    info!(
+        "{}",
+        t!("backup_cmd.restored", id = entry.id, label = target.label())
+    );
+
+    Ok(())
+}
+
+/// 按保留策略清理过期的配置备份
+pub fn cmd_backup_prune(dry_run: bool, ccode: bool) -> AppResult<()> {
+    let target = backup_target(ccode)?;
+    let removed = {
+        let _lock = target.lock()?;
+        target.prune_backups(dry_run)?
+    };
+    // 释放备份锁后再清理，保持先 ccode 配置后 CCR 配置的加锁顺序
+    if !dry_run {
+        sweep_retired_secrets();
+    }
+
+    if removed.is_empty() {
+        outln!("{}", t!("backup_cmd.nothing_to_prune"));
+        return Ok(());
+    }
+
+    let title = if dry_run {
+        t!("backup_cmd.prune_preview", count = removed.len())
+    } else {
+        t!("backup_cmd.pruned", count = removed.len())
+    };
+    outln!("{title}");
+    for entry in &removed {
+        outln!(
+            "   🗂️  {} ({}, {})",
+            entry.id,
+            entry.created_display(),
+            format_size(entry.size)
+        );
+    }
+
+    if dry_run {
+        outln!();
+        info!("{}", t!("backup_cmd.prune_hint"));
+    }
+
+    Ok(())
+}
+
+/// 查看或修改备份保留策略
+pub fn cmd_backup_policy(
+    keep_last: Option<usize>,
+    keep_daily_days: Option<u32>,
+    max_total_size: Option<String>,
+) -> AppResult<()> {
+    let changed = keep_last.is_some() || keep_daily_days.is_some() || max_total_size.is_some();
+
+    let policy = if changed {
+        let _lock = Config::lock()?;
+        let mut config = Config::load_or_default()?;
+        let mut policy = config.backup_retention.clone().unwrap_or_default();
+
+        if let Some(keep_last) = keep_last {
+            policy.keep_last = keep_last;
+        }
+        if let Some(keep_daily_days) = keep_daily_days {
+            policy.keep_daily_days = keep_daily_days;
+        }
+        if let Some(max_total_size) = max_total_size {
+            policy.max_total_size = match max_total_size.trim() {
+                "" | "none" | "0" => None,
+                size => Some(parse_size(size)?),
+            };
+        }
+
+        config.backup_retention = Some(policy.clone());
+        config.save()?;
+        info!("{}", t!("backup_cmd.policy_updated"));
+        policy
+    } else {
+        Config::load_or_default()?
+            .backup_retention
+            .unwrap_or_default()
+    };
+
+    outln!("{}", t!("backup_cmd.policy_title"));
+    outln!(
+        "   {}",
+        t!("backup_cmd.policy_keep_last", count = policy.keep_last)
+    );
+    outln!(
+        "   {}",
+        t!(
+            "backup_cmd.policy_keep_daily",
+            days = policy.keep_daily_days
+        )
+    );
+    match policy.max_total_size {
+        Some(size) => outln!(
+            "   {}",
+            t!("backup_cmd.policy_max_size", size = format_size(size))
+        ),
+        None => outln!("   {}", t!("backup_cmd.policy_unlimited")),
+    }
+
+    if !changed {
+        outln!();
+        info!("{}", t!("backup_cmd.policy_hint"));
+    }
+
+    Ok(())
+}
+
+/// 解析 `KEY=VALUE` 形式的环境变量赋值
+fn parse_env_assignment(assignment: &str) -> AppResult<(String, String)> {
+    let (key, value) = assignment
+        .split_once('=')
+        .ok_or_else(|| AppError::InvalidConfig(t!("env.invalid_assignment", assignment)))?;
+    let key = key.trim();
+    validate_env_name(key)?;
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// 修改配置的环境变量，`update` 返回是否有变化，有变化时记录 `updated_at` 并保存
+fn update_profile_env<F>(name: &str, group: Option<String>, update: F) -> AppResult<()>
+where
+    F: FnOnce(&mut BTreeMap<String, String>) -> bool,
+{
+    let group = resolve_group(name, group)?;
+
+    let _lock = Config::lock()?;
+    let mut config = Config::load()?;
+    let (env, updated_at) = match group.as_str() {
+        "direct" => {
+            let profile = config
+                .groups
+                .direct
+                .get_mut(name)
+                .ok_or_else(|| AppError::ProfileNotFound(name.to_string()))?;
+            (&mut profile.env, &mut profile.updated_at)
+        }
+        "ccr" => {
+            let profile = config
+                .groups
+                .router
+                .get_mut(name)
+                .ok_or_else(|| AppError::ProfileNotFound(name.to_string()))?;
+            (&mut profile.env, &mut profile.updated_at)
+        }
+        g => return Err(AppError::Config(t!("group.unknown", group = g))),
+    };
+
+    if update(env) {
+        *updated_at = Some(current_timestamp());
+        config.save()?;
+    }
+    Ok(())
+}
+
+/// 设置配置的环境变量（`KEY=VALUE`）
+pub fn cmd_env_set(name: String, group: Option<String>, assignments: Vec<String>) -> AppResult<()> {
+    let vars = assignments
+        .iter()
+        .map(|assignment| parse_env_assignment(assignment))
+        .collect::<AppResult<Vec<_>>>()?;
+
+    update_profile_env(&name, group, |env| {
+        let mut changed = false;
+        for (key, value) in &vars {
+            changed |= env.insert(key.clone(), value.clone()).as_ref() != Some(value);
+        }
+        changed
+    })?;
+
+    for (key, _) in &vars {
+        info!("{}", t!("env.set", name, key));
+    }
+    Ok(())
+}
+
+/// 删除配置的环境变量
+pub fn cmd_env_unset(name: String, group: Option<String>, keys: Vec<String>) -> AppResult<()> {
+    let mut removed = Vec::new();
+    update_profile_env(&name, group, |env| {
+        for key in &keys {
+            if env.remove(key).is_some() {
+                removed.push(key.clone());
+            }
+        }
+        !removed.is_empty()
+    })?;
+
+    for key in &keys {
+        if removed.contains(key) {
+            info!("{}", t!("env.unset", name, key));
+        } else {
+            warn!("{}", t!("env.not_set", name, key));
+        }
+    }
+    Ok(())
+}
+
+/// 输出在当前 shell 中设置 Direct 配置环境变量（或恢复原值）的语句，供 `eval` 使用
+pub fn cmd_env_shell(
+    name: Option<String>,
+    group: Option<String>,
+    shell: Option<Shell>,
+    revert: bool,
+    policy: EnvPolicy,
+) -> AppResult<()> {
+    let shell = shell.unwrap_or_else(Shell::detect);
+
+    let lines = if revert {
+        // 恢复上次 `ccode env` 保存的原值，与配置无关
+        match launch::shell_restore_script(shell)? {
+            Some(restore) => {
+                if !restore.unsaved.is_empty() {
+                    warn!(
+                        "{}",
+                        t!(
+                            "env.shell_secret_unsaved",
+                            names = restore.unsaved.join(", ")
+                        )
+                    );
+                }
+                restore.lines
+            }
+            None => {
+                warn!(
+                    "{}",
+                    t!("env.shell_nothing_saved", var = launch::SAVED_ENV_VAR)
+                );
+                return Ok(());
+            }
+        }
+    } else {
+        let config = Config::load()?;
+        let env = match name {
+            Some(name) => match resolve_group(&name, group)?.as_str() {
+                "direct" => resolve_direct_env(&config, config.get_direct_profile(&name)?, policy)?,
+                "ccr" => {
+                    let profile = config.get_router_profile(&name)?;
+                    let (ccr_manager, ccr_config) = load_ccr_service_config()?;
+                    // 不重启共享的 ccr 服务，路由变化时提示用户
+                    if apply_router_to_service(&ccr_manager, profile, &ccr_config)?
+                        && ccr_reachable(&ccr_config)
+                    {
+                        warn!("{}", t!("env.shell_ccr_restart"));
+                    }
+                    router_profile_env(profile, &ccr_config, policy)
+                }
+                g => return Err(AppError::Config(t!("group.unknown", group = g))),
+            },
+            None => resolve_direct_env(&config, config.get_default_direct_profile()?.1, policy)?,
+        };
+        env.shell_script(shell)?
+    };
+
+    for line in lines {
+        outln!("{line}");
+    }
+    Ok(())
+}
+
+/// 列出配置的环境变量
+pub fn cmd_env_list(name: String, group: Option<String>, out: OutputOptions) -> AppResult<()> {
+    let group = resolve_group(&name, group)?;
+    let config = Config::load()?;
+    let env = match group.as_str() {
+        "direct" => &config.get_direct_profile(&name)?.env,
+        "ccr" => &config.get_router_profile(&name)?.env,
+        g => return Err(AppError::Config(t!("group.unknown", group = g))),
+    };
+
+    // 像密钥的变量值默认遮蔽，`--show-secrets` 显示完整值
+    let env = out.env(env);
+    if !out.is_text() {
+        let view = ProfileEnvView { name, group, env };
+        return out.emit("ProfileEnv", &view);
+    }
+
+    if env.is_empty() {
+        info!("{}", t!("env.empty", name));
+        return Ok(());
+    }
+    for (key, value) in &env {
+        outln!("{key}={value}");
+    }
+    Ok(())
+}
+
+/// 健康检查：检查依赖工具、配置文件、路由引用、令牌与文件权限，存在失败项时返回错误
+pub fn cmd_doctor(out: OutputOptions) -> AppResult<()> {
+    let report = Report::run();
+
+    if out.is_text() {
+        outln!("{}", t!("doctor.title"));
+        for section in &report.sections {
+            outln!();
+            outln!("{}", section.title);
+            for check in &section.checks {
+                outln!("  {} {}", check.status.icon(), check.message);
+                if let Some(fix) = &check.fix {
+                    outln!("     {}", t!("doctor.fix_hint", fix));
+                }
+            }
+        }
+        outln!();
+        outln!(
+            "{}",
+            t!(
+                "doctor.summary",
+                pass = report.summary.pass,
+                warn = report.summary.warn,
+                fail = report.summary.fail
+            )
+        );
+    } else {
+        out.emit("DoctorReport", &report)?;
+    }
+
+    if report.summary.fail > 0 {
+        return Err(AppError::Config(t!(
+            "doctor.failed",
+            count = report.summary.fail
+        )));
+    }
+    Ok(())
+}
+
+/// 撤销对 ccode 配置的最近一次修改
+pub fn cmd_undo(yes: bool) -> AppResult<()> {
+    let target = ConfigBackups::new()?;
+    let entry = target
+        .find_backup("latest")
+        .map_err(|_| AppError::Config(t!("undo.nothing")))?;
+    target.validate_backup(&entry)?;
+
+    info!("{}", t!("undo.title", time = entry.created_display()));
+
+    if target.config_path().exists() {
+        let (_, current) = load_backup_json(&target, "current")?;
+        let (_, backup) = load_backup_json(&target, &entry.id)?;
+        let changes = diff_json(&current, &backup);
+
+        if !changes.is_empty() {
+            info!();
+            info!("{}", t!("undo.changes", count = changes.len()));
+            display_json_changes(&changes, console::info)?;
+        }
+    }
+    info!();
+
+    if !yes {
+        let input = read_input(&t!("undo.confirm"))?.to_lowercase();
+        if input != "y" && input != "yes" {
+            info!("{}", t!("undo.cancelled"));
+            return Ok(());
+        }
+    }
+
+    let (backend, keys) = current_secret_keys(&target);
+    target.undo()?;
+    // 撤销前的配置不会保留备份，其中的密钥不再被引用时随即删除
+    retire_secrets(backend, keys.iter().map(String::as_str));
+    sweep_retired_secrets();
+    info!("{}", t!("undo.success", id = entry.id));
+
+    Ok(())
+}
+
+/// 当前配置引用的后端密钥及所在后端，用于恢复其他版本的配置后停用不再使用的密钥
+///
+/// 包括 Direct 配置的 `secret://ccode/<key>` 令牌和以 `$CCODE_<NAME>_API_KEY` 引用的 Provider 密钥
+fn current_secret_keys(target: &dyn BackupTarget) -> (SecretBackendKind, Vec<String>) {
+    let backend = Config::load_or_default()
+        .map(|config| config.secret_backend.unwrap_or_default())
+        .unwrap_or_default();
+    let Some(config) = target
+        .read_current()
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+    else {
+        return (backend, Vec::new());
+    };
+
+    let tokens = config["groups"]["direct"]
+        .as_object()
+        .into_iter()
+        .flat_map(|profiles| profiles.values())
+        .filter_map(|profile| profile["ANTHROPIC_AUTH_TOKEN"].as_str())
+        .filter_map(secrets::parse_secret_ref)
+        .map(str::to_string);
+    let providers = config["Providers"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|provider| {
+            let name = provider["name"].as_str()?;
+            (provider["api_key"] == secrets::provider_env_ref(name).as_str())
+                .then(|| secrets::provider_secret_key(name))
+        });
+    (backend, tokens.chain(providers).collect())
+}
+
+/// 按配置的密钥存储后端保存 Direct 配置的认证令牌
+fn store_direct_token(config: &mut Config, name: &str) -> AppResult<()> {
+    let backend = config.open_secret_backend()?;
+    if backend.kind() == SecretBackendKind::Plaintext {
+        return Ok(());
+    }
+
+    // 已经是引用（env:/file:/cmd:/secret://）的令牌保持不变
+    if let Some(profile) = config.groups.direct.get_mut(name)
+        && SecretRef::parse(&profile.anthropic_auth_token).is_none()
+    {
+        profile.anthropic_auth_token = backend.store(
+            &secrets::direct_secret_key(name),
+            &profile.anthropic_auth_token,
+        )?;
+    }
+    Ok(())
+}
+
+/// Provider 密钥保存后写入 CCR 配置的 `api_key` 取值（只做转换，不写入任何密钥）
+///
+/// - `env:VAR` 转换为 claude-code-router 的 `$VAR` 插值形式
+/// - `file:` / `cmd:` 引用和非明文后端中的普通密钥转换为 `$CCODE_<NAME>_API_KEY`，启动 ccr 时解析
+fn provider_key_value(name: &str, api_key: &str, kind: SecretBackendKind) -> AppResult<String> {
+    match SecretRef::parse(api_key) {
+        Some(reference) => {
+            reference.validate()?;
+            match reference {
+                SecretRef::Env(var) => Ok(format!("${var}")),
+                SecretRef::File(_) | SecretRef::Cmd(_) => Ok(secrets::provider_env_ref(name)),
+                SecretRef::Backend(_) => Err(AppError::InvalidConfig(t!(
+                    "secrets_cmd.provider_backend_ref"
+                ))),
+            }
+        }
+        None if kind == SecretBackendKind::Plaintext || api_key.starts_with('$') => {
+            Ok(api_key.to_string())
+        }
+        None => Ok(secrets::provider_env_ref(name)),
+    }
+}
+
+/// 保存 Provider 的 API 密钥，返回写入 CCR 配置的值（见 [`provider_key_value`]）
+///
+/// `file:` / `cmd:` 引用记录在 ccode 配置中，普通密钥在非明文后端下存入密钥存储后端。
+fn store_provider_key(name: &str, api_key: &str) -> AppResult<String> {
+    let _lock = Config::lock()?;
+    let mut config = Config::load_or_default()?;
+    let had_ref = config.provider_key_refs.remove(name).is_some();
+
+    let stored = provider_key_value(name, api_key, config.secret_backend.unwrap_or_default())?;
+    match SecretRef::parse(api_key) {
+        Some(SecretRef::File(_) | SecretRef::Cmd(_)) => {
+            config
+                .provider_key_refs
+                .insert(name.to_string(), api_key.trim().to_string());
+        }
+        Some(_) => {}
+        None if stored != api_key => {
+            config
+                .open_secret_backend()?
+                .store(&secrets::provider_secret_key(name), api_key)?;
+        }
+        None => {}
+    }
+
+    if had_ref || config.provider_key_refs.contains_key(name) {
+        config.save()?;
+    }
+    Ok(stored)
+}
+
+/// 停用配置项在密钥存储后端中的密钥，失败时仅给出警告
+///
+/// 密钥在引用它的配置备份全部清理后才会从后端删除，`undo` 与 `backup restore` 恢复的引用仍然有效
+fn discard_secret(config: &Config, value: &str) {
+    let Some(key) = secrets::parse_secret_ref(value) else {
+        return;
+    };
+
+    retire_secrets(config.secret_backend.unwrap_or_default(), [key]);
+    sweep_retired_secrets();
+}
+
+/// 将密钥记录为已停用，失败时仅给出警告
+fn retire_secrets<'a>(backend: SecretBackendKind, keys: impl IntoIterator<Item = &'a str>) {
+    // 明文后端中没有需要删除的密钥
+    if backend == SecretBackendKind::Plaintext {
+        return;
+    }
+
+    let result = Config::lock().and_then(|_lock| {
+        let mut retired = RetiredSecrets::load()?;
+        for key in keys {
+            retired.retire(backend, key);
+        }
+        retired.save()
+    });
+    if let Err(e) = result {
+        warn!("{}", t!("secrets_cmd.retire_failed", e));
+    }
+}
+
+/// 从后端删除不再被当前配置或任何配置备份引用的已停用密钥，失败时仅给出警告
+fn sweep_retired_secrets() {
+    if let Err(e) = try_sweep_retired_secrets() {
+        warn!("{}", t!("secrets_cmd.sweep_failed", e));
+    }
+}
+
+fn try_sweep_retired_secrets() -> AppResult<()> {
+    let _lock = Config::lock()?;
+    let mut retired = RetiredSecrets::load()?;
+    if retired.entries().is_empty() {
+        return Ok(());
+    }
+
+    let manager = CcrConfigManager::new()?;
+    let _ccr_lock = manager.lock()?;
+    let ccode_configs = config_documents(&ConfigBackups::new()?)?;
+    let ccr_configs = config_documents(&manager)?;
+
+    let mut backends: Vec<Box<dyn secrets::SecretBackend>> = Vec::new();
+    retired.retain(|secret| {
+        if secret.is_referenced(&ccode_configs, &ccr_configs) {
+            return true;
+        }
+
+        let backend = match backends.iter().position(|b| b.kind() == secret.backend) {
+            Some(index) => Ok(&backends[index]),
+            None => secrets::open_backend(secret.backend).map(|backend| {
+                backends.push(backend);
+                &backends[backends.len() - 1]
+            }),
+        };
+        match backend.and_then(|backend| backend.delete(&secret.key)) {
+            Ok(()) => false,
+            Err(e) => {
+                warn!("{}", t!("secrets_cmd.discard_failed", key = secret.key, e));
+                true
+            }
+        }
+    });
+    retired.save()
+}
+
+/// 读取当前配置与所有备份的 JSON 内容，无法读取或解析的备份会被跳过
+fn config_documents(target: &dyn BackupTarget) -> AppResult<Vec<serde_json::Value>> {
+    let mut documents = Vec::new();
+    if target.config_path().exists() {
+        documents.push(serde_json::from_str(&target.read_current()?)?);
+    }
+    for entry in target.list_backups()? {
+        if let Some(value) = target
+            .read_backup(&entry)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+        {
+            documents.push(value);
+        }
+    }
+    Ok(documents)
+}
+
+/// 解析 CCR 配置中以环境变量引用保存的 Provider 密钥，返回启动 ccr 时需要设置的环境变量
+fn provider_secret_env(
+    config: &Config,
+    manager: &CcrConfigManager,
+) -> AppResult<Vec<(String, String)>> {
+    let mut envs = Vec::new();
+    let mut backend = None;
+
+    for provider in manager.list_providers()? {
+        let var = secrets::provider_env_var(&provider.name);
+        // 已在当前环境中设置的变量不覆盖
+        if provider.api_key != secrets::provider_env_ref(&provider.name)
+            || std::env::var_os(&var).is_some()
+        {
+            continue;
+        }
+
+        if backend.is_none() {
+            backend = Some(config.open_secret_backend()?);
+        }
+        if let Some(backend) = &backend {
+            // 优先使用 file: / cmd: 引用，其次读取密钥存储后端
+            let value = match config.provider_key_refs.get(&provider.name) {
+                Some(reference) => secrets::resolve(reference, backend.as_ref())?,
+                None => backend.load(&secrets::provider_secret_key(&provider.name))?,
+            };
+            envs.push((var, value));
+        }
+    }
+
+    Ok(envs)
+}
+
+/// 显示密钥存储状态
+pub fn cmd_secrets_status() -> AppResult<()> {
+    let config = Config::load_or_default()?;
+    let kind = config.secret_backend.unwrap_or_default();
+
+    outln!(
+        "{}",
+        t!("secrets_cmd.backend", backend = kind.display_name())
+    );
+    outln!();
+
+    let storage_of = |value: &str| match SecretRef::parse(value) {
+        Some(SecretRef::Backend(_)) => format!("🔒 {value}"),
+        Some(SecretRef::Env(_)) => t!("secrets_cmd.storage_env", value),
+        Some(SecretRef::File(_) | SecretRef::Cmd(_)) => t!("secrets_cmd.storage_external", value),
+        None if value.starts_with('$') => t!("secrets_cmd.storage_env", value),
+        None => t!("secrets_cmd.storage_plaintext"),
+    };
+
+    if !config.groups.direct.is_empty() {
+        outln!("{}", t!("secrets_cmd.direct_title"));
+        let mut profiles: Vec<_> = config.groups.direct.iter().collect();
+        profiles.sort_by_key(|(name, _)| name.as_str());
+        for (name, profile) in profiles {
+            outln!("   {name}: {}", storage_of(&profile.anthropic_auth_token));
+        }
+        outln!();
+    }
+
+    let manager = CcrConfigManager::new()?;
+    if manager.config_exists() {
+        let providers = manager.list_providers()?;
+        if !providers.is_empty() {
+            outln!("🔗 CCR Providers:");
+            for provider in providers {
+                let value = config
+                    .provider_key_refs
+                    .get(&provider.name)
+                    .unwrap_or(&provider.api_key);
+                outln!("   {}: {}", provider.name, storage_of(value));
+            }
+            outln!();
+        }
+    }
+
+    if kind == SecretBackendKind::Plaintext {
+        info!("{}", t!("secrets_cmd.migrate_hint"));
+    }
+
+    Ok(())
+}
+
+/// 将所有密钥迁移到指定的存储后端
+pub fn cmd_secrets_migrate(to: String, keep_backups: bool) -> AppResult<()> {
+    let target_kind = SecretBackendKind::from_str(&to)?;
+    // 迁移到安全后端时不再为含明文密钥的旧配置创建备份
+    let backup = target_kind == SecretBackendKind::Plaintext;
+
+    let _lock = Config::lock()?;
+    let mut config = Config::load_or_default()?;
+    let source = config.open_secret_backend()?;
+    let target = secrets::open_backend(target_kind)?;
+
+    info!(
+        "{}",
+        t!(
+            "secrets_cmd.migrate_title",
+            from = source.kind().display_name(),
+            to = target_kind.display_name()
+        )
+    );
+
+    // 迁移完成后需要从原后端删除的密钥
+    let mut stale_keys = Vec::new();
+    let mut migrated = 0;
+
+    for (name, profile) in config.groups.direct.iter_mut() {
+        // env:/file:/cmd: 引用由用户自行维护，不做迁移
+        if SecretRef::parse(&profile.anthropic_auth_token).is_some_and(|r| r.is_external()) {
+            continue;
+        }
+
+        // 沿用已有的密钥名，备份中的引用在新后端中同样有效
+        let key = match secrets::parse_secret_ref(&profile.anthropic_auth_token) {
+            Some(key) => {
+                stale_keys.push(key.to_string());
+                key.to_string()
+            }
+            None => secrets::direct_secret_key(name),
+        };
+        let value = secrets::resolve(&profile.anthropic_auth_token, source.as_ref())?;
+
+        let stored = target.store(&key, &value)?;
+        if stored != profile.anthropic_auth_token {
+            migrated += 1;
+            profile.anthropic_auth_token = stored;
+        }
+    }
+
+    let manager = CcrConfigManager::new()?;
+    let _ccr_lock = manager.lock()?;
+    if manager.config_exists() {
+        let mut providers = manager.list_providers()?;
+        let mut changed = false;
+
+        for provider in providers.iter_mut() {
+            let key = secrets::provider_secret_key(&provider.name);
+            let env_ref = secrets::provider_env_ref(&provider.name);
+
+            let value = if config.provider_key_refs.contains_key(&provider.name) {
+                // file:/cmd: 引用由用户自行维护，不做迁移
+                continue;
+            } else if provider.api_key == env_ref {
+                stale_keys.push(key.clone());
+                source.load(&key)?
+            } else if provider.api_key.starts_with('$') {
+                // 用户自行配置的环境变量引用保持不变
+                continue;
+            } else {
+                provider.api_key.clone()
+            };
+
+            let stored = if target_kind == SecretBackendKind::Plaintext {
+                value
+            } else {
+                target.store(&key, &value)?;
+                env_ref
+            };
+            if stored != provider.api_key {
+                migrated += 1;
+                changed = true;
+                provider.api_key = stored;
+            }
+        }
+
+        if changed {
+            manager.update_providers_only(providers, backup)?;
+        }
+    }
+
+    config.secret_backend = match target_kind {
+        SecretBackendKind::Plaintext => None,
+        kind => Some(kind),
+    };
+    if backup {
+        config.save()?;
+    } else {
+        config.save_without_backup()?;
+    }
+
+    // 原后端中的密钥仍被迁移前的配置备份引用，等这些备份清理后再删除
+    if source.kind() != target_kind {
+        retire_secrets(source.kind(), stale_keys.iter().map(String::as_str));
+    }
+
+    info!(
+        "{}",
+        t!(
+            "secrets_cmd.migrated",
+            migrated,
+            backend = target_kind.display_name()
+        )
+    );
+
+    if target_kind != SecretBackendKind::Plaintext {
+        let config_backups = ConfigBackups::new()?;
+        if keep_backups {
+            let remaining = config_backups
+                .list_backups()?
+                .iter()
+                .filter(|entry| backup_entry_matches(&config_backups, entry, has_plaintext_token))
+                .count()
+                + manager
+                    .list_backups()?
+                    .iter()
+                    .filter(|entry| backup_entry_matches(&manager, entry, has_plaintext_api_key))
+                    .count();
+            if remaining > 0 {
+                info!();
+                warn!("{}", t!("secrets_cmd.backups_plaintext", count = remaining));
+                warn!("   {}", config_backups.backup_dir().display());
+                warn!("   {}", manager.backup_dir().display());
+            }
+        } else {
+            let removed = config_backups
+                .remove_backups_where(&has_plaintext_token)?
+                .len()
+                + manager.remove_backups_where(&has_plaintext_api_key)?.len();
+            if removed > 0 {
+                info!("{}", t!("secrets_cmd.backups_purged", count = removed));
+            }
+        }
+    }
+    sweep_retired_secrets();
+    if target_kind != SecretBackendKind::Plaintext {
+        info!("{}", t!("secrets_cmd.ccr_stop_hint"));
+    }
+
+    Ok(())
+}
+
+/// 备份内容是否满足条件，无法读取或解析的备份视为不满足
+fn backup_entry_matches(
+    target: &dyn BackupTarget,
+    entry: &BackupEntry,
+    matches: fn(&serde_json::Value) -> bool,
+) -> bool {
+    target
+        .read_backup(entry)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .is_some_and(|value| matches(&value))
+}
+
+/// ccode 配置中是否有 Direct 配置直接保存了明文令牌
+fn has_plaintext_token(config: &serde_json::Value) -> bool {
+    config["groups"]["direct"]
+        .as_object()
+        .is_some_and(|profiles| {
+            profiles.values().any(|profile| {
+                profile["ANTHROPIC_AUTH_TOKEN"]
+                    .as_str()
+                    .is_some_and(|token| {
+                        !token.trim().is_empty() && SecretRef::parse(token).is_none()
+                    })
+            })
+        })
+}
+
+/// CCR 配置中是否有 Provider 直接保存了明文 API 密钥
+fn has_plaintext_api_key(config: &serde_json::Value) -> bool {
+    config["Providers"].as_array().is_some_and(|providers| {
+        providers.iter().any(|provider| {
+            provider["api_key"]
+                .as_str()
+                .is_some_and(|key| !key.trim().is_empty() && !key.starts_with('$'))
+        })
+    })
+}
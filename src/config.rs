@@ -1,9 +1,17 @@
+use crate::backup::{BackupStore, BackupTarget, RetentionPolicy};
 use crate::error::{AppError, AppResult};
+use crate::fs_utils::{FileLock, atomic_write};
+use crate::secrets::{self, SecretBackend, SecretBackendKind};
+use crate::{outln, t};
+use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
 
 /// Direct模式配置项（原有的简单配置）
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,29 +27,45 @@ pub struct DirectProfile {
         skip_serializing_if = "Option::is_none"
     )]
     pub anthropic_small_fast_model: Option<String>,
+    /// 启动 claude 时额外设置的环境变量
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
 }
 
 impl DirectProfile {
     /// 显示可选字段信息
     pub fn display_optional_fields(&self, indent: &str) {
         if let Some(model) = &self.anthropic_model {
-            println!("{indent}🤖 模型: {model}");
+            outln!("{indent}{}", t!("profile.model", model));
         }
 
         if let Some(fast_model) = &self.anthropic_small_fast_model {
-            println!("{indent}⚡ 快速模型: {fast_model}");
+            outln!("{indent}{}", t!("profile.fast_model", model = fast_model));
+        }
+
+        if !self.env.is_empty() {
+            outln!(
+                "{indent}{}",
+                t!("profile.env", names = env_names(&self.env))
+            );
         }
 
         if let Some(desc) = &self.description {
-            println!("{indent}📝 描述: {desc}");
+            outln!("{indent}{}", t!("profile.description", desc));
         }
 
         if let Some(created) = &self.created_at {
-            println!("{indent}📅 创建: {created}");
+            outln!("{indent}{}", t!("profile.created", created));
+        }
+
+        if let Some(updated) = &self.updated_at {
+            outln!("{indent}{}", t!("profile.updated", updated));
         }
     }
 }
@@ -49,6 +73,74 @@ impl DirectProfile {
 /// 向后兼容的Profile类型别名
 pub type Profile = DirectProfile;
 
+/// 由配置字段或 claude-code-router 设置的环境变量，不能写在配置的 `env` 中
+const RESERVED_ENV_VARS: &[&str] = &[
+    "ANTHROPIC_AUTH_TOKEN",
+    "ANTHROPIC_BASE_URL",
+    "ANTHROPIC_MODEL",
+    "ANTHROPIC_SMALL_FAST_MODEL",
+    "ANTHROPIC_API_KEY",
+];
+
+/// ccode 内部使用的环境变量前缀（如 `CCODE_<NAME>_API_KEY`、`CCODE_VAULT_PASSPHRASE`）
+const RESERVED_ENV_PREFIX: &str = "CCODE_";
+
+/// 决定程序查找、用户目录、终端与语言环境的基础变量（不区分大小写）
+///
+/// 配置的 `env` 不能覆盖这些变量：例如 `PATH` 会改变查找 claude 程序的位置。
+/// `--clean-env` 启动时也只保留这些变量。
+pub const PROCESS_ENV_VARS: &[&str] = &[
+    "PATH",
+    "HOME",
+    "USER",
+    "LOGNAME",
+    "SHELL",
+    "TERM",
+    "COLORTERM",
+    "LANG",
+    "TZ",
+    "TMPDIR",
+    // Windows
+    "SYSTEMROOT",
+    "COMSPEC",
+    "PATHEXT",
+    "USERPROFILE",
+    "APPDATA",
+    "LOCALAPPDATA",
+    "TEMP",
+    "TMP",
+];
+
+/// 影响语言环境和动态链接的变量前缀，同样不能写在配置的 `env` 中
+const PROCESS_ENV_PREFIXES: &[&str] = &["LC_", "LD_", "DYLD_"];
+
+/// 检查配置 `env` 中的变量名：必须是合法的环境变量名，且不能是保留名称
+pub fn validate_env_name(name: &str) -> AppResult<()> {
+    if !secrets::is_valid_env_name(name) {
+        return Err(AppError::InvalidConfig(t!("env.invalid_name", name)));
+    }
+    if RESERVED_ENV_VARS.contains(&name) || name.starts_with(RESERVED_ENV_PREFIX) {
+        return Err(AppError::InvalidConfig(t!("env.reserved_name", name)));
+    }
+    let upper = name.to_ascii_uppercase();
+    if PROCESS_ENV_VARS.contains(&upper.as_str())
+        || PROCESS_ENV_PREFIXES.iter().any(|p| upper.starts_with(p))
+    {
+        return Err(AppError::InvalidConfig(t!("env.process_name", name)));
+    }
+    Ok(())
+}
+
+/// 检查配置 `env` 中的所有变量名
+pub fn validate_env(env: &BTreeMap<String, String>) -> AppResult<()> {
+    env.keys().try_for_each(|name| validate_env_name(name))
+}
+
+/// 以逗号分隔的变量名列表（值可能包含凭据，展示时只列出名称）
+pub fn env_names(env: &BTreeMap<String, String>) -> String {
+    env.keys().cloned().collect::<Vec<_>>().join(", ")
+}
+
 /// Provider类型枚举
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProviderType {
@@ -74,14 +166,14 @@ pub enum ProviderType {
 
 impl ProviderType {
     /// 获取provider类型的显示名称
-    pub fn display_name(&self) -> &'static str {
+    pub fn display_name(&self) -> String {
         match self {
-            ProviderType::OpenAI => "OpenAI兼容",
-            ProviderType::OpenRouter => "OpenRouter",
-            ProviderType::DeepSeek => "DeepSeek",
-            ProviderType::Gemini => "Gemini",
-            ProviderType::Qwen => "Qwen",
-            ProviderType::Custom => "自定义",
+            ProviderType::OpenAI => t!("provider_type.openai"),
+            ProviderType::OpenRouter => "OpenRouter".to_string(),
+            ProviderType::DeepSeek => "DeepSeek".to_string(),
+            ProviderType::Gemini => "Gemini".to_string(),
+            ProviderType::Qwen => "Qwen".to_string(),
+            ProviderType::Custom => t!("provider_type.custom"),
         }
     }
 
@@ -162,22 +254,30 @@ impl ProviderType {
         }
     }
 
+    /// 所有支持的provider类型
+    pub fn all() -> [ProviderType; 6] {
+        [
+            ProviderType::OpenAI,
+            ProviderType::OpenRouter,
+            ProviderType::DeepSeek,
+            ProviderType::Gemini,
+            ProviderType::Qwen,
+            ProviderType::Custom,
+        ]
+    }
+
     /// 验证API URL格式是否符合provider类型
     pub fn validate_url_format(&self, url: &str) -> AppResult<()> {
         match self {
             ProviderType::Gemini => {
                 if !url.contains("/v1beta/models/") {
-                    return Err(AppError::InvalidConfig(
-                        "Gemini API URL应包含'/v1beta/models/'路径".to_string(),
-                    ));
+                    return Err(AppError::InvalidConfig(t!("provider.gemini_url_path")));
                 }
             }
             _ => {
                 // 其他类型检查是否包含chat/completions
                 if !url.contains("/chat/completions") && *self != ProviderType::Custom {
-                    return Err(AppError::InvalidConfig(
-                        "API URL应包含'/chat/completions'路径".to_string(),
-                    ));
+                    return Err(AppError::InvalidConfig(t!("provider.chat_url_path")));
                 }
             }
         }
@@ -185,6 +285,23 @@ impl ProviderType {
     }
 }
 
+impl FromStr for ProviderType {
+    type Err = AppError;
+
+    /// 从与配置文件一致的类型标识解析（不区分大小写）
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "openai" => Ok(ProviderType::OpenAI),
+            "openrouter" => Ok(ProviderType::OpenRouter),
+            "deepseek" => Ok(ProviderType::DeepSeek),
+            "gemini" => Ok(ProviderType::Gemini),
+            "qwen" => Ok(ProviderType::Qwen),
+            "custom" => Ok(ProviderType::Custom),
+            other => Err(AppError::InvalidConfig(t!("provider_type.unknown", other))),
+        }
+    }
+}
+
 /// CCR提供商配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CcrProvider {
@@ -197,6 +314,9 @@ pub struct CcrProvider {
     /// Provider类型（用于生成transformer配置）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provider_type: Option<ProviderType>,
+    /// ccode 未识别的字段，原样保留以便无损回写
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl CcrProvider {
@@ -217,27 +337,48 @@ impl CcrProvider {
             models,
             transformer,
             provider_type: Some(provider_type),
+            extra: serde_json::Map::new(),
         }
     }
 
     /// 验证配置有效性
     pub fn validate(&self) -> AppResult<()> {
         if self.name.trim().is_empty() {
-            return Err(AppError::InvalidConfig("提供商名称不能为空".to_string()));
+            return Err(AppError::InvalidConfig(t!("provider.empty_name")));
         }
 
         if self.api_base_url.trim().is_empty() {
-            return Err(AppError::InvalidConfig("API URL不能为空".to_string()));
+            return Err(AppError::InvalidConfig(t!("provider.empty_url")));
         }
 
         if !self.api_base_url.starts_with("http://") && !self.api_base_url.starts_with("https://") {
-            return Err(AppError::InvalidConfig(
-                "API URL格式无效，应以'http://'或'https://'开头".to_string(),
-            ));
+            return Err(AppError::InvalidConfig(t!("provider.invalid_url")));
         }
 
         if self.models.is_empty() {
-            return Err(AppError::InvalidConfig("模型列表不能为空".to_string()));
+            return Err(AppError::InvalidConfig(t!("provider.empty_models")));
+        }
+
+        // claude-code-router 只支持 `$VAR` / `${VAR}` 形式的环境变量插值
+        if secrets::SecretRef::parse(&self.api_key).is_some() {
+            return Err(AppError::InvalidConfig(t!(
+                "provider.unconverted_key_ref",
+                name = self.name,
+                reference = self.api_key
+            )));
+        }
+        if let Some(var) = self.api_key.strip_prefix('$') {
+            let var = var
+                .strip_prefix('{')
+                .and_then(|v| v.strip_suffix('}'))
+                .unwrap_or(var);
+            if !secrets::is_valid_env_name(var) {
+                return Err(AppError::InvalidConfig(t!(
+                    "provider.invalid_key_env_ref",
+                    name = self.name,
+                    reference = self.api_key
+                )));
+            }
         }
 
         // 验证URL格式是否符合provider类型
@@ -247,6 +388,137 @@ impl CcrProvider {
 
         Ok(())
     }
+
+    /// 模型是否在 `models` 列表中，忽略 OpenRouter 的模型变体后缀（如 `:online`）
+    pub fn has_model(&self, model: &str) -> bool {
+        let model = model.trim();
+        let base = model
+            .rsplit_once(':')
+            .filter(|(_, suffix)| MODEL_VARIANT_SUFFIXES.contains(suffix))
+            .map_or(model, |(base, _)| base);
+        self.models.iter().any(|m| m == model || m == base)
+    }
+}
+
+/// OpenRouter 的模型变体后缀，追加在模型名称后（如 `anthropic/claude-sonnet-4:online`）
+const MODEL_VARIANT_SUFFIXES: &[&str] = &[
+    "online", "free", "nitro", "floor", "beta", "thinking", "extended", "exacto",
+];
+
+/// 路由（`provider,model`）中的Provider名称
+pub fn route_provider(route: &str) -> Option<&str> {
+    route.split_once(',').map(|(provider, _)| provider.trim())
+}
+
+/// 路由模型校验模式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum RouteValidation {
+    /// 模型不在 Provider 的模型列表中时给出警告（默认）
+    #[default]
+    Warn,
+    /// 模型不在 Provider 的模型列表中时拒绝保存和应用
+    Strict,
+}
+
+static ROUTE_VALIDATION: OnceLock<RouteValidation> = OnceLock::new();
+
+impl RouteValidation {
+    /// 按优先级确定校验模式：命令行参数、`CCODE_ROUTE_VALIDATION`、默认的警告模式
+    pub fn detect(cli: Option<RouteValidation>) -> RouteValidation {
+        cli.or_else(|| {
+            std::env::var("CCODE_ROUTE_VALIDATION")
+                .ok()
+                .and_then(|value| RouteValidation::from_str(value.trim(), true).ok())
+        })
+        .unwrap_or_default()
+    }
+
+    /// 当前进程使用的校验模式
+    pub fn current() -> RouteValidation {
+        ROUTE_VALIDATION.get().copied().unwrap_or_default()
+    }
+}
+
+/// 设置当前进程的路由校验模式（只在启动时调用一次）
+pub fn set_route_validation(mode: RouteValidation) {
+    let _ = ROUTE_VALIDATION.set(mode);
+}
+
+/// 路由引用问题
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteIssue {
+    /// 引用的 Provider 不存在
+    MissingProvider { route: String, provider: String },
+    /// 模型不在 Provider 的 `models` 列表中
+    UnknownModel {
+        route: String,
+        provider: String,
+        model: String,
+    },
+}
+
+impl RouteIssue {
+    /// 路由名称
+    pub fn route(&self) -> &str {
+        match self {
+            RouteIssue::MissingProvider { route, .. } | RouteIssue::UnknownModel { route, .. } => {
+                route
+            }
+        }
+    }
+
+    /// 在给定模式下是否阻止保存（Provider 不存在时 claude-code-router 无法路由，始终阻止）
+    pub fn is_error(&self, mode: RouteValidation) -> bool {
+        match self {
+            RouteIssue::MissingProvider { .. } => true,
+            RouteIssue::UnknownModel { .. } => mode == RouteValidation::Strict,
+        }
+    }
+
+    /// 转换为阻止保存时的错误
+    pub fn into_error(self) -> AppError {
+        let message = match &self {
+            RouteIssue::MissingProvider { .. } => self.to_string(),
+            RouteIssue::UnknownModel { .. } => t!("route.strict_rejected", issue = self),
+        };
+        AppError::InvalidConfig(message)
+    }
+}
+
+impl fmt::Display for RouteIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            RouteIssue::MissingProvider { route, provider } => t!(
+                "ccr.route_missing_provider",
+                route_name = route,
+                provider_name = provider
+            ),
+            RouteIssue::UnknownModel {
+                route,
+                provider,
+                model,
+            } => t!("route.unknown_model", route, model, provider),
+        };
+        f.write_str(&message)
+    }
+}
+
+/// 检查单个路由（`provider,model`）引用的 Provider 与模型，格式不正确的路由不在此检查
+pub fn check_route(route: &str, value: &str, providers: &[CcrProvider]) -> Option<RouteIssue> {
+    let (provider, model) = value.split_once(',')?;
+    let (provider, model) = (provider.trim(), model.trim());
+    match providers.iter().find(|p| p.name == provider) {
+        None => Some(RouteIssue::MissingProvider {
+            route: route.to_string(),
+            provider: provider.to_string(),
+        }),
+        Some(p) if !p.has_model(model) => Some(RouteIssue::UnknownModel {
+            route: route.to_string(),
+            provider: provider.to_string(),
+            model: model.to_string(),
+        }),
+        Some(_) => None,
+    }
 }
 
 /// CCR路由配置
@@ -266,6 +538,9 @@ pub struct CcrRouter {
     pub long_context_threshold: Option<u32>,
     #[serde(rename = "webSearch", skip_serializing_if = "Option::is_none")]
     pub web_search: Option<String>,
+    /// ccode 未识别的路由字段（如新增的路由类型），原样保留
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl CcrRouter {
@@ -278,20 +553,19 @@ impl CcrRouter {
             long_context: None,
             long_context_threshold: Some(60000), // 默认60000
             web_search: None,
+            extra: serde_json::Map::new(),
         }
     }
 
     /// 验证路由配置有效性
     pub fn validate(&self) -> AppResult<()> {
         if self.default.trim().is_empty() {
-            return Err(AppError::InvalidConfig("默认路由配置不能为空".to_string()));
+            return Err(AppError::InvalidConfig(t!("router.empty_default")));
         }
 
         // 验证默认路由格式（应该是 "provider,model" 格式）
         if !self.default.contains(',') {
-            return Err(AppError::InvalidConfig(
-                "默认路由配置格式无效，应为'provider,model'格式".to_string(),
-            ));
+            return Err(AppError::InvalidConfig(t!("router.invalid_default")));
         }
 
         // 验证其他路由配置格式
@@ -307,9 +581,7 @@ impl CcrRouter {
                 && !route_value.trim().is_empty()
                 && !route_value.contains(',')
             {
-                return Err(AppError::InvalidConfig(format!(
-                    "{name}路由配置格式无效，应为'provider,model'格式"
-                )));
+                return Err(AppError::InvalidConfig(t!("router.invalid_route", name)));
             }
         }
 
@@ -335,6 +607,129 @@ impl CcrRouter {
 
         routes
     }
+
+    /// 所有路由，包括 ccode 未识别的字符串路由字段（如 `image`）
+    pub fn all_routes(&self) -> Vec<(String, String)> {
+        let mut routes = self.get_all_routes();
+        routes.extend(
+            self.extra
+                .iter()
+                .filter_map(|(name, value)| value.as_str().map(|r| (name.clone(), r.to_string()))),
+        );
+        routes
+    }
+
+    /// 检查所有路由引用的 Provider 与模型是否存在
+    pub fn check_references(&self, providers: &[CcrProvider]) -> Vec<RouteIssue> {
+        self.all_routes()
+            .into_iter()
+            .filter_map(|(route, value)| check_route(&route, &value, providers))
+            .collect()
+    }
+
+    /// 按校验模式检查路由引用：存在阻止保存的问题时返回错误，否则返回需要提示的警告
+    pub fn validate_references(
+        &self,
+        providers: &[CcrProvider],
+        mode: RouteValidation,
+    ) -> AppResult<Vec<RouteIssue>> {
+        let (errors, warnings): (Vec<_>, Vec<_>) = self
+            .check_references(providers)
+            .into_iter()
+            .partition(|issue| issue.is_error(mode));
+        match errors.into_iter().next() {
+            Some(error) => Err(error.into_error()),
+            None => Ok(warnings),
+        }
+    }
+
+    /// 引用指定Provider的路由，返回 (路由名, 路由)
+    pub fn provider_routes(&self, provider: &str) -> Vec<(String, String)> {
+        let mut routes = self.all_routes();
+        routes.retain(|(_, route)| route_provider(route) == Some(provider));
+        routes
+    }
+
+    /// 将引用指定Provider的路由改为新名称，返回 (路由名, 原路由, 新路由)
+    pub fn rename_provider(&mut self, old: &str, new: &str) -> Vec<(String, String, String)> {
+        self.reassign_provider(old, new, |model| model.to_string())
+    }
+
+    /// 将引用指定Provider的路由改为使用另一个Provider，`model_for` 根据原模型给出新模型，
+    /// 返回 (路由名, 原路由, 新路由)
+    pub fn reassign_provider<F>(
+        &mut self,
+        provider: &str,
+        target: &str,
+        model_for: F,
+    ) -> Vec<(String, String, String)>
+    where
+        F: Fn(&str) -> String,
+    {
+        let mut changes = Vec::new();
+        let mut rewrite = |route_name: &str, route: &mut String| {
+            if let Some((name, model)) = route.split_once(',')
+                && name.trim() == provider
+            {
+                let renamed = format!("{target},{}", model_for(model));
+                let original = std::mem::replace(route, renamed.clone());
+                changes.push((route_name.to_string(), original, renamed));
+            }
+        };
+
+        rewrite("default", &mut self.default);
+        for (route_name, route) in self.optional_routes_mut() {
+            if let Some(route) = route {
+                rewrite(route_name, route);
+            }
+        }
+        for (route_name, value) in self.extra.iter_mut() {
+            if let serde_json::Value::String(route) = value {
+                rewrite(route_name, route);
+            }
+        }
+
+        changes
+    }
+
+    /// 清空引用指定Provider的可选路由，返回被清空的路由名（默认路由不能清空，保持不变）
+    pub fn clear_provider_routes(&mut self, provider: &str) -> Vec<String> {
+        let mut cleared = Vec::new();
+        for (route_name, route) in self.optional_routes_mut() {
+            if route.as_deref().and_then(route_provider) == Some(provider) {
+                *route = None;
+                cleared.push(route_name.to_string());
+            }
+        }
+        self.extra.retain(|route_name, value| {
+            let matched = value.as_str().and_then(route_provider) == Some(provider);
+            if matched {
+                cleared.push(route_name.clone());
+            }
+            !matched
+        });
+        cleared
+    }
+
+    /// 可选路由字段及其在 claude-code-router 配置中的名称
+    fn optional_routes_mut(&mut self) -> [(&'static str, &mut Option<String>); 4] {
+        [
+            ("background", &mut self.background),
+            ("think", &mut self.think),
+            ("longContext", &mut self.long_context),
+            ("webSearch", &mut self.web_search),
+        ]
+    }
+
+    /// 从现有Router中补齐本配置未设置的未知字段
+    /// 用于应用Router Profile时保留claude-code-router新增的路由字段
+    pub fn preserve_unknown_fields(&mut self, existing: &CcrRouter) {
+        for (key, value) in &existing.extra {
+            self.extra
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+    }
 }
 
 /// Provider模板生成器
@@ -368,38 +763,38 @@ impl ProviderType {
     }
 
     /// 获取provider类型的配置提示信息
-    pub fn get_configuration_hints(&self) -> Vec<&'static str> {
+    pub fn get_configuration_hints(&self) -> Vec<String> {
         match self {
             ProviderType::OpenAI => vec![
-                "• 标准OpenAI API格式",
-                "• 无需特殊transformer配置",
-                "• 支持大部分第三方兼容API",
+                t!("hint.openai.format"),
+                t!("hint.openai.no_transformer"),
+                t!("hint.openai.compatible"),
             ],
             ProviderType::OpenRouter => vec![
-                "• 支持多种AI模型路由",
-                "• 自动添加OpenRouter transformer",
-                "• WebSearch功能需要在模型后加':online'后缀",
+                t!("hint.openrouter.routing"),
+                t!("hint.openrouter.transformer"),
+                t!("hint.openrouter.online"),
             ],
             ProviderType::DeepSeek => vec![
-                "• DeepSeek专用API",
-                "• 自动配置DeepSeek transformer",
-                "• deepseek-chat模型自动启用tooluse",
+                t!("hint.deepseek.api"),
+                t!("hint.deepseek.transformer"),
+                t!("hint.deepseek.tooluse"),
             ],
             ProviderType::Gemini => vec![
-                "• Google Gemini API",
-                "• API路径格式: /v1beta/models/",
-                "• 自动配置Gemini transformer",
+                t!("hint.gemini.api"),
+                t!("hint.gemini.path"),
+                t!("hint.gemini.transformer"),
             ],
             ProviderType::Qwen => vec![
-                "• 通义千问系列模型",
-                "• 自动配置最大token限制(65536)",
-                "• Thinking模型自动启用reasoning模式",
-                "• 增强的工具调用支持",
+                t!("hint.qwen.models"),
+                t!("hint.qwen.max_tokens"),
+                t!("hint.qwen.reasoning"),
+                t!("hint.qwen.tooluse"),
             ],
             ProviderType::Custom => vec![
-                "• 自定义API配置",
-                "• 需要手动配置transformer(如需要)",
-                "• 适用于其他AI服务提供商",
+                t!("hint.custom.api"),
+                t!("hint.custom.transformer"),
+                t!("hint.custom.other"),
             ],
         }
     }
@@ -425,8 +820,16 @@ pub struct CcrConfig {
     pub transformers: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub CUSTOM_ROUTER_PATH: Option<String>,
+    /// ccode 未识别的顶层字段（如 StatusLine 等），原样保留
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+/// claude-code-router 服务的默认监听地址、端口和访问密钥
+const CCR_DEFAULT_HOST: &str = "127.0.0.1";
+const CCR_DEFAULT_PORT: u16 = 3456;
+const CCR_DEFAULT_APIKEY: &str = "test";
+
 impl CcrConfig {
     /// 创建新的 CCR 配置文件
     pub fn new() -> Self {
@@ -440,6 +843,43 @@ impl CcrConfig {
             Router: CcrRouter::new("provider,model".to_string()),
             transformers: None,
             CUSTOM_ROUTER_PATH: None,
+            extra: serde_json::Map::new(),
+        }
+    }
+
+    /// claude-code-router 服务的连接地址（监听所有地址时改为本机回环地址）
+    pub fn service_addr(&self) -> (String, u16) {
+        let host = match self.HOST.as_deref().map(str::trim) {
+            None | Some("" | "0.0.0.0" | "::") => CCR_DEFAULT_HOST.to_string(),
+            Some(host) => host.to_string(),
+        };
+        let port = self
+            .extra
+            .get("PORT")
+            .and_then(|port| port.as_u64())
+            .and_then(|port| u16::try_from(port).ok())
+            .unwrap_or(CCR_DEFAULT_PORT);
+        (host, port)
+    }
+
+    /// claude-code-router 服务的 API 地址（即 `ccr code` 设置的 `ANTHROPIC_BASE_URL`）
+    pub fn endpoint(&self) -> String {
+        let (host, port) = self.service_addr();
+        if host.contains(':') {
+            format!("http://[{host}]:{port}")
+        } else {
+            format!("http://{host}:{port}")
+        }
+    }
+
+    /// 访问服务使用的密钥：`APIKEY`（支持 `$VAR` 引用），未设置时与 `ccr code` 一样使用 `test`
+    pub fn client_api_key(&self) -> String {
+        match self.APIKEY.as_deref().map(str::trim) {
+            None | Some("") => CCR_DEFAULT_APIKEY.to_string(),
+            Some(key) => match key.strip_prefix('$') {
+                Some(var) => std::env::var(var.trim_matches(['{', '}'])).unwrap_or_default(),
+                None => key.to_string(),
+            },
         }
     }
 
@@ -448,7 +888,7 @@ impl CcrConfig {
     pub fn validate(&self) -> AppResult<()> {
         // 验证 Providers 不为空
         if self.Providers.is_empty() {
-            return Err(AppError::InvalidConfig("Providers列表不能为空".to_string()));
+            return Err(AppError::InvalidConfig(t!("ccr.empty_providers")));
         }
 
         // 验证每个 Provider
@@ -467,8 +907,10 @@ impl CcrConfig {
             if let Some(provider_name) = route_value.split(',').next()
                 && !provider_names.contains(provider_name)
             {
-                return Err(AppError::InvalidConfig(format!(
-                    "路由'{route_name}'中的提供商'{provider_name}'不存在"
+                return Err(AppError::InvalidConfig(t!(
+                    "ccr.route_missing_provider",
+                    route_name,
+                    provider_name
                 )));
             }
         }
@@ -481,9 +923,9 @@ impl CcrConfig {
     pub fn add_provider(&mut self, provider: CcrProvider) -> AppResult<()> {
         // 检查名称是否重复
         if self.Providers.iter().any(|p| p.name == provider.name) {
-            return Err(AppError::Config(format!(
-                "Provider '{}' 已存在",
-                provider.name
+            return Err(AppError::Config(t!(
+                "provider.already_exists",
+                name = provider.name
             )));
         }
 
@@ -499,7 +941,7 @@ impl CcrConfig {
         self.Providers.retain(|p| p.name != name);
 
         if self.Providers.len() == original_len {
-            return Err(AppError::Config(format!("Provider '{name}' 不存在")));
+            return Err(AppError::Config(t!("provider.not_found", name)));
         }
 
         Ok(())
@@ -519,9 +961,9 @@ impl CcrConfig {
             *existing = provider;
             Ok(())
         } else {
-            Err(AppError::Config(format!(
-                "Provider '{}' 不存在",
-                provider.name
+            Err(AppError::Config(t!(
+                "provider.not_found",
+                name = provider.name
             )))
         }
     }
@@ -546,10 +988,15 @@ impl Default for CcrConfig {
 pub struct RouterProfile {
     pub name: String,
     pub router: CcrRouter,
+    /// 启动 ccr 时额外设置的环境变量（由 ccr 传递给 claude）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
 }
 
 impl RouterProfile {
@@ -560,19 +1007,20 @@ impl RouterProfile {
         Ok(Self {
             name,
             router,
+            env: BTreeMap::new(),
             description,
             created_at: None,
+            updated_at: None,
         })
     }
 
     /// 验证配置有效性
     pub fn validate(&self) -> AppResult<()> {
         if self.name.trim().is_empty() {
-            return Err(AppError::InvalidConfig(
-                "Router Profile 名称不能为空".to_string(),
-            ));
+            return Err(AppError::InvalidConfig(t!("router_profile.empty_name")));
         }
 
+        validate_env(&self.env)?;
         self.router.validate()
     }
 }
@@ -602,6 +1050,17 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_profile: Option<DefaultProfile>,
     pub groups: Groups,
+    /// 配置备份保留策略，未设置时使用默认策略
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backup_retention: Option<RetentionPolicy>,
+    /// 密钥存储后端，未设置时以明文保存在配置文件中
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_backend: Option<SecretBackendKind>,
+    /// Provider API Key 的外部引用（`file:` / `cmd:`），按 Provider 名称索引
+    ///
+    /// 对应 Provider 在 CCR 配置中保存为 `$CCODE_<NAME>_API_KEY`，启动 ccr 时解析后传入
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub provider_key_refs: HashMap<String, String>,
 
     // 兼容旧格式的字段
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -623,6 +1082,9 @@ impl Default for Config {
                 direct: HashMap::new(),
                 router: HashMap::new(),
             },
+            backup_retention: None,
+            secret_backend: None,
+            provider_key_refs: HashMap::new(),
             // 兼容字段设为None
             default: None,
             profiles: None,
@@ -634,7 +1096,7 @@ impl Config {
     /// 获取配置文件路径
     pub fn get_config_path() -> AppResult<PathBuf> {
         let config_dir =
-            dirs::config_dir().ok_or_else(|| AppError::Config("无法获取配置目录".to_string()))?;
+            dirs::config_dir().ok_or_else(|| AppError::Config(t!("config.no_config_dir")))?;
 
         let ccode_dir = config_dir.join("ccode");
 
@@ -663,6 +1125,24 @@ impl Config {
         Ok(config)
     }
 
+    /// 从配置文件加载配置，配置文件不存在时返回默认配置
+    pub fn load_or_default() -> AppResult<Self> {
+        match Self::load() {
+            Err(AppError::ConfigNotFound) => Ok(Self::default()),
+            result => result,
+        }
+    }
+
+    /// 打开配置的密钥存储后端
+    pub fn open_secret_backend(&self) -> AppResult<Box<dyn SecretBackend>> {
+        secrets::open_backend(self.secret_backend.unwrap_or_default())
+    }
+
+    /// 获取配置文件锁，保护跨进程的读取-修改-写入过程
+    pub fn lock() -> AppResult<FileLock> {
+        FileLock::acquire(&Self::get_config_path()?)
+    }
+
     /// 迁移旧格式配置到新的分组格式
     fn migrate_legacy_format(&mut self) -> AppResult<()> {
         // 如果存在旧格式的profiles字段，迁移它们到groups.direct
@@ -694,18 +1174,33 @@ impl Config {
         Ok(())
     }
 
-    /// 保存配置到文件
+    /// 保存配置到文件（原子写入），写入前备份现有配置
     pub fn save(&self) -> AppResult<()> {
-        let config_path = Self::get_config_path()?;
+        let backups =
+            ConfigBackups::new()?.with_retention(self.backup_retention.clone().unwrap_or_default());
+        self.save_with(&backups)
+    }
+
+    /// 保存配置到文件（原子写入），不备份现有配置
+    ///
+    /// 用于不应留下旧内容副本的写入，例如把明文密钥迁移到安全后端
+    pub fn save_without_backup(&self) -> AppResult<()> {
         let content = serde_json::to_string_pretty(self)?;
-        fs::write(config_path, content)?;
-        Ok(())
+        atomic_write(&Config::get_config_path()?, content.as_bytes())
+    }
+
+    /// 通过指定的备份目标保存配置
+    pub(crate) fn save_with(&self, backups: &ConfigBackups) -> AppResult<()> {
+        backups.backup_current()?;
+
+        let content = serde_json::to_string_pretty(self)?;
+        atomic_write(backups.config_path(), content.as_bytes())
     }
 
     /// 添加Direct配置
     pub fn add_direct_profile(&mut self, name: String, profile: DirectProfile) -> AppResult<()> {
         if self.groups.direct.contains_key(&name) {
-            return Err(AppError::Config(format!("配置 '{name}' 已存在")));
+            return Err(AppError::Config(t!("profile.already_exists", name)));
         }
 
         // 验证配置
@@ -728,6 +1223,38 @@ impl Config {
         Ok(())
     }
 
+    /// 更新已有的Direct配置，保留默认配置标记
+    pub fn update_direct_profile(&mut self, name: &str, profile: DirectProfile) -> AppResult<()> {
+        if !self.groups.direct.contains_key(name) {
+            return Err(AppError::ProfileNotFound(name.to_string()));
+        }
+
+        self.validate_direct_profile(&profile)?;
+        self.groups.direct.insert(name.to_string(), profile);
+        Ok(())
+    }
+
+    /// 重命名Direct配置，保留默认配置标记
+    pub fn rename_direct_profile(&mut self, old: &str, new: &str) -> AppResult<()> {
+        if self.groups.direct.contains_key(new) {
+            return Err(AppError::Config(t!("profile.already_exists", name = new)));
+        }
+        let profile = self
+            .groups
+            .direct
+            .remove(old)
+            .ok_or_else(|| AppError::ProfileNotFound(old.to_string()))?;
+        self.groups.direct.insert(new.to_string(), profile);
+
+        if let Some(ref mut default_profile) = self.default_profile
+            && default_profile.direct.as_deref() == Some(old)
+        {
+            default_profile.direct = Some(new.to_string());
+        }
+
+        Ok(())
+    }
+
     /// 删除配置（自动检测组类型）
     pub fn remove_profile(&mut self, name: &str) -> AppResult<()> {
         // 先尝试从direct组删除
@@ -770,7 +1297,7 @@ impl Config {
             .default_profile
             .as_ref()
             .and_then(|dp| dp.direct.as_ref())
-            .ok_or_else(|| AppError::Config("未设置默认Direct配置".to_string()))?;
+            .ok_or_else(|| AppError::Config(t!("profile.no_default")))?;
 
         let profile = self.get_direct_profile(default_name)?;
         Ok((default_name, profile))
@@ -820,32 +1347,33 @@ impl Config {
     }
 
     /// 验证Direct配置有效性
-    fn validate_direct_profile(&self, profile: &DirectProfile) -> AppResult<()> {
+    pub fn validate_direct_profile(&self, profile: &DirectProfile) -> AppResult<()> {
         // 验证token格式
         if profile.anthropic_auth_token.trim().is_empty() {
-            return Err(AppError::InvalidConfig("认证令牌不能为空".to_string()));
+            return Err(AppError::InvalidConfig(t!("profile.empty_token")));
         }
 
+        // 验证 env: / file: / cmd: / secret:// 引用格式
+        secrets::validate_reference(&profile.anthropic_auth_token)?;
+
         // 验证URL格式
         if profile.anthropic_base_url.trim().is_empty() {
-            return Err(AppError::InvalidConfig("基础URL不能为空".to_string()));
+            return Err(AppError::InvalidConfig(t!("profile.empty_url")));
         }
 
         if !profile.anthropic_base_url.starts_with("http://")
             && !profile.anthropic_base_url.starts_with("https://")
         {
-            return Err(AppError::InvalidConfig(
-                "基础URL格式无效，应以 'http://' 或 'https://' 开头".to_string(),
-            ));
+            return Err(AppError::InvalidConfig(t!("profile.invalid_url")));
         }
 
-        Ok(())
+        validate_env(&profile.env)
     }
 
     /// 添加 Router Profile
     pub fn add_router_profile(&mut self, name: String, profile: RouterProfile) -> AppResult<()> {
         if self.groups.router.contains_key(&name) {
-            return Err(AppError::Config(format!("Router Profile '{name}' 已存在")));
+            return Err(AppError::Config(t!("router_profile.already_exists", name)));
         }
 
         // 验证配置
@@ -868,6 +1396,42 @@ impl Config {
         Ok(())
     }
 
+    /// 更新已有的 Router Profile，保留默认配置标记
+    pub fn update_router_profile(&mut self, name: &str, profile: RouterProfile) -> AppResult<()> {
+        if !self.groups.router.contains_key(name) {
+            return Err(AppError::ProfileNotFound(name.to_string()));
+        }
+
+        profile.validate()?;
+        self.groups.router.insert(name.to_string(), profile);
+        Ok(())
+    }
+
+    /// 重命名 Router Profile，保留默认配置标记
+    pub fn rename_router_profile(&mut self, old: &str, new: &str) -> AppResult<()> {
+        if self.groups.router.contains_key(new) {
+            return Err(AppError::Config(t!(
+                "router_profile.already_exists",
+                name = new
+            )));
+        }
+        let mut profile = self
+            .groups
+            .router
+            .remove(old)
+            .ok_or_else(|| AppError::ProfileNotFound(old.to_string()))?;
+        profile.name = new.to_string();
+        self.groups.router.insert(new.to_string(), profile);
+
+        if let Some(ref mut default_profile) = self.default_profile
+            && default_profile.router.as_deref() == Some(old)
+        {
+            default_profile.router = Some(new.to_string());
+        }
+
+        Ok(())
+    }
+
     /// 删除 Router Profile
     pub fn remove_router_profile(&mut self, name: &str) -> AppResult<()> {
         if !self.groups.router.contains_key(name) {
@@ -900,7 +1464,7 @@ impl Config {
             .default_profile
             .as_ref()
             .and_then(|dp| dp.router.as_ref())
-            .ok_or_else(|| AppError::Config("未设置默认 Router Profile".to_string()))?;
+            .ok_or_else(|| AppError::Config(t!("router_profile.no_default")))?;
 
         let profile = self.get_router_profile(default_name)?;
         Ok((default_name, profile))
@@ -941,6 +1505,78 @@ impl Config {
     }
 }
 
+/// ccode 配置文件的备份管理
+pub struct ConfigBackups {
+    config_path: PathBuf,
+    backups: BackupStore,
+}
+
+impl ConfigBackups {
+    /// 创建 ccode 配置备份管理器，备份位于配置目录下的 `backups` 目录
+    pub fn new() -> AppResult<Self> {
+        let config_path = Config::get_config_path()?;
+        let backup_dir = config_path
+            .parent()
+            .map(|dir| dir.join("backups"))
+            .ok_or_else(|| AppError::Config(t!("config.no_config_dir")))?;
+
+        Ok(Self::with_paths(config_path, backup_dir))
+    }
+
+    /// 使用指定的配置文件与备份目录创建管理器
+    pub fn with_paths(config_path: PathBuf, backup_dir: PathBuf) -> Self {
+        // 保留策略来自当前配置，读取失败时使用默认策略
+        let retention = fs::read_to_string(&config_path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Config>(&content).ok())
+            .and_then(|config| config.backup_retention)
+            .unwrap_or_default();
+
+        Self {
+            config_path,
+            backups: BackupStore::new(backup_dir, "config_backup").with_retention(retention),
+        }
+    }
+
+    /// 设置备份保留策略
+    pub fn with_retention(mut self, retention: RetentionPolicy) -> Self {
+        self.backups = self.backups.with_retention(retention);
+        self
+    }
+}
+
+impl BackupTarget for ConfigBackups {
+    fn label(&self) -> String {
+        t!("config.label")
+    }
+
+    fn config_path(&self) -> &Path {
+        &self.config_path
+    }
+
+    fn backup_store(&self) -> &BackupStore {
+        &self.backups
+    }
+
+    fn validate_content(&self, content: &str) -> AppResult<()> {
+        let mut config: Config = serde_json::from_str(content)
+            .map_err(|e| AppError::InvalidConfig(t!("config.invalid_content", e)))?;
+        config.migrate_legacy_format()?;
+
+        for (name, profile) in &config.groups.direct {
+            config
+                .validate_direct_profile(profile)
+                .map_err(|e| AppError::InvalidConfig(t!("profile.invalid", name, e)))?;
+        }
+        for (name, profile) in &config.groups.router {
+            profile
+                .validate()
+                .map_err(|e| AppError::InvalidConfig(t!("router_profile.invalid", name, e)))?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -951,8 +1587,10 @@ mod tests {
             anthropic_base_url: "https://api.anthropic.com".to_string(),
             anthropic_model: None,
             anthropic_small_fast_model: None,
+            env: BTreeMap::new(),
             description: Some("Test profile".to_string()),
             created_at: Some("2025-07-29T00:00:00Z".to_string()),
+            updated_at: None,
         }
     }
 
@@ -982,8 +1620,10 @@ mod tests {
             anthropic_base_url: "https://api.test.com".to_string(),
             anthropic_model: Some("claude-3-5-sonnet-20241022".to_string()),
             anthropic_small_fast_model: Some("claude-3-haiku-20240307".to_string()),
+            env: BTreeMap::new(),
             description: Some("Test with models".to_string()),
             created_at: None,
+            updated_at: None,
         };
 
         assert_eq!(
@@ -1003,8 +1643,10 @@ mod tests {
             anthropic_base_url: "https://api.test.com".to_string(),
             anthropic_model: Some("test-model".to_string()),
             anthropic_small_fast_model: Some("test-fast-model".to_string()),
+            env: BTreeMap::new(),
             description: Some("Test".to_string()),
             created_at: None,
+            updated_at: None,
         };
 
         // 测试序列化
@@ -1030,8 +1672,10 @@ mod tests {
             anthropic_base_url: "https://api.test.com".to_string(),
             anthropic_model: None,
             anthropic_small_fast_model: None,
+            env: BTreeMap::new(),
             description: None,
             created_at: None,
+            updated_at: None,
         };
 
         // 测试序列化 - 可选字段不应该出现在JSON中
@@ -1110,6 +1754,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_provider_type_from_str() {
+        assert_eq!(
+            "deepseek".parse::<ProviderType>().unwrap(),
+            ProviderType::DeepSeek
+        );
+        assert_eq!(
+            "OpenRouter".parse::<ProviderType>().unwrap(),
+            ProviderType::OpenRouter
+        );
+        assert!("unknown".parse::<ProviderType>().is_err());
+
+        // 与序列化标识保持一致
+        for provider_type in ProviderType::all() {
+            let id = serde_json::to_value(&provider_type).unwrap();
+            let parsed: ProviderType = id.as_str().unwrap().parse().unwrap();
+            assert_eq!(parsed, provider_type);
+        }
+    }
+
+    #[test]
+    fn test_validate_secret_references() {
+        let config = Config::default();
+        let mut profile = create_test_profile();
+
+        for token in ["env:MY_KEY", "file:~/.keys/ds", "cmd:pass show deepseek"] {
+            profile.anthropic_auth_token = token.to_string();
+            assert!(config.validate_direct_profile(&profile).is_ok(), "{token}");
+        }
+        for token in ["env:", "env:MY-KEY", "file:", "cmd:"] {
+            profile.anthropic_auth_token = token.to_string();
+            assert!(config.validate_direct_profile(&profile).is_err(), "{token}");
+        }
+
+        let mut provider = CcrProvider::new(
+            "deepseek".to_string(),
+            "https://api.deepseek.com/chat/completions".to_string(),
+            "$DEEPSEEK_API_KEY".to_string(),
+            vec!["deepseek-chat".to_string()],
+            ProviderType::DeepSeek,
+        );
+        assert!(provider.validate().is_ok());
+        provider.api_key = "${DEEPSEEK_API_KEY}".to_string();
+        assert!(provider.validate().is_ok());
+        provider.api_key = "$DEEPSEEK-KEY".to_string();
+        assert!(provider.validate().is_err());
+        // 未转换的引用不能直接写入 CCR 配置
+        provider.api_key = "env:DEEPSEEK_API_KEY".to_string();
+        assert!(provider.validate().is_err());
+    }
+
     #[test]
     fn test_list_profiles() {
         let mut config = Config::default();
@@ -1132,4 +1827,281 @@ mod tests {
             .count();
         assert_eq!(default_count, 1);
     }
+
+    #[test]
+    fn test_update_profile_keeps_default() {
+        let mut config = Config::default();
+        config
+            .add_direct_profile("work".to_string(), create_test_profile())
+            .unwrap();
+
+        let mut profile = create_test_profile();
+        profile.anthropic_model = Some("claude-sonnet-4".to_string());
+        config
+            .update_direct_profile("work", profile.clone())
+            .unwrap();
+        assert_eq!(
+            config.get_direct_profile("work").unwrap().anthropic_model,
+            Some("claude-sonnet-4".to_string())
+        );
+        assert_eq!(
+            config.default_profile.as_ref().unwrap().direct,
+            Some("work".to_string())
+        );
+
+        // 更新时同样校验字段，且不能更新不存在的配置
+        profile.anthropic_base_url = "ftp://invalid".to_string();
+        assert!(
+            config
+                .update_direct_profile("work", profile.clone())
+                .is_err()
+        );
+        assert!(config.update_direct_profile("missing", profile).is_err());
+
+        let router = RouterProfile::new(
+            "fast".to_string(),
+            CcrRouter::new("deepseek,deepseek-chat".to_string()),
+            None,
+        )
+        .unwrap();
+        config
+            .add_router_profile("fast".to_string(), router.clone())
+            .unwrap();
+        let mut edited = router;
+        edited.router.think = Some("deepseek,deepseek-reasoner".to_string());
+        config.update_router_profile("fast", edited).unwrap();
+        assert!(
+            config
+                .get_router_profile("fast")
+                .unwrap()
+                .router
+                .think
+                .is_some()
+        );
+        assert_eq!(
+            config.default_profile.as_ref().unwrap().router,
+            Some("fast".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rename_profiles_keeps_default() {
+        let mut config = Config::default();
+        config
+            .add_direct_profile("work".to_string(), create_test_profile())
+            .unwrap();
+        config
+            .add_direct_profile("home".to_string(), create_test_profile())
+            .unwrap();
+
+        config.rename_direct_profile("work", "office").unwrap();
+        assert!(config.get_direct_profile("work").is_err());
+        assert!(config.get_direct_profile("office").is_ok());
+        assert_eq!(
+            config.default_profile.as_ref().unwrap().direct,
+            Some("office".to_string())
+        );
+        assert!(config.rename_direct_profile("office", "home").is_err());
+        assert!(config.rename_direct_profile("missing", "other").is_err());
+
+        let router = RouterProfile::new(
+            "fast".to_string(),
+            CcrRouter::new("deepseek,deepseek-chat".to_string()),
+            None,
+        )
+        .unwrap();
+        config
+            .add_router_profile("fast".to_string(), router)
+            .unwrap();
+        config.rename_router_profile("fast", "quick").unwrap();
+        assert_eq!(config.get_router_profile("quick").unwrap().name, "quick");
+        assert_eq!(
+            config.default_profile.as_ref().unwrap().router,
+            Some("quick".to_string())
+        );
+    }
+
+    #[test]
+    fn test_router_rename_provider() {
+        let mut router = CcrRouter::new("deepseek,deepseek-chat".to_string());
+        router.think = Some("deepseek,deepseek-reasoner".to_string());
+        router.web_search = Some("gemini,gemini-2.5-flash".to_string());
+        router.extra.insert(
+            "image".to_string(),
+            serde_json::Value::String("deepseek,deepseek-vl".to_string()),
+        );
+
+        let changes = router.rename_provider("deepseek", "ds");
+        assert_eq!(changes.len(), 3);
+        assert_eq!(router.default, "ds,deepseek-chat");
+        assert_eq!(router.think.as_deref(), Some("ds,deepseek-reasoner"));
+        assert_eq!(
+            router.web_search.as_deref(),
+            Some("gemini,gemini-2.5-flash")
+        );
+        assert_eq!(router.extra["image"], "ds,deepseek-vl");
+        assert!(router.rename_provider("deepseek", "ds").is_empty());
+
+        let routes: Vec<String> = router
+            .provider_routes("ds")
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(routes, ["default", "think", "image"]);
+
+        // 默认路由不会被清空
+        let mut cleared = router.clone();
+        assert_eq!(cleared.clear_provider_routes("ds"), ["think", "image"]);
+        assert_eq!(cleared.default, "ds,deepseek-chat");
+        assert!(cleared.think.is_none() && !cleared.extra.contains_key("image"));
+
+        router.reassign_provider("ds", "gemini", |_| "gemini-2.5-pro".to_string());
+        assert_eq!(router.default, "gemini,gemini-2.5-pro");
+        assert!(router.provider_routes("ds").is_empty());
+    }
+
+    #[test]
+    fn test_profile_env() {
+        assert!(validate_env_name("HTTPS_PROXY").is_ok());
+        assert!(validate_env_name("API_TIMEOUT_MS").is_ok());
+        assert!(validate_env_name("1BAD").is_err());
+        assert!(validate_env_name("BAD-NAME").is_err());
+        assert!(validate_env_name("ANTHROPIC_BASE_URL").is_err());
+        assert!(validate_env_name("CCODE_VAULT_PASSPHRASE").is_err());
+        for name in [
+            "PATH",
+            "Path",
+            "HOME",
+            "SHELL",
+            "LC_ALL",
+            "LD_PRELOAD",
+            "SystemRoot",
+        ] {
+            assert!(validate_env_name(name).is_err(), "{name} 应为保留名称");
+        }
+
+        // 旧配置没有 env 字段，空 env 也不写入文件
+        let json =
+            r#"{"ANTHROPIC_AUTH_TOKEN":"sk-test","ANTHROPIC_BASE_URL":"https://api.test.com"}"#;
+        let mut profile: DirectProfile = serde_json::from_str(json).unwrap();
+        assert!(profile.env.is_empty());
+        assert!(!serde_json::to_string(&profile).unwrap().contains("env"));
+
+        let config = Config::default();
+        profile
+            .env
+            .insert("ANTHROPIC_MODEL".to_string(), "x".to_string());
+        assert!(config.validate_direct_profile(&profile).is_err());
+    }
+
+    #[test]
+    fn test_route_reference_validation() {
+        let providers = vec![CcrProvider::new(
+            "openrouter".to_string(),
+            "https://openrouter.ai/api/v1/chat/completions".to_string(),
+            "sk-or-test".to_string(),
+            vec![
+                "anthropic/claude-sonnet-4".to_string(),
+                "qwen:7b".to_string(),
+            ],
+            ProviderType::OpenRouter,
+        )];
+        assert!(providers[0].has_model("anthropic/claude-sonnet-4:online"));
+        assert!(providers[0].has_model("qwen:7b"));
+        assert!(!providers[0].has_model("qwen:14b"));
+
+        let mut router = CcrRouter::new("openrouter,anthropic/claude-sonnet-4".to_string());
+        router.web_search = Some("openrouter,anthropic/claude-sonnet-4:online".to_string());
+        assert!(router.check_references(&providers).is_empty());
+
+        router.think = Some("openrouter,openai/o3".to_string());
+        router.extra.insert(
+            "image".to_string(),
+            serde_json::Value::String("gemini,gemini-2.5-flash".to_string()),
+        );
+        let issues = router.check_references(&providers);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].route(), "think");
+        assert!(matches!(issues[1], RouteIssue::MissingProvider { .. }));
+
+        // 警告模式下只有缺失的 Provider 阻止保存
+        assert!(
+            router
+                .validate_references(&providers, RouteValidation::Warn)
+                .is_err()
+        );
+        router.extra.clear();
+        let warnings = router
+            .validate_references(&providers, RouteValidation::Warn)
+            .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            router
+                .validate_references(&providers, RouteValidation::Strict)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_ccr_endpoint() {
+        let mut config = CcrConfig::new();
+        assert_eq!(config.endpoint(), "http://127.0.0.1:3456");
+        assert_eq!(config.client_api_key(), "test");
+
+        config.HOST = Some("0.0.0.0".to_string());
+        config
+            .extra
+            .insert("PORT".to_string(), serde_json::json!(3457));
+        config.APIKEY = Some("secret".to_string());
+        assert_eq!(config.endpoint(), "http://127.0.0.1:3457");
+        assert_eq!(config.client_api_key(), "secret");
+
+        config.HOST = Some("::1".to_string());
+        assert_eq!(config.endpoint(), "http://[::1]:3457");
+    }
+
+    #[test]
+    fn test_config_backup_and_undo() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        let backups = ConfigBackups::with_paths(config_path.clone(), dir.path().join("backups"));
+
+        // 首次保存没有旧配置，不产生备份
+        let mut config = Config::default();
+        config
+            .add_direct_profile("work".to_string(), create_test_profile())
+            .unwrap();
+        config.save_with(&backups).unwrap();
+        assert!(backups.list_backups().unwrap().is_empty());
+
+        // 误删配置后可以撤销
+        config.remove_profile("work").unwrap();
+        config.save_with(&backups).unwrap();
+        assert_eq!(backups.list_backups().unwrap().len(), 1);
+
+        backups.undo().unwrap();
+        let restored: Config = serde_json::from_str(&backups.read_current().unwrap()).unwrap();
+        assert!(restored.groups.direct.contains_key("work"));
+        assert!(backups.list_backups().unwrap().is_empty());
+        assert!(backups.undo().is_err());
+    }
+
+    #[test]
+    fn test_config_restore_rejects_invalid_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        let backups = ConfigBackups::with_paths(config_path.clone(), dir.path().join("backups"));
+        Config::default().save_with(&backups).unwrap();
+
+        fs::create_dir_all(backups.backup_dir()).unwrap();
+        fs::write(
+            backups.backup_dir().join("config_backup_20000101_000000.json"),
+            r#"{"version": "1.0", "groups": {"direct": {"bad": {"ANTHROPIC_AUTH_TOKEN": "", "ANTHROPIC_BASE_URL": ""}}, "router": {}}}"#,
+        )
+        .unwrap();
+
+        let original = backups.read_current().unwrap();
+        assert!(backups.restore_backup("20000101_000000").is_err());
+        assert_eq!(backups.read_current().unwrap(), original);
+    }
 }
@@ -0,0 +1,197 @@
+//! 文本输出层
+//!
+//! 命令的结果数据（列表、详情、差异等）写到标准输出，进度、提示、警告和交互提问写到标准错误，
+//! 使标准输出可以直接交给脚本处理。`--quiet`/`--verbose` 控制信息量，
+//! `--no-emoji` 或 `NO_COLOR` 环境变量去掉行首的 emoji 前缀。
+
+use std::io::{self, Write};
+use std::sync::OnceLock;
+
+/// 信息输出的详细程度
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// 只输出结果数据、警告和错误
+    Quiet,
+    #[default]
+    Normal,
+    /// 额外输出备份、同步等过程信息
+    Verbose,
+}
+
+/// 文本输出样式（来自全局命令行参数与环境变量）
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Console {
+    pub verbosity: Verbosity,
+    /// 去掉行首 emoji，输出纯文本
+    pub plain: bool,
+}
+
+impl Console {
+    /// 根据命令行参数和 `NO_COLOR` 环境变量构造
+    pub fn from_args(quiet: bool, verbose: bool, no_emoji: bool) -> Self {
+        let verbosity = if quiet {
+            Verbosity::Quiet
+        } else if verbose {
+            Verbosity::Verbose
+        } else {
+            Verbosity::Normal
+        };
+        // https://no-color.org: 设置为任意非空值即生效
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        Console {
+            verbosity,
+            plain: no_emoji || no_color,
+        }
+    }
+}
+
+static CONSOLE: OnceLock<Console> = OnceLock::new();
+
+/// 设置当前进程的输出样式（仅首次调用生效）
+pub fn set_console(console: Console) {
+    let _ = CONSOLE.set(console);
+}
+
+/// 当前输出样式
+pub fn console() -> Console {
+    *CONSOLE.get_or_init(Console::default)
+}
+
+/// 是否为 emoji 前缀中的字符（含变体选择符与零宽连接符）
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF
+            | 0x2600..=0x27BF
+            | 0x2300..=0x23FF
+            | 0x2190..=0x21FF
+            | 0x2B00..=0x2BFF
+            | 0x2139
+            | 0x200D
+            | 0x20E3
+            | 0xFE0F
+    )
+}
+
+/// 去掉每行行首（保留缩进）的 emoji 及其后的空格
+pub fn strip_emoji(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            let body = line.trim_start_matches(' ');
+            let indent = &line[..line.len() - body.len()];
+            let stripped = body.trim_start_matches(is_emoji);
+            if stripped.len() == body.len() {
+                line.to_string()
+            } else {
+                format!("{indent}{}", stripped.trim_start_matches(' '))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 按当前样式处理文本
+pub fn decorate(text: &str) -> String {
+    if console().plain {
+        strip_emoji(text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// 结果数据，写到标准输出，不受 `--quiet` 影响
+pub fn data(text: &str) {
+    println!("{}", decorate(text));
+}
+
+/// 一般信息，写到标准错误，`--quiet` 时不输出
+pub fn info(text: &str) {
+    if console().verbosity >= Verbosity::Normal {
+        eprintln!("{}", decorate(text));
+    }
+}
+
+/// 过程信息，写到标准错误，仅 `--verbose` 时输出
+pub fn verbose(text: &str) {
+    if console().verbosity >= Verbosity::Verbose {
+        eprintln!("{}", decorate(text));
+    }
+}
+
+/// 警告与错误，写到标准错误，始终输出
+pub fn warn(text: &str) {
+    eprintln!("{}", decorate(text));
+}
+
+/// 交互提问，写到标准错误且不换行
+pub fn prompt(text: &str) {
+    eprint!("{}", decorate(text));
+    let _ = io::stderr().flush();
+}
+
+/// 输出结果数据到标准输出：`outln!("...")`
+#[macro_export]
+macro_rules! outln {
+    () => {
+        $crate::console::data("")
+    };
+    ($($arg:tt)*) => {
+        $crate::console::data(&format!($($arg)*))
+    };
+}
+
+/// 输出一般信息到标准错误：`info!("...")`
+#[macro_export]
+macro_rules! info {
+    () => {
+        $crate::console::info("")
+    };
+    ($($arg:tt)*) => {
+        $crate::console::info(&format!($($arg)*))
+    };
+}
+
+/// 输出仅在 `--verbose` 时显示的过程信息：`verbose!("...")`
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        $crate::console::verbose(&format!($($arg)*))
+    };
+}
+
+/// 输出警告到标准错误：`warn!("...")`
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        $crate::console::warn(&format!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_emoji() {
+        assert_eq!(strip_emoji("✅ 配置已保存"), "配置已保存");
+        assert_eq!(strip_emoji("⚠️  警告: x"), "警告: x");
+        assert_eq!(strip_emoji("   🗂️  a\n🔗 b"), "   a\nb");
+        // 行中的符号与非 emoji 前缀保持不变
+        assert_eq!(strip_emoji("📊 对比 a → b"), "对比 a → b");
+        assert_eq!(strip_emoji("   • work"), "   • work");
+        assert_eq!(strip_emoji("  + path: 1"), "  + path: 1");
+    }
+
+    #[test]
+    fn test_verbosity_from_args() {
+        assert_eq!(
+            Console::from_args(true, true, false).verbosity,
+            Verbosity::Quiet
+        );
+        assert_eq!(
+            Console::from_args(false, true, false).verbosity,
+            Verbosity::Verbose
+        );
+        assert!(Console::from_args(false, false, true).plain);
+    }
+}
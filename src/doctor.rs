@@ -0,0 +1,655 @@
+//! 健康检查
+//!
+//! `ccode doctor` 依次检查依赖工具、配置文件、默认配置、路由引用、令牌与密钥以及文件权限，
+//! 每项结果为通过、警告或失败，并尽量给出修复建议。
+
+use crate::backup::BackupTarget;
+use crate::ccr_config::CcrConfigManager;
+use crate::config::{CcrConfig, Config, ConfigBackups, RouteIssue, RouteValidation};
+use crate::console;
+use crate::error::{AppError, AppResult};
+use crate::output::{Table, Tabular};
+use crate::secrets::{self, SecretRef, VaultBackend};
+use crate::t;
+use serde::Serialize;
+use std::path::Path;
+use std::process::Command;
+
+/// 检查结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl Status {
+    /// 文本报告中的状态标记（纯文本模式下使用文字）
+    pub fn icon(self) -> String {
+        match (self, console::console().plain) {
+            (Status::Pass, false) => "✅".to_string(),
+            (Status::Warn, false) => "⚠️ ".to_string(),
+            (Status::Fail, false) => "❌".to_string(),
+            (Status::Pass, true) => t!("doctor.status_pass"),
+            (Status::Warn, true) => t!("doctor.status_warn"),
+            (Status::Fail, true) => t!("doctor.status_fail"),
+        }
+    }
+}
+
+/// 单项检查
+#[derive(Debug, Serialize)]
+pub struct Check {
+    pub status: Status,
+    pub message: String,
+    /// 修复建议
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl Check {
+    fn pass(message: String) -> Self {
+        Self {
+            status: Status::Pass,
+            message,
+            fix: None,
+        }
+    }
+
+    fn warn(message: String) -> Self {
+        Self {
+            status: Status::Warn,
+            message,
+            fix: None,
+        }
+    }
+
+    fn fail(message: String) -> Self {
+        Self {
+            status: Status::Fail,
+            message,
+            fix: None,
+        }
+    }
+
+    fn fix(mut self, fix: String) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+}
+
+/// 一组相关的检查
+#[derive(Debug, Serialize)]
+pub struct Section {
+    pub id: &'static str,
+    pub title: String,
+    pub checks: Vec<Check>,
+}
+
+/// 各状态的检查项数量
+#[derive(Debug, Default, Serialize)]
+pub struct Summary {
+    pub pass: usize,
+    pub warn: usize,
+    pub fail: usize,
+}
+
+/// 健康检查报告
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub sections: Vec<Section>,
+    pub summary: Summary,
+}
+
+impl Report {
+    /// 执行全部检查
+    pub fn run() -> Self {
+        let config = Config::load();
+        let manager = CcrConfigManager::new();
+        let ccr_config = match &manager {
+            Ok(manager) if manager.config_exists() => Some(manager.load_config()),
+            _ => None,
+        };
+
+        let config_ok = config.as_ref().ok();
+        let ccr_ok = ccr_config.as_ref().and_then(|result| result.as_ref().ok());
+        let uses_router =
+            config_ok.is_some_and(|config| !config.groups.router.is_empty()) || ccr_ok.is_some();
+
+        let mut sections = vec![
+            Section {
+                id: "tools",
+                title: t!("doctor.section.tools"),
+                checks: check_tools(uses_router),
+            },
+            Section {
+                id: "config",
+                title: t!("doctor.section.config"),
+                checks: check_config_files(&config, &manager, ccr_config.as_ref(), uses_router),
+            },
+        ];
+
+        if let Some(config) = config_ok {
+            sections.push(Section {
+                id: "defaults",
+                title: t!("doctor.section.defaults"),
+                checks: check_defaults(config),
+            });
+        }
+        if uses_router {
+            sections.push(Section {
+                id: "routes",
+                title: t!("doctor.section.routes"),
+                checks: check_routes(config_ok, ccr_ok),
+            });
+        }
+        sections.push(Section {
+            id: "credentials",
+            title: t!("doctor.section.credentials"),
+            checks: check_credentials(config_ok, ccr_ok),
+        });
+        if cfg!(unix) {
+            let mut paths = Vec::new();
+            if let Ok(path) = Config::get_config_path() {
+                paths.push(path);
+            }
+            if let Ok(manager) = &manager {
+                paths.push(manager.config_path().to_path_buf());
+            }
+            if let Ok(path) = VaultBackend::default_path() {
+                paths.push(path);
+            }
+            // 备份目录中保存着两个配置文件的完整副本
+            let mut backup_dirs = Vec::new();
+            if let Ok(backups) = ConfigBackups::new() {
+                backup_dirs.push(backups.backup_dir().to_path_buf());
+            }
+            if let Ok(manager) = &manager {
+                backup_dirs.push(manager.backup_dir().to_path_buf());
+            }
+            sections.push(Section {
+                id: "permissions",
+                title: t!("doctor.section.permissions"),
+                checks: paths
+                    .iter()
+                    .filter_map(|path| check_permissions(path))
+                    .chain(
+                        backup_dirs
+                            .iter()
+                            .filter_map(|dir| check_backup_permissions(dir)),
+                    )
+                    .collect(),
+            });
+        }
+
+        sections.retain(|section| !section.checks.is_empty());
+
+        let mut summary = Summary::default();
+        for check in sections.iter().flat_map(|section| &section.checks) {
+            match check.status {
+                Status::Pass => summary.pass += 1,
+                Status::Warn => summary.warn += 1,
+                Status::Fail => summary.fail += 1,
+            }
+        }
+
+        Report { sections, summary }
+    }
+}
+
+impl Tabular for Report {
+    fn tables(&self) -> Vec<Table> {
+        let mut table = Table::new(vec!["SECTION", "STATUS", "CHECK", "FIX"]);
+        for section in &self.sections {
+            for check in &section.checks {
+                let status = serde_json::to_value(check.status)
+                    .ok()
+                    .and_then(|value| value.as_str().map(str::to_string))
+                    .unwrap_or_default();
+                table.push(vec![
+                    section.id.to_string(),
+                    status,
+                    check.message.clone(),
+                    check.fix.clone().unwrap_or_else(|| "-".to_string()),
+                ]);
+            }
+        }
+        vec![table]
+    }
+}
+
+/// 检查 claude 与 ccr 是否可用
+fn check_tools(uses_router: bool) -> Vec<Check> {
+    let claude = match tool_version("claude", "--version") {
+        Some(version) => Check::pass(t!("doctor.tool_found", tool = "claude", version)),
+        None => Check::fail(t!("doctor.tool_missing", tool = "claude"))
+            .fix(t!("doctor.fix.install_claude")),
+    };
+
+    let ccr = match tool_version("ccr", "-v") {
+        Some(version) => Check::pass(t!("doctor.tool_found", tool = "ccr", version)),
+        None if uses_router => {
+            Check::warn(t!("doctor.tool_missing", tool = "ccr")).fix(t!("doctor.fix.install_ccr"))
+        }
+        None => Check::pass(t!("doctor.ccr_not_needed")),
+    };
+
+    vec![claude, ccr]
+}
+
+/// 执行 `<program> <arg>` 读取版本号，程序不存在时返回 None
+fn tool_version(program: &str, arg: &str) -> Option<String> {
+    let output = Command::new(program).arg(arg).output().ok()?;
+    let text = String::from_utf8_lossy(&output.stdout).to_string()
+        + &String::from_utf8_lossy(&output.stderr);
+    Some(
+        text.lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .unwrap_or("?")
+            .to_string(),
+    )
+}
+
+/// 检查 ccode 与 claude-code-router 配置文件能否解析
+fn check_config_files(
+    config: &AppResult<Config>,
+    manager: &AppResult<CcrConfigManager>,
+    ccr_config: Option<&AppResult<CcrConfig>>,
+    uses_router: bool,
+) -> Vec<Check> {
+    let ccode_path = Config::get_config_path()
+        .map(|path| path.display().to_string())
+        .unwrap_or_default();
+    let ccode = match config {
+        Ok(_) => Check::pass(t!("doctor.ccode_config_ok", path = ccode_path)),
+        Err(AppError::ConfigNotFound) => {
+            Check::warn(t!("doctor.ccode_config_missing")).fix(t!("doctor.fix.add_profile"))
+        }
+        Err(e) => Check::fail(t!(
+            "doctor.ccode_config_invalid",
+            path = ccode_path,
+            err = e
+        ))
+        .fix(t!("doctor.fix.restore_ccode")),
+    };
+
+    let ccr = match (manager, ccr_config) {
+        (Err(e), _) => Check::fail(t!("doctor.ccr_config_unavailable", err = e)),
+        (Ok(manager), Some(Ok(ccr_config))) => {
+            let path = manager.config_path().display();
+            match ccr_config.validate() {
+                Ok(()) => Check::pass(t!("doctor.ccr_config_ok", path)),
+                Err(e) => Check::fail(t!("doctor.ccr_config_invalid", path, err = e))
+                    .fix(t!("doctor.fix.provider_add")),
+            }
+        }
+        (Ok(manager), Some(Err(e))) => Check::fail(t!(
+            "doctor.ccr_config_invalid",
+            path = manager.config_path().display(),
+            err = e
+        ))
+        .fix(t!("doctor.fix.restore_ccr")),
+        (Ok(_), None) if uses_router => {
+            Check::fail(t!("doctor.ccr_config_missing")).fix(t!("doctor.fix.provider_add"))
+        }
+        (Ok(_), None) => Check::pass(t!("doctor.ccr_config_not_needed")),
+    };
+
+    vec![ccode, ccr]
+}
+
+/// 检查默认配置是否指向存在的配置
+fn check_defaults(config: &Config) -> Vec<Check> {
+    let default_profile = config.default_profile.as_ref();
+    let groups = [
+        (
+            "direct",
+            default_profile.and_then(|d| d.direct.as_ref()),
+            config.groups.direct.keys().collect::<Vec<_>>(),
+            "ccode use",
+        ),
+        (
+            "ccr",
+            default_profile.and_then(|d| d.router.as_ref()),
+            config.groups.router.keys().collect::<Vec<_>>(),
+            "ccode use-ccr",
+        ),
+    ];
+
+    let mut checks = Vec::new();
+    for (group, default, mut names, command) in groups {
+        names.sort();
+        let suggestion = || {
+            t!(
+                "doctor.fix.set_default",
+                command,
+                name = names.first().map(|name| name.as_str()).unwrap_or("<name>")
+            )
+        };
+        match default {
+            Some(name) if names.contains(&name) => {
+                checks.push(Check::pass(t!("doctor.default_ok", group, name)));
+            }
+            Some(name) => {
+                checks
+                    .push(Check::fail(t!("doctor.default_missing", group, name)).fix(suggestion()));
+            }
+            None if !names.is_empty() => {
+                checks.push(Check::warn(t!("doctor.default_unset", group)).fix(suggestion()));
+            }
+            None => {}
+        }
+    }
+    checks
+}
+
+/// 检查 claude-code-router Router 节点与各 RouterProfile 的路由引用
+///
+/// 模型不在 Provider 模型列表中的路由，在严格校验模式下为失败，否则为警告。
+fn check_routes(config: Option<&Config>, ccr_config: Option<&CcrConfig>) -> Vec<Check> {
+    let mut checks = Vec::new();
+    let providers = ccr_config.map(|c| c.Providers.as_slice()).unwrap_or(&[]);
+    let mode = RouteValidation::current();
+
+    if let Some(ccr_config) = ccr_config {
+        let issues = ccr_config.Router.check_references(providers);
+        if issues.is_empty() {
+            checks.push(Check::pass(t!("doctor.ccr_router_ok")));
+        }
+        let fix = t!("doctor.fix.ccr_router");
+        for issue in &issues {
+            checks.push(route_check(&t!("route_ref.ccr_label"), issue, mode, &fix));
+        }
+    }
+
+    if let Some(config) = config {
+        let mut names: Vec<&String> = config.groups.router.keys().collect();
+        names.sort();
+        let mut problems = 0;
+        for name in &names {
+            let label = t!("route_ref.profile_label", name);
+            let fix = t!("doctor.fix.edit_profile", name);
+            let issues = config.groups.router[*name]
+                .router
+                .check_references(providers);
+            problems += issues.len();
+            for issue in &issues {
+                checks.push(route_check(&label, issue, mode, &fix));
+            }
+        }
+        if !names.is_empty() && problems == 0 {
+            checks.push(Check::pass(t!(
+                "doctor.profile_routes_ok",
+                count = names.len()
+            )));
+        }
+    }
+
+    checks
+}
+
+/// 路由问题对应的检查项，`fix` 为 Provider 不存在时的修复建议
+fn route_check(label: &str, issue: &RouteIssue, mode: RouteValidation, fix: &str) -> Check {
+    let route = issue.route();
+    match issue {
+        RouteIssue::MissingProvider { provider, .. } => {
+            Check::fail(t!("doctor.route_missing_provider", label, route, provider))
+                .fix(fix.to_string())
+        }
+        RouteIssue::UnknownModel {
+            provider, model, ..
+        } => {
+            let message = t!("doctor.route_unknown_model", label, route, model, provider);
+            let check = if issue.is_error(mode) {
+                Check::fail(message)
+            } else {
+                Check::warn(message)
+            };
+            check.fix(t!("doctor.fix.provider_edit", provider))
+        }
+    }
+}
+
+/// 检查 Direct 配置令牌与 Provider 密钥的格式及其引用的环境变量、文件
+fn check_credentials(config: Option<&Config>, ccr_config: Option<&CcrConfig>) -> Vec<Check> {
+    let mut checks = Vec::new();
+    let mut checked = 0;
+
+    if let Some(config) = config {
+        let mut names: Vec<&String> = config.groups.direct.keys().collect();
+        names.sort();
+        for name in names {
+            checked += 1;
+            let label = t!("doctor.direct_label", name);
+            let token = &config.groups.direct[name].anthropic_auth_token;
+            let edit = t!("doctor.fix.edit_profile", name);
+            checks.extend(check_secret_value(&label, token, &edit));
+        }
+    }
+
+    if let Some(ccr_config) = ccr_config {
+        for provider in &ccr_config.Providers {
+            checked += 1;
+            let label = t!("doctor.provider_label", name = provider.name);
+            let key = provider.api_key.trim();
+            let edit = t!("doctor.fix.provider_edit", provider = provider.name);
+
+            if key == secrets::provider_env_ref(&provider.name) {
+                // 由 ccode 在启动 ccr 时解析：file:/cmd: 引用或密钥存储后端
+                if let Some(reference) =
+                    config.and_then(|config| config.provider_key_refs.get(&provider.name))
+                {
+                    checks.extend(check_secret_value(&label, reference, &edit));
+                }
+            } else if let Some(var) = key.strip_prefix('$') {
+                let var = var
+                    .strip_prefix('{')
+                    .and_then(|v| v.strip_suffix('}'))
+                    .unwrap_or(var);
+                if std::env::var(var).map_or(true, |value| value.is_empty()) {
+                    checks.push(
+                        Check::warn(t!("doctor.provider_env_unset", label, var))
+                            .fix(t!("doctor.fix.export_env", var)),
+                    );
+                }
+            } else {
+                checks.extend(check_secret_value(&label, key, &edit));
+            }
+        }
+    }
+
+    if checks.is_empty() && checked > 0 {
+        checks.push(Check::pass(t!("doctor.credentials_ok", count = checked)));
+    }
+    checks
+}
+
+/// 检查单个令牌或密钥：引用是否可解析，普通值格式是否可疑
+///
+/// 密钥存储后端与 `cmd:` 引用需要密码或执行命令，这里不做检查；`edit` 为修改该值的命令。
+fn check_secret_value(label: &str, value: &str, edit: &str) -> Option<Check> {
+    match SecretRef::parse(value) {
+        Some(reference) => {
+            if let Err(e) = reference.validate() {
+                return Some(
+                    Check::fail(t!("doctor.secret_invalid", label, err = e)).fix(edit.to_string()),
+                );
+            }
+            match reference {
+                SecretRef::Env(var) if std::env::var(var).map_or(true, |v| v.is_empty()) => Some(
+                    Check::fail(t!("doctor.secret_env_unset", label, var))
+                        .fix(t!("doctor.fix.export_env", var)),
+                ),
+                SecretRef::File(path) if !secrets::expand_home(path).is_file() => Some(
+                    Check::fail(t!("doctor.secret_file_missing", label, path))
+                        .fix(t!("doctor.fix.secret_file", path)),
+                ),
+                _ => None,
+            }
+        }
+        None => malformed_reason(value).map(|reason| {
+            Check::warn(t!("doctor.secret_malformed", label, reason)).fix(edit.to_string())
+        }),
+    }
+}
+
+/// 普通令牌看起来不正确的原因（复制时带入空白、引号、`Bearer` 前缀等）
+fn malformed_reason(token: &str) -> Option<String> {
+    if token.trim().is_empty() {
+        Some(t!("doctor.malformed.empty"))
+    } else if token.trim_start().starts_with("Bearer ") {
+        Some(t!("doctor.malformed.bearer"))
+    } else if token.chars().any(char::is_whitespace) {
+        Some(t!("doctor.malformed.whitespace"))
+    } else if token.contains(['"', '\'', '<', '>']) {
+        Some(t!("doctor.malformed.quotes"))
+    } else if !token.is_ascii() {
+        Some(t!("doctor.malformed.non_ascii"))
+    } else if token.len() < 8 {
+        Some(t!("doctor.malformed.too_short"))
+    } else {
+        None
+    }
+}
+
+/// 包含密钥的文件不应允许其他用户访问
+#[cfg(unix)]
+fn check_permissions(path: &Path) -> Option<Check> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = std::fs::metadata(path).ok()?.permissions().mode() & 0o777;
+    let path = path.display();
+    Some(if mode & 0o077 == 0 {
+        Check::pass(t!(
+            "doctor.permissions_ok",
+            path,
+            mode = format!("{mode:o}")
+        ))
+    } else {
+        Check::warn(t!(
+            "doctor.permissions_open",
+            path,
+            mode = format!("{mode:o}")
+        ))
+        .fix(t!("doctor.fix.chmod", path))
+    })
+}
+
+#[cfg(not(unix))]
+fn check_permissions(_path: &Path) -> Option<Check> {
+    None
+}
+
+/// 备份目录及其中的备份文件都不应允许其他用户访问
+#[cfg(unix)]
+fn check_backup_permissions(dir: &Path) -> Option<Check> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode_of = |path: &Path| {
+        std::fs::metadata(path)
+            .ok()
+            .map(|metadata| metadata.permissions().mode() & 0o777)
+    };
+    let dir_mode = mode_of(dir)?;
+    let files: Vec<_> = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .collect();
+    let open = std::iter::once(dir_mode)
+        .chain(files.iter().filter_map(|file| mode_of(file)))
+        .filter(|mode| mode & 0o077 != 0)
+        .count();
+
+    let path = dir.display();
+    Some(if open == 0 {
+        Check::pass(t!(
+            "doctor.backup_permissions_ok",
+            path,
+            count = files.len()
+        ))
+    } else {
+        Check::warn(t!("doctor.backup_permissions_open", path, count = open))
+            .fix(t!("doctor.fix.chmod_recursive", path))
+    })
+}
+
+#[cfg(not(unix))]
+fn check_backup_permissions(_dir: &Path) -> Option<Check> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_token() {
+        assert!(malformed_reason("sk-ant-api03-abcdef").is_none());
+        assert!(malformed_reason("").is_some());
+        assert!(malformed_reason("Bearer sk-ant-api03").is_some());
+        assert!(malformed_reason("sk-ant api03").is_some());
+        assert!(malformed_reason("\"sk-ant-api03\"").is_some());
+        assert!(malformed_reason("sk-1").is_some());
+    }
+
+    #[test]
+    fn test_route_checks() {
+        let missing = RouteIssue::MissingProvider {
+            route: "think".to_string(),
+            provider: "gemini".to_string(),
+        };
+        let unknown = RouteIssue::UnknownModel {
+            route: "default".to_string(),
+            provider: "deepseek".to_string(),
+            model: "deepseek-coder".to_string(),
+        };
+
+        for mode in [RouteValidation::Warn, RouteValidation::Strict] {
+            assert_eq!(route_check("p", &missing, mode, "fix").status, Status::Fail);
+        }
+        let warn = route_check("p", &unknown, RouteValidation::Warn, "fix");
+        assert_eq!(warn.status, Status::Warn);
+        assert!(warn.fix.unwrap().contains("deepseek"));
+        assert_eq!(
+            route_check("p", &unknown, RouteValidation::Strict, "fix").status,
+            Status::Fail
+        );
+    }
+
+    #[test]
+    fn test_secret_reference_checks() {
+        assert!(check_secret_value("t", "env:CCODE_DOCTOR_TEST_UNSET_VAR", "fix").is_some());
+        assert!(check_secret_value("t", "file:/nonexistent/ccode/token", "fix").is_some());
+        assert!(check_secret_value("t", "secret://ccode/work", "fix").is_none());
+        assert!(check_secret_value("t", "sk-ant-api03-abcdef", "fix").is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_backup_permission_checks() {
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let backups = dir.path().join("backups");
+        fs::create_dir(&backups).unwrap();
+        fs::set_permissions(&backups, fs::Permissions::from_mode(0o700)).unwrap();
+        let backup = backups.join("config_backup_20250101_120000_000.json");
+        fs::write(&backup, "{}").unwrap();
+        fs::set_permissions(&backup, fs::Permissions::from_mode(0o600)).unwrap();
+        assert_eq!(
+            check_backup_permissions(&backups).unwrap().status,
+            Status::Pass
+        );
+
+        fs::set_permissions(&backup, fs::Permissions::from_mode(0o644)).unwrap();
+        let check = check_backup_permissions(&backups).unwrap();
+        assert_eq!(check.status, Status::Warn);
+        assert!(check.fix.unwrap().contains("go-rwx"));
+
+        assert!(check_backup_permissions(&dir.path().join("missing")).is_none());
+    }
+}
@@ -0,0 +1,163 @@
+//! 外部编辑器
+//!
+//! `--raw` 编辑时将配置节点写入临时 JSON 文件并用 `$VISUAL`/`$EDITOR` 打开，保存退出后重新解析校验。
+//! 校验失败时把错误信息以 `//` 注释写在文件开头并重新打开编辑器（与 `git commit`、`crontab -e` 相同），
+//! 清空文件内容即可放弃编辑。临时文件名随机生成，仅当前用户可读写。
+
+use crate::error::{AppError, AppResult};
+use crate::{info, t};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::NamedTempFile;
+
+/// 注释行前缀（JSON 字符串不能跨行，以此开头的行不会出现在合法内容中）
+const COMMENT_PREFIX: &str = "//";
+
+/// 未设置 `$VISUAL`/`$EDITOR` 时使用的编辑器
+#[cfg(windows)]
+const DEFAULT_EDITOR: &str = "notepad";
+#[cfg(not(windows))]
+const DEFAULT_EDITOR: &str = "vi";
+
+/// 用户选择的编辑器命令：`$VISUAL` 优先，其次 `$EDITOR`
+pub fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.trim().is_empty()))
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string())
+}
+
+/// 在编辑器中修改 `value`，返回通过 `validate` 校验的新值
+///
+/// 内容未修改或文件被清空时返回 `None`。
+pub fn edit_json<T, F>(label: &str, value: &T, mut validate: F) -> AppResult<Option<T>>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnMut(&T) -> AppResult<()>,
+{
+    let original = serde_json::to_value(value)?;
+    let mut body = format!("{}\n", serde_json::to_string_pretty(&original)?);
+    let mut error: Option<String> = None;
+
+    let file = temp_file(label)?;
+    let editor = editor_command();
+
+    loop {
+        fs::write(file.path(), annotate(label, error.as_deref(), &body))?;
+        launch(&editor, file.path())?;
+
+        body = strip_comments(&fs::read_to_string(file.path())?);
+        if body.trim().is_empty() {
+            info!("{}", t!("editor.cancelled"));
+            return Ok(None);
+        }
+
+        let mut parse = || -> AppResult<(serde_json::Value, T)> {
+            let json: serde_json::Value = serde_json::from_str(&body)?;
+            let edited: T = serde_json::from_value(json.clone())?;
+            validate(&edited)?;
+            Ok((json, edited))
+        };
+
+        match parse() {
+            Ok((json, _)) if json == original => {
+                info!("{}", t!("editor.unchanged"));
+                return Ok(None);
+            }
+            Ok((_, edited)) => return Ok(Some(edited)),
+            Err(e) => {
+                info!("{}", t!("editor.invalid", err = e));
+                error = Some(e.to_string());
+            }
+        }
+    }
+}
+
+/// 在内容前加上说明和上一次的错误信息（均为注释行）
+fn annotate(label: &str, error: Option<&str>, body: &str) -> String {
+    let mut text = String::new();
+    if let Some(error) = error {
+        for line in t!("editor.error_header", err = error).lines() {
+            text.push_str(&format!("{COMMENT_PREFIX} {line}\n"));
+        }
+        text.push_str(&format!("{COMMENT_PREFIX}\n"));
+    }
+    for line in t!("editor.header", label).lines() {
+        text.push_str(&format!("{COMMENT_PREFIX} {line}\n"));
+    }
+    text.push_str(body);
+    text
+}
+
+/// 去掉注释行
+fn strip_comments(text: &str) -> String {
+    text.lines()
+        .filter(|line| !line.trim_start().starts_with(COMMENT_PREFIX))
+        .map(|line| format!("{line}\n"))
+        .collect()
+}
+
+/// 启动编辑器并等待退出
+///
+/// Unix 上通过 `sh` 执行，使 `EDITOR="code --wait"` 这类带参数的写法可用。
+fn launch(editor: &str, path: &Path) -> AppResult<()> {
+    #[cfg(unix)]
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{editor} \"$@\""))
+        .arg(editor)
+        .arg(path)
+        .status();
+    #[cfg(not(unix))]
+    let status = {
+        let mut parts = editor.split_whitespace();
+        Command::new(parts.next().unwrap_or(DEFAULT_EDITOR))
+            .args(parts)
+            .arg(path)
+            .status()
+    };
+
+    let status = status
+        .map_err(|e| AppError::CommandExecution(t!("editor.launch_failed", editor, err = e)))?;
+    if !status.success() {
+        return Err(AppError::CommandExecution(t!(
+            "editor.exit_failed",
+            editor,
+            status
+        )));
+    }
+    Ok(())
+}
+
+/// 创建编辑用的临时文件：随机文件名、以独占方式创建，Unix 上权限为 0600，离开作用域时删除
+fn temp_file(label: &str) -> AppResult<NamedTempFile> {
+    let label: String = label
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    let file = tempfile::Builder::new()
+        .prefix(&format!("ccode-{label}-"))
+        .suffix(".json")
+        .tempfile()?;
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_annotate_and_strip_comments() {
+        let body = "{\n  \"url\": \"https://a//b\"\n}\n";
+        let text = annotate("work", Some("line one\nline two"), body);
+
+        assert!(text.starts_with("// "));
+        assert!(text.contains("// line two"));
+        assert!(text.ends_with(body));
+        // 字符串中的 // 不会被当作注释
+        assert_eq!(strip_comments(&text), body);
+    }
+}
@@ -1,4 +1,6 @@
+use crate::t;
 use std::fmt;
+use std::path::PathBuf;
 
 /// 应用程序错误类型
 #[derive(Debug)]
@@ -17,22 +19,33 @@ pub enum AppError {
     InvalidConfig(String),
     /// 命令执行错误
     CommandExecution(String),
+    /// 密钥存储相关错误
+    Secret(String),
+    /// 等待配置文件锁超时（其他 ccode 进程正在修改配置）
+    LockTimeout(PathBuf),
+    /// 子程序以非零状态退出（退出状态已提示，ccode 以相同的退出码退出）
+    ChildExit(i32),
 }
 
 impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            AppError::Config(msg) => write!(f, "配置错误: {msg}"),
-            AppError::Io(err) => write!(f, "文件操作错误: {err}"),
-            AppError::Json(err) => write!(f, "JSON格式错误: {err}"),
-            AppError::ConfigNotFound => {
-                write!(f, "配置文件不存在，请使用 'ccode add <name>' 添加配置")
-            }
+            AppError::Config(msg) => write!(f, "{}", t!("error.config", msg)),
+            AppError::Io(err) => write!(f, "{}", t!("error.io", err)),
+            AppError::Json(err) => write!(f, "{}", t!("error.json", err)),
+            AppError::ConfigNotFound => write!(f, "{}", t!("error.config_not_found")),
             AppError::ProfileNotFound(name) => {
-                write!(f, "配置 '{name}' 不存在，请使用 'ccode list' 查看可用配置")
+                write!(f, "{}", t!("error.profile_not_found", name))
+            }
+            AppError::InvalidConfig(msg) => write!(f, "{}", t!("error.invalid_config", msg)),
+            AppError::CommandExecution(msg) => {
+                write!(f, "{}", t!("error.command_execution", msg))
+            }
+            AppError::Secret(msg) => write!(f, "{}", t!("error.secret", msg)),
+            AppError::LockTimeout(path) => {
+                write!(f, "{}", t!("error.lock_timeout", path = path.display()))
             }
-            AppError::InvalidConfig(msg) => write!(f, "无效配置: {msg}"),
-            AppError::CommandExecution(msg) => write!(f, "命令执行失败: {msg}"),
+            AppError::ChildExit(code) => write!(f, "{}", t!("error.child_exit", code)),
         }
     }
 }
@@ -0,0 +1,406 @@
+use crate::error::{AppError, AppResult};
+use crate::t;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// 临时文件序号，避免同一进程内并发写入时文件名冲突
+static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// 默认的锁等待超时时间
+const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 获取锁失败时的重试间隔
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+thread_local! {
+    /// 当前线程已持有的锁及其重入次数，避免嵌套获取同一把锁时自我阻塞
+    static HELD_LOCKS: RefCell<HashMap<PathBuf, usize>> = RefCell::new(HashMap::new());
+}
+
+/// 跨进程的文件锁（基于 `<文件名>.lock` 锁文件的建议锁）
+///
+/// 用于保护配置文件的读取-修改-写入过程，离开作用域时自动释放。
+/// 同一线程内可重入。
+#[derive(Debug)]
+pub struct FileLock {
+    lock_path: PathBuf,
+    file: Option<File>,
+}
+
+impl FileLock {
+    /// 获取指定文件的锁，超时时间默认10秒，可通过 `CCODE_LOCK_TIMEOUT`（秒）调整
+    pub fn acquire(target: &Path) -> AppResult<Self> {
+        let timeout = std::env::var("CCODE_LOCK_TIMEOUT")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_LOCK_TIMEOUT);
+
+        Self::acquire_with_timeout(target, timeout)
+    }
+
+    /// 在指定超时时间内获取文件锁
+    pub fn acquire_with_timeout(target: &Path, timeout: Duration) -> AppResult<Self> {
+        let lock_path = lock_path_for(target);
+
+        // 当前线程已持有该锁时直接重入
+        let reentered = HELD_LOCKS.with(|held| {
+            let mut held = held.borrow_mut();
+            match held.get_mut(&lock_path) {
+                Some(count) => {
+                    *count += 1;
+                    true
+                }
+                None => false,
+            }
+        });
+        if reentered {
+            return Ok(Self {
+                lock_path,
+                file: None,
+            });
+        }
+
+        let dir = parent_dir(&lock_path);
+        if !dir.exists() {
+            fs::create_dir_all(&dir)?;
+        }
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&lock_path)?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            match file.try_lock() {
+                Ok(()) => break,
+                Err(TryLockError::WouldBlock) => {
+                    if Instant::now() >= deadline {
+                        return Err(AppError::LockTimeout(target.to_path_buf()));
+                    }
+                    std::thread::sleep(LOCK_RETRY_INTERVAL);
+                }
+                Err(TryLockError::Error(e)) => return Err(AppError::Io(e)),
+            }
+        }
+
+        HELD_LOCKS.with(|held| held.borrow_mut().insert(lock_path.clone(), 1));
+
+        Ok(Self {
+            lock_path,
+            file: Some(file),
+        })
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        HELD_LOCKS.with(|held| {
+            let mut held = held.borrow_mut();
+            if let Some(count) = held.get_mut(&self.lock_path) {
+                *count -= 1;
+                if *count == 0 {
+                    held.remove(&self.lock_path);
+                }
+            }
+        });
+
+        if let Some(file) = self.file.take() {
+            let _ = file.unlock();
+        }
+    }
+}
+
+/// 获取目标文件对应的锁文件路径
+fn lock_path_for(target: &Path) -> PathBuf {
+    let mut name = target
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_default();
+    name.push(".lock");
+    target.with_file_name(name)
+}
+
+/// 原子写入文件
+///
+/// 先写入同目录下的临时文件并同步到磁盘，再通过重命名替换目标文件。
+/// 写入过程中崩溃、磁盘写满或被中断时，原文件保持不变。
+/// 目标是符号链接时写入链接指向的文件，链接本身保持不变。
+pub fn atomic_write(path: &Path, contents: &[u8]) -> AppResult<()> {
+    atomic_write_with(path, |file| file.write_all(contents))
+}
+
+/// 原子写入文件，由回调负责向临时文件写入内容
+pub fn atomic_write_with<F>(path: &Path, write: F) -> AppResult<()>
+where
+    F: FnOnce(&mut File) -> io::Result<()>,
+{
+    let path = resolve_symlink(path);
+    let dir = parent_dir(&path);
+    if !dir.exists() {
+        fs::create_dir_all(&dir)?;
+    }
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| AppError::Config(t!("fs.invalid_path", path = path.display())))?
+        .to_string_lossy();
+    let temp_path = dir.join(format!(
+        ".{file_name}.{}.{}.tmp",
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = write_and_replace(&path, &temp_path, write);
+    if result.is_err() {
+        // 清理未完成的临时文件，原文件不受影响
+        let _ = fs::remove_file(&temp_path);
+    }
+    result?;
+
+    sync_dir(&dir);
+    Ok(())
+}
+
+/// 写入临时文件、同步并重命名到目标路径
+///
+/// 临时文件创建时即使用原文件的权限（原文件不存在时为 0600），
+/// 写入内容前不会对其他用户可读。
+fn write_and_replace<F>(path: &Path, temp_path: &Path, write: F) -> io::Result<()>
+where
+    F: FnOnce(&mut File) -> io::Result<()>,
+{
+    let permissions = target_permissions(path);
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if let Some(permissions) = &permissions {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(permissions.mode() & 0o777);
+    }
+    let mut file = options.open(temp_path)?;
+    // 临时文件已存在时创建模式不生效，这里再设置一次
+    if let Some(permissions) = permissions {
+        file.set_permissions(permissions)?;
+    }
+
+    write(&mut file)?;
+    file.sync_all()?;
+    drop(file);
+
+    fs::rename(temp_path, path)
+}
+
+/// 写入后的文件权限：保留原文件权限（例如包含密钥的配置文件可能设置为 0600），
+/// 首次创建时在 Unix 上仅当前用户可读写
+fn target_permissions(path: &Path) -> Option<fs::Permissions> {
+    if let Ok(metadata) = fs::metadata(path) {
+        return Some(metadata.permissions());
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        Some(fs::Permissions::from_mode(0o600))
+    }
+    #[cfg(not(unix))]
+    None
+}
+
+/// 目标是符号链接时返回链接指向的路径，避免重命名时用普通文件替换链接
+fn resolve_symlink(path: &Path) -> PathBuf {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_symlink() => {
+            fs::canonicalize(path).unwrap_or_else(|_| match fs::read_link(path) {
+                // 悬空链接：相对路径相对于链接所在目录
+                Ok(target) => parent_dir(path).join(target),
+                Err(_) => path.to_path_buf(),
+            })
+        }
+        _ => path.to_path_buf(),
+    }
+}
+
+/// 创建仅当前用户可访问的目录（Unix 上为 0700），已存在时不做修改
+pub fn create_private_dir_all(dir: &Path) -> io::Result<()> {
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    builder.create(dir)
+}
+
+/// 获取文件所在目录（相对路径无父目录时使用当前目录）
+fn parent_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+/// 同步目录项，确保重命名操作持久化（仅Unix有效）
+fn sync_dir(dir: &Path) {
+    #[cfg(unix)]
+    if let Ok(dir) = File::open(dir) {
+        let _ = dir.sync_all();
+    }
+    #[cfg(not(unix))]
+    let _ = dir;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_files(dir: &Path) -> Vec<String> {
+        fs::read_dir(dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| name.ends_with(".tmp"))
+            .collect()
+    }
+
+    #[test]
+    fn test_atomic_write_replaces_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        fs::write(&path, "{\"old\": true}").unwrap();
+
+        atomic_write(&path, b"{\"new\": true}").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"new\": true}");
+        assert!(temp_files(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_atomic_write_creates_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("config.json");
+
+        atomic_write(&path, b"{}").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_failed_write_keeps_original_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        fs::write(&path, "{\"old\": true}").unwrap();
+
+        // 模拟写入到一半时失败（如磁盘已满）
+        let result = atomic_write_with(&path, |file| {
+            file.write_all(b"{\"trunc")?;
+            Err(io::Error::other("disk full"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"old\": true}");
+        assert!(temp_files(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_lock_blocks_other_holders_until_released() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+
+        let lock = FileLock::acquire(&path).unwrap();
+
+        // 其他线程（等同于其他进程的独立文件句柄）在超时后得到明确的错误
+        let contended = path.clone();
+        let result = std::thread::spawn(move || {
+            FileLock::acquire_with_timeout(&contended, Duration::from_millis(200)).map(|_| ())
+        })
+        .join()
+        .unwrap();
+        assert!(matches!(result, Err(AppError::LockTimeout(p)) if p == path));
+
+        drop(lock);
+
+        let released = path.clone();
+        let result = std::thread::spawn(move || {
+            FileLock::acquire_with_timeout(&released, Duration::from_millis(200)).map(|_| ())
+        })
+        .join()
+        .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_lock_is_reentrant_within_thread() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+
+        let outer = FileLock::acquire(&path).unwrap();
+        let inner = FileLock::acquire_with_timeout(&path, Duration::from_millis(100)).unwrap();
+        drop(inner);
+
+        // 内层释放后外层仍持有锁
+        let contended = path.clone();
+        let result = std::thread::spawn(move || {
+            FileLock::acquire_with_timeout(&contended, Duration::from_millis(100)).map(|_| ())
+        })
+        .join()
+        .unwrap();
+        assert!(result.is_err());
+
+        drop(outer);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_atomic_write_preserves_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        fs::write(&path, "{}").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+
+        atomic_write(&path, b"{\"new\": true}").unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o600);
+
+        // 首次写入的文件同样仅当前用户可读写
+        let created = dir.path().join("new.json");
+        atomic_write(&created, b"{}").unwrap();
+        let mode = fs::metadata(&created).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode, 0o600);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_atomic_write_follows_symlink() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("dotfiles").join("config.json");
+        fs::create_dir_all(target.parent().unwrap()).unwrap();
+        fs::write(&target, "{}").unwrap();
+        let link = dir.path().join("config.json");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        atomic_write(&link, b"{\"new\": true}").unwrap();
+
+        assert!(
+            fs::symlink_metadata(&link)
+                .unwrap()
+                .file_type()
+                .is_symlink()
+        );
+        assert_eq!(fs::read_to_string(&target).unwrap(), "{\"new\": true}");
+        assert!(temp_files(dir.path()).is_empty());
+        assert!(temp_files(target.parent().unwrap()).is_empty());
+    }
+}
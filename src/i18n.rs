@@ -0,0 +1,336 @@
+//! 多语言消息目录
+//!
+//! 所有面向用户的文本都通过 [`t!`](crate::t) 按键名从当前语言的消息目录中查找，
+//! 语言依次由 `--lang`、`CCODE_LANG` 环境变量和系统 locale（`LC_ALL`、`LC_MESSAGES`、`LANG`）决定，
+//! 均未指定时使用简体中文。
+
+mod en;
+mod zh_cn;
+
+use clap::{Command, ValueEnum};
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Lang {
+    /// 简体中文
+    #[default]
+    #[value(name = "zh-CN", alias = "zh")]
+    ZhCn,
+    /// English
+    #[value(name = "en")]
+    En,
+}
+
+impl Lang {
+    /// 从 `--lang`/`CCODE_LANG` 的取值或 locale 字符串（如 `en_US.UTF-8`）解析语言
+    pub fn from_locale(value: &str) -> Option<Lang> {
+        let language = value
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .split(['_', '-'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "zh" => Some(Lang::ZhCn),
+            "en" => Some(Lang::En),
+            _ => None,
+        }
+    }
+
+    /// 按优先级检测语言：命令行参数、`CCODE_LANG`、系统 locale
+    pub fn detect(cli: Option<Lang>) -> Lang {
+        if let Some(lang) = cli {
+            return lang;
+        }
+        if let Some(lang) = env_lang("CCODE_LANG") {
+            return lang;
+        }
+        // POSIX 约定：第一个非空的 locale 变量生效
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+            .and_then(|locale| Lang::from_locale(&locale))
+            .unwrap_or_default()
+    }
+
+    fn messages(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Lang::ZhCn => zh_cn::MESSAGES,
+            Lang::En => en::MESSAGES,
+        }
+    }
+
+    fn help(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Lang::ZhCn => zh_cn::HELP,
+            Lang::En => en::HELP,
+        }
+    }
+}
+
+fn env_lang(var: &str) -> Option<Lang> {
+    std::env::var(var)
+        .ok()
+        .and_then(|value| Lang::from_locale(&value))
+}
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+/// 设置当前进程的界面语言（仅首次调用生效）
+pub fn set_lang(lang: Lang) {
+    let _ = LANG.set(lang);
+}
+
+/// 当前界面语言，未设置时自动检测
+pub fn lang() -> Lang {
+    *LANG.get_or_init(|| Lang::detect(None))
+}
+
+/// 在解析命令行之前从原始参数中提取 `--lang`，以便帮助信息也能本地化
+///
+/// `--lang` 只能写在子命令之前，遇到第一个非选项参数（子命令）即停止。
+pub fn lang_from_args(args: &[OsString]) -> Option<Lang> {
+    let mut iter = args.iter().skip(1).filter_map(|arg| arg.to_str());
+    while let Some(arg) = iter.next() {
+        if !arg.starts_with('-') || arg == "--" {
+            break;
+        }
+        let value = match arg.strip_prefix("--lang") {
+            Some("") => iter.next(),
+            Some(rest) => rest.strip_prefix('='),
+            None => {
+                // 跳过其他带值的根选项的取值
+                if ROOT_VALUE_OPTIONS.contains(&arg) {
+                    iter.next();
+                }
+                continue;
+            }
+        };
+        return value.and_then(|v| Lang::from_str(v, true).ok());
+    }
+    None
+}
+
+/// 以单独参数接收取值的根选项
+const ROOT_VALUE_OPTIONS: &[&str] = &["--output", "--route-validation"];
+
+fn catalog(lang: Lang) -> &'static HashMap<&'static str, &'static str> {
+    static ZH_CN: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+    static EN: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+    let cell = match lang {
+        Lang::ZhCn => &ZH_CN,
+        Lang::En => &EN,
+    };
+    cell.get_or_init(|| lang.messages().iter().copied().collect())
+}
+
+/// 按当前语言查找并渲染消息，缺失时回退到中文目录，再回退到键名本身
+pub fn message(key: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    message_in(lang(), key, args)
+}
+
+/// 按指定语言查找并渲染消息
+pub fn message_in(lang: Lang, key: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let template = catalog(lang)
+        .get(key)
+        .or_else(|| catalog(Lang::ZhCn).get(key))
+        .copied()
+        .unwrap_or(key);
+    render(template, args)
+}
+
+/// 将模板中的 `{name}` 占位符替换为参数值，未知占位符原样保留
+fn render(template: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let value = after.find('}').and_then(|end| {
+            let name = &after[..end];
+            args.iter()
+                .find(|(arg, _)| *arg == name)
+                .map(|(_, value)| (end, value.to_string()))
+        });
+        match value {
+            Some((end, value)) => {
+                out.push_str(&value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// 指定语言是否提供了某个命令或参数的帮助文本
+#[cfg(test)]
+pub fn has_help(lang: Lang, key: &str) -> bool {
+    lang.help().iter().any(|(k, _)| *k == key)
+}
+
+/// 用当前语言的帮助文本替换命令行定义中的说明（中文帮助直接来自 main.rs 的文档注释）
+pub fn localize_command(cmd: Command) -> Command {
+    let help: HashMap<&str, &'static str> = lang().help().iter().copied().collect();
+    if help.is_empty() {
+        return cmd;
+    }
+    localize_with(cmd, "cli", &help)
+}
+
+fn localize_with(mut cmd: Command, path: &str, help: &HashMap<&str, &'static str>) -> Command {
+    if let Some(about) = help.get(path) {
+        cmd = cmd.about(*about).long_about(None);
+    }
+
+    let arg_ids: Vec<String> = cmd
+        .get_arguments()
+        .map(|arg| arg.get_id().to_string())
+        .collect();
+    for id in arg_ids {
+        if let Some(text) = help.get(format!("{path}.{id}").as_str()) {
+            // 可选值的说明来自文档注释，无法单独翻译，改由帮助文本列出可选值
+            cmd = cmd.mut_arg(&id, |arg| {
+                let has_value_help = arg
+                    .get_possible_values()
+                    .iter()
+                    .any(|value| value.get_help().is_some());
+                arg.help(*text)
+                    .long_help(None)
+                    .hide_possible_values(has_value_help)
+            });
+        }
+    }
+
+    let subcommands: Vec<String> = cmd
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    for name in subcommands {
+        let sub_path = format!("{path}.{name}");
+        cmd = cmd.mut_subcommand(&name, |sub| localize_with(sub, &sub_path, help));
+    }
+    cmd
+}
+
+/// 按键名查找当前语言的消息：`t!("key")` 或 `t!("key", name, other = expr)`
+#[macro_export]
+macro_rules! t {
+    ($key:literal $(,)?) => {
+        $crate::i18n::message($key, &[])
+    };
+    ($key:literal, $($name:ident $(= $value:expr)?),+ $(,)?) => {
+        $crate::i18n::message(
+            $key,
+            &[$((
+                stringify!($name),
+                &$crate::__t_arg!($name $(, $value)?) as &dyn ::std::fmt::Display,
+            )),+],
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __t_arg {
+    ($name:ident) => {
+        $name
+    };
+    ($name:ident, $value:expr) => {
+        $value
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// 提取模板中的占位符名
+    fn placeholders(template: &str) -> HashSet<&str> {
+        template
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .filter(|name| name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+            .collect()
+    }
+
+    #[test]
+    fn test_catalogs_consistent() {
+        let zh = catalog(Lang::ZhCn);
+        let en = catalog(Lang::En);
+        assert_eq!(zh.len(), zh_cn::MESSAGES.len(), "中文目录存在重复键");
+        assert_eq!(en.len(), en::MESSAGES.len(), "英文目录存在重复键");
+
+        for (key, template) in zh {
+            let translated = en.get(key).unwrap_or_else(|| panic!("英文目录缺少 {key}"));
+            assert_eq!(
+                placeholders(template),
+                placeholders(translated),
+                "{key} 的占位符不一致"
+            );
+        }
+        for key in en.keys() {
+            assert!(zh.contains_key(key), "中文目录缺少 {key}");
+        }
+    }
+
+    #[test]
+    fn test_render_message() {
+        assert_eq!(
+            message_in(Lang::En, "error.profile_not_found", &[("name", &"work")]),
+            "Profile 'work' not found, use 'ccode list' to see available profiles"
+        );
+        assert_eq!(
+            message_in(Lang::ZhCn, "error.profile_not_found", &[("name", &"work")]),
+            "配置 'work' 不存在，请使用 'ccode list' 查看可用配置"
+        );
+        // 未知占位符与键名回退
+        assert_eq!(render("{a} {b} {", &[("a", &1)]), "1 {b} {");
+        assert_eq!(message_in(Lang::En, "no.such.key", &[]), "no.such.key");
+    }
+
+    #[test]
+    fn test_lang_detection() {
+        assert_eq!(Lang::from_locale("en_US.UTF-8"), Some(Lang::En));
+        assert_eq!(Lang::from_locale("zh_CN.UTF-8"), Some(Lang::ZhCn));
+        assert_eq!(Lang::from_locale("zh-TW"), Some(Lang::ZhCn));
+        assert_eq!(Lang::from_locale("C"), None);
+        assert_eq!(Lang::detect(Some(Lang::En)), Lang::En);
+
+        let args = |list: &[&str]| list.iter().map(OsString::from).collect::<Vec<_>>();
+        assert_eq!(
+            lang_from_args(&args(&["ccode", "--lang", "en", "list"])),
+            Some(Lang::En)
+        );
+        assert_eq!(
+            lang_from_args(&args(&[
+                "ccode",
+                "--output",
+                "json",
+                "--lang=zh-CN",
+                "list"
+            ])),
+            Some(Lang::ZhCn)
+        );
+        assert_eq!(
+            lang_from_args(&args(&["ccode", "run", "x", "--lang", "en"])),
+            None
+        );
+        assert_eq!(
+            lang_from_args(&args(&["ccode", "run", "x", "--", "--lang", "en"])),
+            None
+        );
+    }
+}
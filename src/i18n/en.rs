@@ -0,0 +1,1667 @@
+//! English message catalog
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // error.rs
+    ("error.config", "Configuration error: {msg}"),
+    ("error.io", "File operation error: {err}"),
+    ("error.json", "Invalid JSON: {err}"),
+    (
+        "error.config_not_found",
+        "Configuration file not found, use 'ccode add <name>' to add a profile",
+    ),
+    (
+        "error.profile_not_found",
+        "Profile '{name}' not found, use 'ccode list' to see available profiles",
+    ),
+    ("error.invalid_config", "Invalid configuration: {msg}"),
+    ("error.command_execution", "Command failed: {msg}"),
+    ("error.secret", "Secret storage error: {msg}"),
+    (
+        "error.lock_timeout",
+        "Configuration file '{path}' is being modified by another ccode process and the lock wait timed out, please try again later",
+    ),
+    ("error.child_exit", "Child program exited with code {code}"),
+    ("error.prefix", "❌ Error: {err}"),
+    // fs_utils.rs
+    ("fs.invalid_path", "Invalid file path: {path}"),
+    // backup.rs
+    ("backup.unknown_time", "unknown"),
+    (
+        "backup.invalid_size",
+        "Invalid size '{size}', e.g. 512KB, 10MB",
+    ),
+    ("backup.invalid_file", "Invalid backup file: {path}"),
+    (
+        "backup.name_exhausted",
+        "Could not find a free backup file name for {timestamp} in {dir}",
+    ),
+    ("backup.none", "No backups yet"),
+    (
+        "backup.not_found",
+        "Backup '{id}' not found, use 'ccode backup list' to see available backups",
+    ),
+    (
+        "backup.prune_failed",
+        "⚠️  Failed to prune expired backups: {e}",
+    ),
+    ("backup.invalid", "Backup '{id}' is invalid: {e}"),
+    // secrets.rs
+    ("secrets.backend.plaintext", "plaintext"),
+    ("secrets.backend.vault", "encrypted vault (vault)"),
+    ("secrets.backend.keyring", "system keyring (keyring)"),
+    (
+        "secrets.unknown_backend",
+        "Unknown secret backend '{other}', expected one of: plaintext, vault, keyring",
+    ),
+    (
+        "secrets.ref.missing_key",
+        "Secret reference is missing a key name, format: secret://ccode/<name>",
+    ),
+    (
+        "secrets.ref.invalid_env",
+        "Invalid environment variable reference 'env:{var}': names may only contain letters, digits and underscores and must not start with a digit",
+    ),
+    (
+        "secrets.ref.missing_path",
+        "File reference is missing a path, format: file:<path>",
+    ),
+    (
+        "secrets.ref.missing_command",
+        "Command reference is missing a command, format: cmd:<command>",
+    ),
+    (
+        "secrets.env_not_set",
+        "Environment variable '{var}' is not set",
+    ),
+    (
+        "secrets.file_read_failed",
+        "Failed to read secret file '{path}': {e}",
+    ),
+    ("secrets.file_empty", "Secret file '{path}' is empty"),
+    (
+        "secrets.cmd_spawn_failed",
+        "Failed to run secret command '{command}': {e}",
+    ),
+    (
+        "secrets.cmd_failed",
+        "Secret command '{command}' failed with exit code: {code}",
+    ),
+    (
+        "secrets.cmd_no_output",
+        "Secret command '{command}' produced no output",
+    ),
+    (
+        "secrets.plaintext_cannot_resolve",
+        "Cannot resolve secret reference '{reference}' with plaintext storage, use 'ccode secrets migrate --to <backend>' to switch backends",
+    ),
+    ("secrets.vault.prompt", "🔐 Vault passphrase: "),
+    (
+        "secrets.vault.empty_passphrase",
+        "Vault passphrase must not be empty",
+    ),
+    ("secrets.vault.confirm", "🔐 Confirm passphrase: "),
+    ("secrets.vault.mismatch", "Passphrases do not match"),
+    (
+        "secrets.vault.decrypt_failed",
+        "Unable to decrypt the vault (wrong passphrase?): {e}",
+    ),
+    (
+        "secrets.vault.corrupted",
+        "Vault contents are corrupted: {e}",
+    ),
+    (
+        "secrets.vault.encrypt_failed",
+        "Failed to encrypt the vault: {e}",
+    ),
+    (
+        "secrets.vault.not_found",
+        "Secret '{key}' not found in the vault",
+    ),
+    (
+        "secrets.keyring.not_installed",
+        "'secret-tool' not found, please install libsecret-tools (only the Linux Secret Service is supported)",
+    ),
+    (
+        "secrets.keyring.spawn_failed",
+        "Failed to run secret-tool: {e}",
+    ),
+    ("secrets.keyring.failed", "secret-tool failed: {stderr}"),
+    (
+        "secrets.keyring.not_found",
+        "Secret '{key}' not found in the system keyring",
+    ),
+    // ccr_config.rs
+    ("ccr.no_home_dir", "Unable to determine the home directory"),
+    (
+        "ccr.parse_failed",
+        "Failed to parse the CCR configuration file: {e}",
+    ),
+    ("ccr.saved", "✅ CCR configuration saved: {path}"),
+    (
+        "ccr.backup_missing_config",
+        "The CCR configuration file does not exist, cannot create a backup",
+    ),
+    ("ccr.backup_created", "📦 Backup created: {path}"),
+    ("ccr.backup_pruned", "🧹 Pruned {count} expired backup(s)"),
+    ("ccr.profile_applied", "✅ Applied Router Profile '{name}'"),
+    (
+        "ccr.route_missing_provider",
+        "Route '{route_name}' references unknown provider '{provider_name}'",
+    ),
+    (
+        "ccr.route_missing_provider_warning",
+        "⚠️  Warning: route '{route_name}' references unknown provider '{provider_name}'",
+    ),
+    (
+        "ccr.generated_description",
+        "Generated from the claude-code-router configuration",
+    ),
+    (
+        "ccr.no_providers",
+        "No providers configured, use 'ccode provider add <name>' to add one first",
+    ),
+    (
+        "ccr.sync_providers",
+        "🔄 Syncing providers: found {count} provider(s)",
+    ),
+    ("ccr.router_updated", "✅ CCR Router configuration updated"),
+    (
+        "ccr.provider_updated",
+        "✅ CCR Provider configuration updated",
+    ),
+    (
+        "ccr.providers_updated",
+        "✅ CCR Providers configuration updated",
+    ),
+    ("ccr.label", "CCR configuration"),
+    ("ccr.invalid_content", "Not a valid CCR configuration: {e}"),
+    ("stats.provider_count", "🔗 Providers: {count}"),
+    ("stats.default_route", "🎯 Default route: {route}"),
+    ("stats.background_route", "🔄 Background route: ✅"),
+    ("stats.think_route", "💭 Think route: ✅"),
+    ("stats.long_context_route", "📜 Long context route: ✅"),
+    ("stats.web_search_route", "🔍 Web search route: ✅"),
+    ("stats.api_timeout", "⏱️  API timeout: {timeout}ms"),
+    ("stats.log_enabled", "📝 Logging: enabled"),
+    ("stats.log_disabled", "📝 Logging: disabled"),
+    // config.rs
+    (
+        "config.no_config_dir",
+        "Unable to determine the configuration directory",
+    ),
+    ("config.label", "ccode configuration"),
+    (
+        "config.invalid_content",
+        "Not a valid ccode configuration: {e}",
+    ),
+    ("profile.model", "🤖 Model: {model}"),
+    ("profile.fast_model", "⚡ Fast model: {model}"),
+    ("profile.env", "🌱 Environment: {names}"),
+    ("profile.description", "📝 Description: {desc}"),
+    ("profile.created", "📅 Created: {created}"),
+    ("profile.already_exists", "Profile '{name}' already exists"),
+    ("profile.no_default", "No default Direct profile is set"),
+    ("profile.empty_token", "Auth token must not be empty"),
+    ("profile.empty_url", "Base URL must not be empty"),
+    (
+        "profile.invalid_url",
+        "Invalid base URL, it must start with 'http://' or 'https://'",
+    ),
+    ("profile.invalid", "Profile '{name}' is invalid: {e}"),
+    (
+        "router_profile.empty_name",
+        "Router Profile name must not be empty",
+    ),
+    (
+        "router_profile.already_exists",
+        "Router Profile '{name}' already exists",
+    ),
+    (
+        "router_profile.no_default",
+        "No default Router Profile is set",
+    ),
+    (
+        "router_profile.invalid",
+        "Router Profile '{name}' is invalid: {e}",
+    ),
+    (
+        "router.empty_default",
+        "The default route must not be empty",
+    ),
+    (
+        "router.invalid_default",
+        "Invalid default route, expected the 'provider,model' format",
+    ),
+    (
+        "router.invalid_route",
+        "Invalid {name} route, expected the 'provider,model' format",
+    ),
+    (
+        "ccr.empty_providers",
+        "The Providers list must not be empty",
+    ),
+    ("provider_type.openai", "OpenAI compatible"),
+    ("provider_type.custom", "Custom"),
+    (
+        "provider_type.unknown",
+        "Unknown provider type '{other}', expected one of: openai, openrouter, deepseek, gemini, qwen, custom",
+    ),
+    (
+        "provider.gemini_url_path",
+        "The Gemini API URL should contain the '/v1beta/models/' path",
+    ),
+    (
+        "provider.chat_url_path",
+        "The API URL should contain the '/chat/completions' path",
+    ),
+    ("provider.empty_name", "Provider name must not be empty"),
+    ("provider.empty_url", "API URL must not be empty"),
+    (
+        "provider.invalid_url",
+        "Invalid API URL, it must start with 'http://' or 'https://'",
+    ),
+    ("provider.empty_models", "The model list must not be empty"),
+    (
+        "provider.unconverted_key_ref",
+        "The API key reference '{reference}' of provider '{name}' was not converted, the CCR configuration only supports $VAR environment variable references",
+    ),
+    (
+        "provider.invalid_key_env_ref",
+        "Invalid API key environment variable reference '{reference}' for provider '{name}'",
+    ),
+    (
+        "provider.already_exists",
+        "Provider '{name}' already exists",
+    ),
+    ("provider.not_found", "Provider '{name}' not found"),
+    ("hint.openai.format", "• Standard OpenAI API format"),
+    (
+        "hint.openai.no_transformer",
+        "• No special transformer required",
+    ),
+    (
+        "hint.openai.compatible",
+        "• Works with most third-party compatible APIs",
+    ),
+    ("hint.openrouter.routing", "• Routes to many AI models"),
+    (
+        "hint.openrouter.transformer",
+        "• Adds the OpenRouter transformer automatically",
+    ),
+    (
+        "hint.openrouter.online",
+        "• Web search requires the ':online' model suffix",
+    ),
+    ("hint.deepseek.api", "• Dedicated DeepSeek API"),
+    (
+        "hint.deepseek.transformer",
+        "• Configures the DeepSeek transformer automatically",
+    ),
+    (
+        "hint.deepseek.tooluse",
+        "• Enables tooluse for deepseek-chat automatically",
+    ),
+    ("hint.gemini.api", "• Google Gemini API"),
+    ("hint.gemini.path", "• API path format: /v1beta/models/"),
+    (
+        "hint.gemini.transformer",
+        "• Configures the Gemini transformer automatically",
+    ),
+    ("hint.qwen.models", "• Qwen model family"),
+    (
+        "hint.qwen.max_tokens",
+        "• Sets the max token limit (65536) automatically",
+    ),
+    (
+        "hint.qwen.reasoning",
+        "• Enables reasoning mode for Thinking models automatically",
+    ),
+    ("hint.qwen.tooluse", "• Enhanced tool calling support"),
+    ("hint.custom.api", "• Custom API configuration"),
+    (
+        "hint.custom.transformer",
+        "• Configure a transformer manually (if needed)",
+    ),
+    ("hint.custom.other", "• For other AI service providers"),
+    // commands.rs: 路由推荐与 Direct 配置
+    ("recommend.fast", "🚀 Fast responses"),
+    ("recommend.cost_effective", "💰 Cost effective"),
+    ("recommend.reasoning", "🧠 Strong reasoning"),
+    (
+        "recommend.chain_of_thought",
+        "🤔 Chain-of-thought reasoning",
+    ),
+    ("recommend.logic", "🔬 Logical analysis"),
+    ("recommend.long_context", "📜 Very long context"),
+    ("recommend.massive_input", "🌐 Handles massive inputs"),
+    ("recommend.documents", "📖 Document analysis"),
+    ("recommend.live_search", "🔍 Live search"),
+    ("recommend.basic_search", "🌐 Basic web lookups"),
+    (
+        "input.env_not_set",
+        "Environment variable '{var}' is not set or is not valid UTF-8",
+    ),
+    (
+        "input.missing_flag",
+        "Missing required argument {flag} in non-interactive mode",
+    ),
+    (
+        "add.token_file_failed",
+        "Failed to read token file '{path}': {e}",
+    ),
+    ("add.title", "🔧 Adding profile: {name}"),
+    (
+        "add.prompt_token",
+        "🔑 Enter ANTHROPIC_AUTH_TOKEN (any third-party API format, or an env:VAR / file:PATH / cmd:COMMAND reference): ",
+    ),
+    (
+        "add.prompt_base_url",
+        "📍 Enter ANTHROPIC_BASE_URL (e.g. https://api.anthropic.com): ",
+    ),
+    (
+        "add.prompt_model",
+        "🤖 Enter ANTHROPIC_MODEL (optional, press Enter to skip): ",
+    ),
+    (
+        "add.prompt_fast_model",
+        "⚡ Enter ANTHROPIC_SMALL_FAST_MODEL (optional, press Enter to skip): ",
+    ),
+    (
+        "add.prompt_description",
+        "📝 Enter a description (optional, press Enter to skip): ",
+    ),
+    ("add.success", "✅ Profile '{name}' added!"),
+    (
+        "add.auto_default",
+        "🎯 Set as the default profile automatically",
+    ),
+    ("use.success", "✅ '{name}' is now the default profile"),
+    (
+        "run.starting",
+        "🚀 Starting claude with profile '{name}'...",
+    ),
+    ("run.passthrough", "📄 Passing arguments: {args}"),
+    (
+        "run.passthrough_hint",
+        "💡 Tip: arguments after the profile name are passed to claude (e.g. ccode run myprofile --version or ccode run myprofile -- --help)",
+    ),
+    ("run.exited", "✅ {program} exited normally"),
+    (
+        "run.exited_abnormally",
+        "⚠️  {program} exited abnormally, exit code: {code}",
+    ),
+    (
+        "run.killed_by_signal",
+        "⚠️  {program} was terminated by signal {signal}, exit code: {code}",
+    ),
+    (
+        "run.claude_not_found",
+        "Cannot find the 'claude' program, make sure claude is installed and on your PATH",
+    ),
+    ("run.failed", "Failed to run {program}: {e}"),
+    ("remove.confirm", "⚠️  Delete profile '{name}'? (y/N): "),
+    ("remove.cancelled", "❌ Deletion cancelled"),
+    ("remove.success", "✅ Profile '{name}' deleted"),
+    (
+        "remove.current_default_direct",
+        "🎯 Current default Direct profile: {name}",
+    ),
+    (
+        "remove.current_default_router",
+        "🎯 Current default Router profile: {name}",
+    ),
+    (
+        "list.empty",
+        "📋 No profiles yet, use 'ccode add <name>' to add one",
+    ),
+    ("group.unknown", "Unknown profile group: {group}"),
+    (
+        "launch.env_title",
+        "🌱 Environment for {program} (policy: {policy}):",
+    ),
+    ("launch.env_set", "Set by ccode:"),
+    ("launch.env_none", "(none)"),
+    (
+        "launch.env_removed",
+        "Removed from the inherited environment:",
+    ),
+    (
+        "launch.env_inherited_rest",
+        "All other variables are inherited from the current environment",
+    ),
+    (
+        "exec.no_command",
+        "Missing the program to run; usage: ccode exec <name> -- <command> [args...]",
+    ),
+    (
+        "exec.starting",
+        "🚀 Running {program} with profile '{name}'...",
+    ),
+    (
+        "exec.not_found",
+        "Cannot find the program '{program}', check the name or your PATH",
+    ),
+    (
+        "exec.no_ccr_config",
+        "claude-code-router configuration file not found; use 'ccode provider add <name>' to add a provider first",
+    ),
+    (
+        "exec.restarting_ccr",
+        "🔄 Restarting the claude-code-router service...",
+    ),
+    (
+        "exec.ccr_unchanged",
+        "ℹ️  ccr routes unchanged and the service is running, not restarting (use --restart to reload edited providers)",
+    ),
+    (
+        "exec.ccr_restart_failed",
+        "Failed to restart the claude-code-router service ({status})",
+    ),
+    (
+        "exec.ccr_unreachable",
+        "The claude-code-router service at {endpoint} did not accept connections after starting; run 'ccr status' to check",
+    ),
+    // commands.rs: 列表与 CCR 配置
+    (
+        "add.ccr_ignores_flags",
+        "⚠️  Note: --token and similar flags only apply to Direct profiles and will be ignored",
+    ),
+    ("list.all_title", "📋 All profiles:"),
+    ("list.direct_section", "🔗 Direct profiles:"),
+    ("list.router_section", "🎯 Router profiles:"),
+    (
+        "list.direct_empty",
+        "📋 No Direct profiles yet, use 'ccode add --group direct <name>' to add one",
+    ),
+    ("list.direct_title", "📋 Direct profiles:"),
+    (
+        "use.direct_success",
+        "✅ '{name}' is now the default Direct profile",
+    ),
+    (
+        "remove.direct_confirm",
+        "⚠️  Delete Direct profile '{name}'? (y/N): ",
+    ),
+    (
+        "remove.direct_success",
+        "✅ Direct profile '{name}' deleted",
+    ),
+    ("list_ccr.title", "📋 CCR profiles (Router Profiles):"),
+    ("list_ccr.empty", "❌ No CCR profiles yet"),
+    ("list_ccr.getting_started", "💡 To get started with CCR:"),
+    (
+        "list_ccr.step_add_provider",
+        "   1. ccode provider add <name>     # add a provider",
+    ),
+    (
+        "list_ccr.step_add_ccr",
+        "   2. ccode add-ccr <name>          # add a CCR profile",
+    ),
+    (
+        "list_ccr.hint_add",
+        "💡 Use 'ccode add-ccr <name>' to add a CCR profile",
+    ),
+    ("list_ccr.active_title", "📊 Currently applied routes:"),
+    (
+        "list_ccr.no_ccr_config",
+        "⚠️  The claude-code-router configuration file does not exist",
+    ),
+    (
+        "hint.add_provider_first",
+        "💡 Use 'ccode provider add <name>' to add a provider first",
+    ),
+    (
+        "add_ccr.title",
+        "🎯 Adding CCR profile (Router Profile): {name}",
+    ),
+    (
+        "add_ccr.success",
+        "✅ CCR profile (Router Profile) '{name}' added!",
+    ),
+    (
+        "add_ccr.auto_default",
+        "🎯 Set as the default CCR profile automatically",
+    ),
+    ("providers.available", "📋 Available providers:"),
+    ("providers.features", "     💡 Highlights:"),
+    (
+        "add_ccr.default_route_title",
+        "🎯 Configure the default route (format: provider,model):",
+    ),
+    ("add_ccr.recommended", "💡 Recommended routes:"),
+    ("add_ccr.recommended_options", "💡 Suggestions:"),
+    (
+        "add_ccr.invalid_route_skip",
+        "⚠️  Routes must use the 'provider,model' format, skipping",
+    ),
+    (
+        "add_ccr.prompt_threshold",
+        "⚖️  Long context threshold (default: 60000): ",
+    ),
+    (
+        "add_ccr.invalid_threshold",
+        "⚠️  Invalid threshold, using the default of 60000",
+    ),
+    ("add_ccr.prompt_description", "📝 Description (optional): "),
+    ("use_ccr.title", "🎯 Activating CCR profile: {name}"),
+    ("use_ccr.details", "📋 Profile details:"),
+    (
+        "use_ccr.success",
+        "✅ Activated CCR profile '{name}' and synced it to claude-code-router",
+    ),
+    ("list.default_marker", " (default)"),
+    (
+        "run.ccr_ignores_args",
+        "⚠️  Note: CCR mode does not support passing arguments, ignoring: {args}",
+    ),
+    ("route.default", "🚀 Default route: {route}"),
+    ("route.background", "🔄 Background route: {route}"),
+    ("route.think", "💭 Think route: {route}"),
+    ("route.long_context", "📜 Long context route: {route}"),
+    ("route.web_search", "🔍 Web search route: {route}"),
+    ("active_route.default", "🎯 Default: {route}"),
+    ("active_route.background", "🔄 Background: {route}"),
+    ("active_route.think", "💭 Think: {route}"),
+    ("active_route.long_context", "📜 Long context: {route}"),
+    ("active_route.web_search", "🔍 Web search: {route}"),
+    (
+        "list_ccr.available_providers",
+        "🔗 Available providers: {count}",
+    ),
+    (
+        "route.invalid_format",
+        "{route_desc} has an invalid format, expected 'provider,model'",
+    ),
+    ("route_desc.default", "Default route"),
+    ("route_desc.background", "🔄 Background route"),
+    ("route_desc.think", "💭 Think route"),
+    ("route_desc.long_context", "📜 Long context route"),
+    ("route_desc.web_search", "🔍 Web search route"),
+    (
+        "add_ccr.router_file_failed",
+        "Failed to read the Router configuration file '{path}': {e}",
+    ),
+    (
+        "add_ccr.missing_default",
+        "Missing default route, specify it with --default or --from-file",
+    ),
+    (
+        "add_ccr.no_ccr_config",
+        "claude-code-router configuration file not found, use 'ccode provider add <name>' to add a provider first",
+    ),
+    (
+        "add_ccr.no_providers",
+        "No providers available, use 'ccode provider add <name>' to add one first",
+    ),
+    (
+        "add_ccr.prompt_route",
+        "Configure {route_desc} (press Enter to skip): ",
+    ),
+    ("provider_type.unknown_type", "unknown type"),
+    ("providers.model_list", "🤖 Models ({count}):"),
+    ("providers.more_models", "... ({count} more models)"),
+    ("recommend.openai", "🔑 Most stable and compatible"),
+    ("recommend.openrouter", "🌐 Many models"),
+    ("recommend.deepseek", "🧠 Strong reasoning"),
+    ("recommend.gemini", "🚀 Latest from Google"),
+    ("recommend.qwen", "🎨 Optimized for Chinese"),
+    ("recommend.custom", "⚙️ Custom configuration"),
+    ("recommend.generic", "💻 General purpose"),
+    (
+        "route.unknown_model",
+        "Model '{model}' of route '{route}' is not in the model list of provider '{provider}'",
+    ),
+    ("route.issue_warning", "⚠️  Warning: {issue}"),
+    (
+        "route.strict_rejected",
+        "{issue} (strict route validation; use --route-validation warn to relax)",
+    ),
+    ("add_ccr.route_skipped", "⚠️  {issue}; route skipped"),
+    // commands.rs: CCR 运行与 Provider 管理
+    ("run_ccr.starting", "🚀 Starting CCR profile..."),
+    ("run_ccr.native", "💡 Managed by the native ccr command"),
+    ("run_ccr.no_profiles", "❌ No Router Profiles configured"),
+    ("run_ccr.setup_steps", "💡 Set one up first:"),
+    (
+        "run_ccr.step_provider",
+        "   1. ccode provider add <name>  # add a provider",
+    ),
+    (
+        "run_ccr.step_profile",
+        "   2. ccode add-ccr <name>       # add a Router Profile",
+    ),
+    (
+        "run_ccr.hint_add",
+        "💡 Use 'ccode add-ccr <name>' to add a Router Profile",
+    ),
+    ("run_ccr.no_default", "❌ No default Router Profile set"),
+    ("run_ccr.available", "💡 Available Router Profiles:"),
+    ("run_ccr.usage", "Usage: ccode run-ccr <profile-name>"),
+    (
+        "run_ccr.usage_default",
+        "Or set a default: ccode use-ccr <profile-name>",
+    ),
+    ("run_ccr.using", "🎯 Using Router Profile '{profile_name}'"),
+    (
+        "run_ccr.no_ccr_config",
+        "❌ claude-code-router configuration file not found",
+    ),
+    (
+        "run_ccr.applying",
+        "📄 Applying the Router Profile to the configuration file...",
+    ),
+    ("run_ccr.launching", "🎯 Launching ccr code..."),
+    ("remove_ccr.title", "🗑️  Removing CCR profile: {name}"),
+    ("remove_ccr.details", "📋 Profile to be removed:"),
+    (
+        "remove_ccr.is_default",
+        "⚠️  '{name}' is the current default CCR profile",
+    ),
+    (
+        "remove_ccr.reset_default",
+        "You will need to set a new default after removing it",
+    ),
+    ("remove_ccr.confirm", "Delete CCR profile '{name}'? (y/N): "),
+    ("remove_ccr.success", "✅ CCR profile '{name}' deleted"),
+    (
+        "remove_ccr.current_default",
+        "🎯 Current default CCR profile: {router}",
+    ),
+    (
+        "remove_ccr.no_default",
+        "⚠️  No default CCR profile, use 'ccode use-ccr <name>' to set one",
+    ),
+    (
+        "remove_ccr.empty",
+        "📋 No CCR profiles yet, use 'ccode add-ccr <name>' to add one",
+    ),
+    (
+        "provider_list.no_config",
+        "📋 No claude-code-router configuration file yet",
+    ),
+    (
+        "provider_list.hint_first",
+        "💡 Use 'ccode provider add <name>' to add your first provider",
+    ),
+    ("provider_list.empty", "📋 No providers configured"),
+    (
+        "provider_list.hint_add",
+        "💡 Use 'ccode provider add <name>' to add a provider",
+    ),
+    ("provider_list.title", "📋 Providers:"),
+    ("provider_list.stats", "📊 Statistics:"),
+    ("provider_add.select_type", "📋 Select a provider type:"),
+    ("provider_add.prompt_type", "Choose [1-6]: "),
+    (
+        "provider_add.invalid_type",
+        "❌ Invalid choice, using the OpenAI-compatible type",
+    ),
+    ("provider_add.title", "🔗 Adding provider: {name}"),
+    ("provider_add.prompt_key", "🔑 API key: "),
+    ("provider_add.url_title", "📍 API URL:"),
+    (
+        "provider_add.prompt_url",
+        "  Custom URL (press Enter for the default): ",
+    ),
+    ("provider_add.models_title", "🤖 Models:"),
+    (
+        "provider_add.prompt_models",
+        "  Custom model list (comma separated, press Enter for the default): ",
+    ),
+    ("provider_add.creating", "🔧 Creating provider..."),
+    ("provider_add.dry_run", "🔍 Dry run, nothing was written:"),
+    ("provider_add.success", "✅ Provider '{name}' added!"),
+    (
+        "provider_remove.referenced",
+        "⚠️  Provider '{name}' is still referenced by {count} route(s):",
+    ),
+    (
+        "provider_remove.confirm",
+        "⚠️  Delete provider '{name}'? (y/N): ",
+    ),
+    ("provider_remove.success", "✅ Provider '{name}' deleted"),
+    ("provider_show.models", "🤖 Models:"),
+    ("provider_show.transformer", "🔄 Transformer:"),
+    (
+        "provider_edit.prompt_key",
+        "New API key (press Enter to keep it): ",
+    ),
+    (
+        "provider_edit.prompt_url",
+        "New API URL (press Enter to keep it): ",
+    ),
+    (
+        "provider_edit.prompt_models",
+        "New model list (comma separated, press Enter to keep it): ",
+    ),
+    ("provider_edit.success", "✅ Provider '{name}' updated!"),
+    (
+        "run_ccr.ccr_not_found",
+        "Cannot find the 'ccr' program, make sure claude-code-router is installed and on your PATH",
+    ),
+    ("provider.model_count", "📊 Models: {count}"),
+    ("provider.type", "🏷️  Type: {kind}"),
+    ("provider.models", "🤖 Models: {models}"),
+    (
+        "provider.models_truncated",
+        "🤖 Models: {models} and more ({count} total)",
+    ),
+    (
+        "provider.transformer_file_failed",
+        "Failed to read transformer file '{path}': {e}",
+    ),
+    (
+        "provider_add.configure",
+        "🔧 Configuring a {kind} provider:",
+    ),
+    ("provider_add.default_url", "Default: {url}"),
+    ("provider_add.default_models", "Default models: {models}"),
+    ("provider_add.kind", "🔗 Type: {kind}"),
+    ("provider_show.title", "🔗 Provider: {name}"),
+    ("provider_show.type", "🏷️  Type: {kind}"),
+    ("provider_show.model_count", "📊 Models: {count}"),
+    ("provider_edit.title", "✏️  Editing provider: {name}"),
+    ("provider_edit.current_key", "🔑 Current API key: {key}"),
+    ("provider_edit.current_url", "📍 Current API URL: {url}"),
+    (
+        "provider_edit.current_models",
+        "🤖 Current models: {models}",
+    ),
+    // commands.rs: 备份与密钥
+    ("backup_cmd.diff_title", "📊 Comparing {old_id} → {new_id}"),
+    (
+        "backup_cmd.identical",
+        "✅ Both configurations are identical",
+    ),
+    (
+        "backup_cmd.restore_identical",
+        "✅ The current configuration matches this backup, nothing to restore",
+    ),
+    (
+        "backup_cmd.restore_confirm",
+        "Restore this backup? The current configuration will be backed up first (y/N): ",
+    ),
+    ("backup_cmd.restore_cancelled", "❌ Restore cancelled"),
+    ("backup_cmd.nothing_to_prune", "✅ No backups to prune"),
+    (
+        "backup_cmd.prune_hint",
+        "💡 Run again without --dry-run to prune",
+    ),
+    (
+        "backup_cmd.policy_updated",
+        "✅ Backup retention policy updated",
+    ),
+    ("backup_cmd.policy_title", "📋 Backup retention policy:"),
+    (
+        "backup_cmd.policy_hint",
+        "💡 Change it with 'ccode backup policy --keep-last <N> --keep-daily-days <M> --max-total-size <SIZE>'",
+    ),
+    ("undo.confirm", "Undo this change? (y/N): "),
+    ("undo.cancelled", "❌ Undo cancelled"),
+    (
+        "secrets_cmd.provider_backend_ref",
+        "Provider API keys do not support secret:// references directly",
+    ),
+    ("secrets_cmd.direct_title", "🔗 Direct profiles:"),
+    (
+        "secrets_cmd.migrate_hint",
+        "💡 Use 'ccode secrets migrate --to vault|keyring' to move plaintext secrets into secure storage",
+    ),
+    (
+        "secrets_cmd.backups_plaintext",
+        "⚠️  {count} configuration backup(s) still contain plaintext secrets (kept by --keep-backups), clean them up manually once the migration is verified:",
+    ),
+    (
+        "secrets_cmd.backups_purged",
+        "🧹 Removed {count} configuration backup(s) containing plaintext secrets",
+    ),
+    ("backup_cmd.empty", "📋 No {label} backups yet"),
+    ("backup_cmd.list_title", "📦 {label} backups ({count}):"),
+    ("backup_cmd.dir", "📁 Directory: {dir}"),
+    ("backup_cmd.latest_marker", " (latest)"),
+    ("backup_cmd.time", "📅 Time: {time}"),
+    ("backup_cmd.size", "📊 Size: {size}"),
+    (
+        "backup_cmd.list_hint",
+        "💡 Use 'ccode backup show <id>{flag}' to view a backup and 'ccode backup restore <id>{flag}' to restore it",
+    ),
+    ("backup_cmd.show_title", "🗂️  Backup: {id}"),
+    ("backup_cmd.file", "📁 File: {path}"),
+    ("backup_cmd.diff_count", "{count} difference(s):"),
+    (
+        "backup_cmd.restore_title",
+        "♻️  Restoring {label} backup: {id}",
+    ),
+    ("backup_cmd.restore_time", "📅 Backup time: {time}"),
+    (
+        "backup_cmd.restore_changes",
+        "📋 Changes after restoring ({count}):",
+    ),
+    (
+        "backup_cmd.restored",
+        "✅ Restored {label} from backup '{id}'",
+    ),
+    (
+        "backup_cmd.prune_preview",
+        "🧹 Backups to be pruned ({count}):",
+    ),
+    ("backup_cmd.pruned", "🧹 Pruned backups ({count}):"),
+    ("backup_cmd.policy_keep_last", "🔢 Keep last: {count}"),
+    (
+        "backup_cmd.policy_keep_daily",
+        "📅 Keep daily: last {days} day(s)",
+    ),
+    ("backup_cmd.policy_max_size", "📊 Total size limit: {size}"),
+    (
+        "backup_cmd.policy_unlimited",
+        "📊 Total size limit: unlimited",
+    ),
+    (
+        "undo.nothing",
+        "Nothing to undo: there are no backups of the ccode configuration",
+    ),
+    (
+        "undo.title",
+        "↩️  Undoing the last change, restoring the configuration from {time}",
+    ),
+    ("undo.changes", "📋 Changes after undoing ({count}):"),
+    (
+        "undo.success",
+        "✅ Undone, backup '{id}' was restored and removed from the backup list",
+    ),
+    ("secrets_cmd.backend", "🔐 Secret backend: {backend}"),
+    ("secrets_cmd.storage_env", "🌐 environment variable {value}"),
+    (
+        "secrets_cmd.storage_external",
+        "🔗 external reference {value}",
+    ),
+    ("secrets_cmd.storage_plaintext", "⚠️  plaintext"),
+    (
+        "secrets_cmd.migrate_title",
+        "🔐 Migrating secrets: {from} → {to}",
+    ),
+    (
+        "secrets_cmd.migrated",
+        "✅ Migrated {migrated} secret(s), current backend: {backend}",
+    ),
+    (
+        "secrets_cmd.ccr_stop_hint",
+        "💡 Provider keys are passed to claude-code-router as environment variables, run 'ccr stop' first if the ccr service is already running",
+    ),
+    (
+        "secrets_cmd.discard_failed",
+        "⚠️  Failed to remove secret '{key}': {e}",
+    ),
+    (
+        "secrets_cmd.retire_failed",
+        "⚠️  Failed to record retired secrets: {e}",
+    ),
+    (
+        "secrets_cmd.sweep_failed",
+        "⚠️  Failed to clean up retired secrets: {e}",
+    ),
+    // commands.rs: 编辑配置
+    ("profile.updated", "🕒 Updated: {updated}"),
+    ("edit.unset", "(not set)"),
+    ("edit.current_value", "{label}: {value}"),
+    (
+        "edit.prompt_optional",
+        "  New value (press Enter to keep it, - to clear): ",
+    ),
+    ("edit.prompt_keep", "  New value (press Enter to keep it): "),
+    (
+        "group.ambiguous",
+        "Profile '{name}' exists in both the Direct and Router groups, specify one with --group",
+    ),
+    (
+        "edit.route_flags_for_direct",
+        "Direct profiles do not accept route arguments (--default, --think, ...)",
+    ),
+    (
+        "edit.direct_flags_for_router",
+        "Router profiles do not accept Direct arguments such as --token, --base-url or --model",
+    ),
+    ("edit.title", "✏️  Editing profile: {name}"),
+    ("edit.current_token", "🔑 Current token: {token}"),
+    ("edit.current_url", "📍 Current URL: {url}"),
+    ("edit.field_description", "📝 Description"),
+    ("edit.field_threshold", "⚖️  Long context threshold"),
+    (
+        "edit.invalid_threshold",
+        "⚠️  Invalid threshold, keeping the current value",
+    ),
+    ("edit.success", "✅ Profile '{name}' updated!"),
+    (
+        "edit_ccr.title",
+        "✏️  Editing CCR profile (Router Profile): {name}",
+    ),
+    (
+        "edit_ccr.success",
+        "✅ CCR profile (Router Profile) '{name}' updated!",
+    ),
+    (
+        "edit_ccr.applied",
+        "🔄 Synced to claude-code-router (current default CCR profile)",
+    ),
+    // editor.rs: 外部编辑器
+    (
+        "editor.header",
+        "Editing {label}; changes take effect after you save and quit the editor\nLines starting with // are ignored; empty the file to abort",
+    ),
+    (
+        "editor.error_header",
+        "The last saved content failed validation:\n{err}\nPlease fix it and save again",
+    ),
+    (
+        "editor.invalid",
+        "⚠️  Validation failed, reopening the editor: {err}",
+    ),
+    ("editor.cancelled", "❌ File is empty, edit aborted"),
+    ("editor.unchanged", "ℹ️  No changes made, edit aborted"),
+    (
+        "editor.launch_failed",
+        "Failed to launch editor '{editor}': {err}",
+    ),
+    (
+        "editor.exit_failed",
+        "Editor '{editor}' exited abnormally ({status}), edit aborted",
+    ),
+    (
+        "editor.name_changed",
+        "The name cannot be changed here; it must stay '{name}'",
+    ),
+    // commands.rs: 重命名与复制
+    ("rename.invalid_name", "Invalid name '{name}'"),
+    ("rename.success", "✅ Profile '{old}' renamed to '{new}'"),
+    ("copy.success", "✅ Profile '{source}' copied to '{target}'"),
+    (
+        "provider_rename.title",
+        "📝 Renaming provider '{old}' to '{new}'",
+    ),
+    (
+        "provider_rename.no_references",
+        "   No routes reference this provider",
+    ),
+    (
+        "provider_rename.references",
+        "🔗 The following {count} route reference(s) will be updated:",
+    ),
+    ("route_ref.profile_label", "RouterProfile '{name}'"),
+    ("route_ref.ccr_label", "claude-code-router Router"),
+    ("provider_rename.confirm", "❓ Confirm rename? (y/N): "),
+    ("provider_rename.cancelled", "❌ Rename cancelled"),
+    (
+        "provider_rename.success",
+        "✅ Provider '{old}' renamed to '{new}'",
+    ),
+    // commands.rs: 删除 Provider 时的引用检查
+    (
+        "provider_remove.blocked",
+        "Provider '{name}' is still referenced by routes, removal blocked; use --cascade to clear the optional routes that use it, or --reassign <provider[,model]> to switch them to another provider",
+    ),
+    (
+        "provider_remove.default_route",
+        "The default route of the following uses provider '{name}' and cannot be cleared: {profiles}; use --reassign to switch to another provider",
+    ),
+    (
+        "provider_remove.reassign_self",
+        "Cannot reassign routes to provider '{name}', which is being removed",
+    ),
+    (
+        "provider_remove.unknown_model",
+        "Provider '{provider}' has no model '{model}'",
+    ),
+    (
+        "provider_remove.choose",
+        "❓ How should these routes be handled?",
+    ),
+    ("provider_remove.option_block", "Cancel the removal"),
+    (
+        "provider_remove.option_cascade",
+        "Clear the optional routes that use it",
+    ),
+    (
+        "provider_remove.option_cascade_unavailable",
+        "Clear the optional routes that use it (unavailable: a default route uses it)",
+    ),
+    (
+        "provider_remove.option_reassign",
+        "Switch them to another provider",
+    ),
+    ("provider_remove.prompt_choice", "Choose (1-3, default 1): "),
+    (
+        "provider_remove.available",
+        "Available providers: {providers}",
+    ),
+    (
+        "provider_remove.prompt_reassign",
+        "New provider (provider or provider,model): ",
+    ),
+    (
+        "provider_remove.no_other_provider",
+        "No other provider is available",
+    ),
+    ("provider_remove.cleared", "🧹 Cleared {count} route(s)"),
+    (
+        "provider_remove.reassigned",
+        "🔀 {count} route(s) now use '{provider}'",
+    ),
+    // doctor.rs: 健康检查
+    ("doctor.title", "🩺 ccode health check"),
+    ("doctor.section.tools", "🧰 Tools"),
+    ("doctor.section.config", "📄 Configuration files"),
+    ("doctor.section.defaults", "🎯 Default profiles"),
+    ("doctor.section.routes", "🔀 Routes"),
+    ("doctor.section.credentials", "🔑 Tokens and keys"),
+    ("doctor.section.permissions", "🔒 File permissions"),
+    ("doctor.status_pass", "[PASS]"),
+    ("doctor.status_warn", "[WARN]"),
+    ("doctor.status_fail", "[FAIL]"),
+    ("doctor.fix_hint", "💡 Fix: {fix}"),
+    (
+        "doctor.summary",
+        "📊 {pass} passed, {warn} warning(s), {fail} failed",
+    ),
+    ("doctor.failed", "Health check found {count} failure(s)"),
+    ("doctor.tool_found", "{tool}: {version}"),
+    ("doctor.tool_missing", "{tool} was not found on PATH"),
+    (
+        "doctor.ccr_not_needed",
+        "ccr: not installed (only needed for Router mode)",
+    ),
+    (
+        "doctor.ccode_config_ok",
+        "ccode configuration parses: {path}",
+    ),
+    (
+        "doctor.ccode_config_missing",
+        "No ccode configuration file yet",
+    ),
+    (
+        "doctor.ccode_config_invalid",
+        "ccode configuration {path} cannot be parsed: {err}",
+    ),
+    (
+        "doctor.ccr_config_unavailable",
+        "Cannot read the claude-code-router configuration: {err}",
+    ),
+    (
+        "doctor.ccr_config_ok",
+        "claude-code-router configuration parses: {path}",
+    ),
+    (
+        "doctor.ccr_config_invalid",
+        "claude-code-router configuration {path} is invalid: {err}",
+    ),
+    (
+        "doctor.ccr_config_missing",
+        "RouterProfiles exist but there is no claude-code-router configuration file",
+    ),
+    (
+        "doctor.ccr_config_not_needed",
+        "claude-code-router configuration: not created (only needed for Router mode)",
+    ),
+    ("doctor.default_ok", "Default {group} profile: {name}"),
+    (
+        "doctor.default_missing",
+        "Default {group} profile '{name}' does not exist",
+    ),
+    ("doctor.default_unset", "No default {group} profile is set"),
+    (
+        "doctor.ccr_router_ok",
+        "All routes in the claude-code-router Router node are valid",
+    ),
+    (
+        "doctor.profile_routes_ok",
+        "Routes of {count} RouterProfile(s) are valid",
+    ),
+    (
+        "doctor.route_missing_provider",
+        "{label} {route}: references missing provider '{provider}'",
+    ),
+    (
+        "doctor.route_unknown_model",
+        "{label} {route}: model '{model}' is not in the model list of provider '{provider}'",
+    ),
+    ("doctor.direct_label", "Direct profile '{name}'"),
+    ("doctor.provider_label", "Provider '{name}'"),
+    (
+        "doctor.provider_env_unset",
+        "{label}: environment variable {var} referenced by the API key is not set",
+    ),
+    (
+        "doctor.credentials_ok",
+        "No problems found in {count} token(s) and key(s)",
+    ),
+    ("doctor.secret_invalid", "{label}: {err}"),
+    (
+        "doctor.secret_env_unset",
+        "{label}: environment variable {var} is not set",
+    ),
+    (
+        "doctor.secret_file_missing",
+        "{label}: file {path} does not exist",
+    ),
+    (
+        "doctor.secret_malformed",
+        "{label}: token looks malformed ({reason})",
+    ),
+    ("doctor.malformed.empty", "empty"),
+    ("doctor.malformed.bearer", "includes a Bearer prefix"),
+    ("doctor.malformed.whitespace", "contains whitespace"),
+    (
+        "doctor.malformed.quotes",
+        "contains quotes or angle brackets",
+    ),
+    (
+        "doctor.malformed.non_ascii",
+        "contains non-ASCII characters",
+    ),
+    ("doctor.malformed.too_short", "too short"),
+    ("doctor.permissions_ok", "{path} has mode {mode}"),
+    (
+        "doctor.permissions_open",
+        "{path} has mode {mode} and is accessible to other users",
+    ),
+    (
+        "doctor.backup_permissions_ok",
+        "{path} and its {count} backup(s) are private to the current user",
+    ),
+    (
+        "doctor.backup_permissions_open",
+        "{count} item(s) in {path} are accessible to other users; backups hold plaintext copies of the config",
+    ),
+    (
+        "doctor.fix.install_claude",
+        "npm install -g @anthropic-ai/claude-code",
+    ),
+    (
+        "doctor.fix.install_ccr",
+        "npm install -g @musistudio/claude-code-router",
+    ),
+    (
+        "doctor.fix.add_profile",
+        "Add a first profile with 'ccode add <name>'",
+    ),
+    (
+        "doctor.fix.restore_ccode",
+        "Fix the file, or restore a backup with 'ccode backup --ccode restore latest'",
+    ),
+    (
+        "doctor.fix.restore_ccr",
+        "Fix the file, or restore a backup with 'ccode backup restore latest'",
+    ),
+    (
+        "doctor.fix.provider_add",
+        "Add a provider with 'ccode provider add <name>'",
+    ),
+    ("doctor.fix.set_default", "{command} {name}"),
+    (
+        "doctor.fix.ccr_router",
+        "Add the missing provider, or re-apply a RouterProfile with 'ccode use-ccr <name>'",
+    ),
+    ("doctor.fix.edit_profile", "ccode edit {name}"),
+    ("doctor.fix.provider_edit", "ccode provider edit {provider}"),
+    ("doctor.fix.export_env", "export {var}=..."),
+    (
+        "doctor.fix.secret_file",
+        "Create {path}, or change the token reference",
+    ),
+    ("doctor.fix.chmod", "chmod 600 {path}"),
+    ("doctor.fix.chmod_recursive", "chmod -R go-rwx {path}"),
+    // commands.rs: 配置环境变量
+    (
+        "env.invalid_assignment",
+        "Invalid environment assignment '{assignment}', expected KEY=VALUE",
+    ),
+    (
+        "env.invalid_name",
+        "Invalid environment variable name '{name}': use letters, digits and underscores, not starting with a digit",
+    ),
+    (
+        "env.reserved_name",
+        "Environment variable '{name}' is managed by ccode and cannot be set in a profile's env",
+    ),
+    (
+        "env.process_name",
+        "Environment variable '{name}' controls program lookup or the runtime environment and cannot be set in a profile's env",
+    ),
+    ("env.set", "✅ Set {key} for profile '{name}'"),
+    ("env.unset", "✅ Removed {key} from profile '{name}'"),
+    ("env.not_set", "⚠️  Profile '{name}' does not set {key}"),
+    (
+        "env.empty",
+        "📋 Profile '{name}' sets no extra environment variables",
+    ),
+    (
+        "env.shell_ccr_restart",
+        "⚠️  The Router profile was applied to the ccr config; the running ccr service uses the new routes only after a restart (ccr restart)",
+    ),
+    (
+        "env.shell_nothing_saved",
+        "⚠️  {var} is not set in the current environment, so there is nothing to restore (use --unset in the same shell where ccode env was evaluated)",
+    ),
+    (
+        "env.shell_secret_unsaved",
+        "⚠️  These variables were set before, but their secret values were not saved to avoid leaking them, so they were removed; set them again if needed: {names}",
+    ),
+    ("env.shell_saved_invalid", "Invalid contents in {var}: {e}"),
+];
+
+/// 命令行帮助信息，键为 `cli.<子命令>...` 或 `cli.<子命令>.<参数ID>`
+pub(super) const HELP: &[(&str, &str)] = &[
+    ("cli", "Claude Code environment switcher"),
+    (
+        "cli.output",
+        "Output format for list and show commands: text (default), json, yaml or table",
+    ),
+    (
+        "cli.show_secrets",
+        "Show full secrets in structured output (masked by default)",
+    ),
+    ("cli.quiet", "Only print result data, warnings and errors"),
+    (
+        "cli.verbose",
+        "Also print backup, sync and other progress details",
+    ),
+    (
+        "cli.no_emoji",
+        "Strip emoji prefixes from output (same as setting NO_COLOR)",
+    ),
+    ("cli.lang", "Interface language: zh-CN or en"),
+    ("cli.list", "List all available profiles"),
+    ("cli.list.group", "Profile group (direct|ccr)"),
+    ("cli.add", "Add a new profile"),
+    ("cli.add.name", "Profile name"),
+    ("cli.add.group", "Profile group (direct|ccr)"),
+    ("cli.add.token", "ANTHROPIC_AUTH_TOKEN"),
+    (
+        "cli.add.token_from_env",
+        "Read ANTHROPIC_AUTH_TOKEN from the given environment variable",
+    ),
+    (
+        "cli.add.token_from_file",
+        "Read ANTHROPIC_AUTH_TOKEN from the given file",
+    ),
+    ("cli.add.base_url", "ANTHROPIC_BASE_URL"),
+    ("cli.add.model", "ANTHROPIC_MODEL"),
+    ("cli.add.small_fast_model", "ANTHROPIC_SMALL_FAST_MODEL"),
+    ("cli.add.description", "Profile description"),
+    (
+        "cli.add.non_interactive",
+        "Never prompt, fail when a required argument is missing",
+    ),
+    ("cli.use", "Set the default profile"),
+    ("cli.use.name", "Profile name"),
+    ("cli.use.group", "Profile group (direct|ccr)"),
+    ("cli.run", "Launch claude"),
+    (
+        "cli.run.name",
+        "Optional profile name, the default profile is used when omitted",
+    ),
+    ("cli.run.group", "Profile group (direct|ccr)"),
+    (
+        "cli.run.claude_args",
+        "Arguments passed through to claude (Direct mode only, e.g. run myprofile --version or run myprofile -- --help)",
+    ),
+    ("cli.remove", "Remove a profile"),
+    ("cli.remove.name", "Profile name"),
+    ("cli.remove.group", "Profile group (direct|ccr)"),
+    (
+        "cli.edit",
+        "Edit a profile (interactive when no field arguments are given)",
+    ),
+    ("cli.edit.name", "Profile name"),
+    (
+        "cli.edit.group",
+        "Profile group (direct|ccr), detected from the name when omitted",
+    ),
+    ("cli.edit.token", "ANTHROPIC_AUTH_TOKEN"),
+    (
+        "cli.edit.token_from_env",
+        "Read ANTHROPIC_AUTH_TOKEN from the given environment variable",
+    ),
+    (
+        "cli.edit.token_from_file",
+        "Read ANTHROPIC_AUTH_TOKEN from the given file",
+    ),
+    ("cli.edit.base_url", "ANTHROPIC_BASE_URL"),
+    (
+        "cli.edit.model",
+        "ANTHROPIC_MODEL (an empty string clears it)",
+    ),
+    (
+        "cli.edit.small_fast_model",
+        "ANTHROPIC_SMALL_FAST_MODEL (an empty string clears it)",
+    ),
+    ("cli.edit.default", "Default route (provider,model)"),
+    (
+        "cli.edit.background",
+        "Background task route (provider,model), an empty string clears it",
+    ),
+    (
+        "cli.edit.think",
+        "Thinking task route (provider,model), an empty string clears it",
+    ),
+    (
+        "cli.edit.long_context",
+        "Long context route (provider,model), an empty string clears it",
+    ),
+    ("cli.edit.long_context_threshold", "Long context threshold"),
+    (
+        "cli.edit.web_search",
+        "Web search route (provider,model), an empty string clears it",
+    ),
+    (
+        "cli.edit.from_file",
+        "Read the Router configuration from a JSON file (command line arguments take precedence)",
+    ),
+    (
+        "cli.edit.description",
+        "Profile description (an empty string clears it)",
+    ),
+    (
+        "cli.edit.raw",
+        "Edit the profile's JSON directly in $EDITOR",
+    ),
+    ("cli.rename", "Rename a profile"),
+    ("cli.rename.old", "Current profile name"),
+    ("cli.rename.new", "New profile name"),
+    (
+        "cli.rename.group",
+        "Profile group (direct|ccr); detected from the name if omitted",
+    ),
+    ("cli.copy", "Copy a profile"),
+    ("cli.copy.source", "Source profile name"),
+    ("cli.copy.target", "New profile name"),
+    (
+        "cli.copy.group",
+        "Profile group (direct|ccr); detected from the name if omitted",
+    ),
+    ("cli.add-ccr", "Add a CCR profile"),
+    ("cli.add-ccr.name", "Profile name"),
+    ("cli.add-ccr.default", "Default route (provider,model)"),
+    (
+        "cli.add-ccr.background",
+        "Background task route (provider,model)",
+    ),
+    ("cli.add-ccr.think", "Thinking task route (provider,model)"),
+    (
+        "cli.add-ccr.long_context",
+        "Long context route (provider,model)",
+    ),
+    (
+        "cli.add-ccr.long_context_threshold",
+        "Long context threshold",
+    ),
+    (
+        "cli.add-ccr.web_search",
+        "Web search route (provider,model)",
+    ),
+    ("cli.add-ccr.description", "Profile description"),
+    (
+        "cli.add-ccr.from_file",
+        "Read the Router configuration from a JSON file (command line arguments take precedence)",
+    ),
+    ("cli.run-ccr", "Launch a CCR profile"),
+    (
+        "cli.run-ccr.name",
+        "Optional profile name, the default CCR profile is used when omitted",
+    ),
+    ("cli.list-ccr", "List CCR profiles"),
+    ("cli.use-ccr", "Set the default CCR profile"),
+    ("cli.use-ccr.name", "Profile name"),
+    ("cli.remove-ccr", "Remove a CCR profile"),
+    ("cli.remove-ccr.name", "Profile name"),
+    ("cli.provider", "Manage providers"),
+    ("cli.provider.list", "List all providers"),
+    ("cli.provider.add", "Add a new provider"),
+    ("cli.provider.add.name", "Provider name"),
+    (
+        "cli.provider.add.provider_type",
+        "Provider type (openai|openrouter|deepseek|gemini|qwen|custom)",
+    ),
+    ("cli.provider.add.api_key", "API key"),
+    (
+        "cli.provider.add.api_key_env",
+        "Read the API key from the given environment variable",
+    ),
+    (
+        "cli.provider.add.url",
+        "API URL, defaults to the URL of the provider type",
+    ),
+    (
+        "cli.provider.add.models",
+        "Comma separated model list, defaults to the models of the provider type",
+    ),
+    (
+        "cli.provider.add.transformer_json",
+        "Read the transformer configuration from a JSON file, overriding the generated one",
+    ),
+    (
+        "cli.provider.add.dry_run",
+        "Only print the generated provider JSON without writing the configuration",
+    ),
+    (
+        "cli.provider.add.non_interactive",
+        "Never prompt, fail when a required argument is missing",
+    ),
+    ("cli.provider.remove", "Remove a provider"),
+    ("cli.provider.remove.name", "Provider name"),
+    (
+        "cli.provider.remove.force",
+        "Skip confirmation prompts; removal is still blocked while routes reference the provider unless --cascade or --reassign is given",
+    ),
+    (
+        "cli.provider.remove.cascade",
+        "Clear the optional routes that reference the provider",
+    ),
+    (
+        "cli.provider.remove.reassign",
+        "Switch routes that reference the provider to another provider (provider or provider,model)",
+    ),
+    ("cli.provider.show", "Show provider details"),
+    ("cli.provider.show.name", "Provider name"),
+    (
+        "cli.provider.rename",
+        "Rename a provider and update every route that references it",
+    ),
+    ("cli.provider.rename.old", "Current provider name"),
+    ("cli.provider.rename.new", "New provider name"),
+    ("cli.provider.rename.yes", "Skip the confirmation prompt"),
+    ("cli.provider.edit", "Edit a provider"),
+    ("cli.provider.edit.name", "Provider name"),
+    (
+        "cli.provider.edit.raw",
+        "Edit the provider's JSON directly in $EDITOR",
+    ),
+    (
+        "cli.backup",
+        "Manage configuration backups (CCR configuration backups by default)",
+    ),
+    (
+        "cli.backup.ccode",
+        "Manage backups of ccode's own configuration",
+    ),
+    ("cli.backup.list", "List all backups"),
+    ("cli.backup.show", "Show a backup"),
+    ("cli.backup.show.id", "Backup ID (or latest)"),
+    ("cli.backup.diff", "Compare backups"),
+    ("cli.backup.diff.id", "Backup ID (or latest)"),
+    (
+        "cli.backup.diff.other",
+        "Comparison target: another backup ID or current (default)",
+    ),
+    (
+        "cli.backup.restore",
+        "Restore the configuration from a backup",
+    ),
+    ("cli.backup.restore.id", "Backup ID (or latest)"),
+    ("cli.backup.restore.yes", "Skip the confirmation prompt"),
+    (
+        "cli.backup.prune",
+        "Prune expired backups according to the retention policy",
+    ),
+    (
+        "cli.backup.prune.dry_run",
+        "Only show the backups that would be pruned",
+    ),
+    (
+        "cli.backup.policy",
+        "Show or change the backup retention policy",
+    ),
+    (
+        "cli.backup.policy.keep_last",
+        "Number of most recent backups to keep",
+    ),
+    (
+        "cli.backup.policy.keep_daily_days",
+        "Keep the newest backup of each day for this many days",
+    ),
+    (
+        "cli.backup.policy.max_total_size",
+        "Total backup size limit, e.g. 10MB (none for unlimited)",
+    ),
+    ("cli.secrets", "Manage secret storage"),
+    (
+        "cli.secrets.status",
+        "Show the secret backend and how each profile's secret is stored",
+    ),
+    (
+        "cli.secrets.migrate",
+        "Migrate all secrets to the given backend",
+    ),
+    (
+        "cli.secrets.migrate.to",
+        "Target backend: plaintext, vault, keyring",
+    ),
+    (
+        "cli.secrets.migrate.keep_backups",
+        "Keep existing configuration backups that contain plaintext secrets",
+    ),
+    (
+        "cli.undo",
+        "Undo the last change to the ccode configuration",
+    ),
+    ("cli.undo.yes", "Skip the confirmation prompt"),
+    (
+        "cli.doctor",
+        "Check the environment and configuration and suggest fixes",
+    ),
+    (
+        "cli.route_validation",
+        "Model check for routes: warn or strict (defaults to CCODE_ROUTE_VALIDATION, then warn)",
+    ),
+    (
+        "cli.run.no_exec",
+        "Wait for claude and print its exit status instead of replacing ccode with it (Unix only)",
+    ),
+    (
+        "cli.run.inherit_env",
+        "Inherit Anthropic/Claude Code variables from the current environment as-is (by default, those not set by the profile are removed)",
+    ),
+    (
+        "cli.run.clean_env",
+        "Pass only basic variables (PATH, HOME, terminal and locale) and the profile's variables to claude",
+    ),
+    (
+        "cli.run.show_env",
+        "Show the environment claude would get (secrets masked) without launching it",
+    ),
+    (
+        "cli.run-ccr.no_exec",
+        "Wait for ccr and print its exit status instead of replacing ccode with it (Unix only)",
+    ),
+    (
+        "cli.run-ccr.inherit_env",
+        "Inherit Anthropic/Claude Code variables from the current environment as-is (by default, those not set by the profile are removed)",
+    ),
+    (
+        "cli.run-ccr.clean_env",
+        "Pass only basic variables (PATH, HOME, terminal and locale) and the profile's variables to ccr",
+    ),
+    (
+        "cli.run-ccr.show_env",
+        "Show the environment ccr would get (secrets masked) without launching it",
+    ),
+    (
+        "cli.exec",
+        "Run any program with a profile's environment variables",
+    ),
+    ("cli.exec.name", "Profile name"),
+    (
+        "cli.exec.group",
+        "Profile group (direct|ccr); detected from the name if omitted",
+    ),
+    (
+        "cli.exec.no_exec",
+        "Wait for the program to exit and report its status instead of replacing the current process (Unix only)",
+    ),
+    (
+        "cli.exec.inherit_env",
+        "Inherit Anthropic/Claude Code variables from the current environment as-is (by default, those not set by the profile are removed)",
+    ),
+    (
+        "cli.exec.clean_env",
+        "Pass only basic variables (PATH, HOME, terminal and locale) and the profile's variables to the program",
+    ),
+    (
+        "cli.exec.show_env",
+        "Show the environment the program would get (secrets masked) without launching it",
+    ),
+    (
+        "cli.exec.restart",
+        "Restart the ccr service even if the routes did not change, e.g. after editing providers (Router profiles only)",
+    ),
+    (
+        "cli.exec.command",
+        "Program to run and its arguments (after --)",
+    ),
+    (
+        "cli.env",
+        "Manage extra environment variables of a profile, or print shell statements that set a Direct profile's variables",
+    ),
+    (
+        "cli.env.name",
+        "Profile name (Direct or Router); the default Direct profile is used if omitted",
+    ),
+    (
+        "cli.env.group",
+        "Profile group (direct|ccr); detected from the name if omitted",
+    ),
+    (
+        "cli.env.shell",
+        "Shell to generate statements for; detected from $SHELL if omitted",
+    ),
+    (
+        "cli.env.revert",
+        "Print statements that restore the values saved by the last ccode env instead",
+    ),
+    (
+        "cli.env.inherit_env",
+        "Do not print statements that remove other Anthropic/Claude Code variables from the current environment",
+    ),
+    (
+        "cli.env.list",
+        "List the environment variables of a profile",
+    ),
+    ("cli.env.list.name", "Profile name"),
+    (
+        "cli.env.list.group",
+        "Profile group (direct|ccr); detected from the name if omitted",
+    ),
+    ("cli.env.set", "Set environment variables"),
+    ("cli.env.set.name", "Profile name"),
+    ("cli.env.set.vars", "Assignments in KEY=VALUE form"),
+    (
+        "cli.env.set.group",
+        "Profile group (direct|ccr); detected from the name if omitted",
+    ),
+    ("cli.env.unset", "Remove environment variables"),
+    ("cli.env.unset.name", "Profile name"),
+    ("cli.env.unset.keys", "Variable names"),
+    (
+        "cli.env.unset.group",
+        "Profile group (direct|ccr); detected from the name if omitted",
+    ),
+];
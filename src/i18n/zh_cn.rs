@@ -0,0 +1,1039 @@
+//! 简体中文消息目录
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // error.rs
+    ("error.config", "配置错误: {msg}"),
+    ("error.io", "文件操作错误: {err}"),
+    ("error.json", "JSON格式错误: {err}"),
+    (
+        "error.config_not_found",
+        "配置文件不存在，请使用 'ccode add <name>' 添加配置",
+    ),
+    (
+        "error.profile_not_found",
+        "配置 '{name}' 不存在，请使用 'ccode list' 查看可用配置",
+    ),
+    ("error.invalid_config", "无效配置: {msg}"),
+    ("error.command_execution", "命令执行失败: {msg}"),
+    ("error.secret", "密钥存储错误: {msg}"),
+    (
+        "error.lock_timeout",
+        "配置文件 '{path}' 正被其他 ccode 进程修改，等待锁超时，请稍后重试",
+    ),
+    ("error.child_exit", "子程序退出码: {code}"),
+    ("error.prefix", "❌ 错误: {err}"),
+    // fs_utils.rs
+    ("fs.invalid_path", "无效的文件路径: {path}"),
+    // backup.rs
+    ("backup.unknown_time", "未知"),
+    (
+        "backup.invalid_size",
+        "无效的大小 '{size}'，示例: 512KB、10MB",
+    ),
+    ("backup.invalid_file", "无效的备份文件: {path}"),
+    (
+        "backup.name_exhausted",
+        "无法在 {dir} 中为 {timestamp} 生成不重复的备份文件名",
+    ),
+    ("backup.none", "暂无任何备份"),
+    (
+        "backup.not_found",
+        "备份 '{id}' 不存在，请使用 'ccode backup list' 查看可用备份",
+    ),
+    ("backup.prune_failed", "⚠️  清理过期备份失败: {e}"),
+    ("backup.invalid", "备份 '{id}' 无效: {e}"),
+    // secrets.rs
+    ("secrets.backend.plaintext", "明文 (plaintext)"),
+    ("secrets.backend.vault", "加密密钥库 (vault)"),
+    ("secrets.backend.keyring", "系统密钥环 (keyring)"),
+    (
+        "secrets.unknown_backend",
+        "未知的密钥存储后端 '{other}'，可选: plaintext, vault, keyring",
+    ),
+    (
+        "secrets.ref.missing_key",
+        "密钥引用缺少密钥名，格式: secret://ccode/<name>",
+    ),
+    (
+        "secrets.ref.invalid_env",
+        "环境变量引用 'env:{var}' 无效，变量名只能包含字母、数字和下划线且不能以数字开头",
+    ),
+    (
+        "secrets.ref.missing_path",
+        "文件引用缺少路径，格式: file:<path>",
+    ),
+    (
+        "secrets.ref.missing_command",
+        "命令引用缺少命令，格式: cmd:<command>",
+    ),
+    ("secrets.env_not_set", "环境变量 '{var}' 未设置"),
+    (
+        "secrets.file_read_failed",
+        "读取密钥文件 '{path}' 失败: {e}",
+    ),
+    ("secrets.file_empty", "密钥文件 '{path}' 为空"),
+    (
+        "secrets.cmd_spawn_failed",
+        "执行密钥命令 '{command}' 失败: {e}",
+    ),
+    (
+        "secrets.cmd_failed",
+        "密钥命令 '{command}' 执行失败，退出码: {code}",
+    ),
+    ("secrets.cmd_no_output", "密钥命令 '{command}' 没有输出"),
+    (
+        "secrets.plaintext_cannot_resolve",
+        "当前使用明文存储，无法解析密钥引用 '{reference}'，请使用 'ccode secrets migrate --to <backend>' 切换后端",
+    ),
+    ("secrets.vault.prompt", "🔐 请输入密钥库密码: "),
+    ("secrets.vault.empty_passphrase", "密钥库密码不能为空"),
+    ("secrets.vault.confirm", "🔐 请再次输入密码确认: "),
+    ("secrets.vault.mismatch", "两次输入的密码不一致"),
+    (
+        "secrets.vault.decrypt_failed",
+        "无法解密密钥库（密码错误？）: {e}",
+    ),
+    ("secrets.vault.corrupted", "密钥库内容损坏: {e}"),
+    ("secrets.vault.encrypt_failed", "加密密钥库失败: {e}"),
+    ("secrets.vault.not_found", "密钥库中不存在密钥 '{key}'"),
+    (
+        "secrets.keyring.not_installed",
+        "找不到 'secret-tool'，请安装 libsecret-tools（仅支持 Linux Secret Service）",
+    ),
+    ("secrets.keyring.spawn_failed", "执行 secret-tool 失败: {e}"),
+    ("secrets.keyring.failed", "secret-tool 执行失败: {stderr}"),
+    (
+        "secrets.keyring.not_found",
+        "系统密钥环中不存在密钥 '{key}'",
+    ),
+    // ccr_config.rs
+    ("ccr.no_home_dir", "无法获取用户主目录"),
+    ("ccr.parse_failed", "解析 CCR 配置文件失败: {e}"),
+    ("ccr.saved", "✅ CCR 配置文件已保存: {path}"),
+    (
+        "ccr.backup_missing_config",
+        "CCR 配置文件不存在，无法创建备份",
+    ),
+    ("ccr.backup_created", "📦 配置备份已创建: {path}"),
+    ("ccr.backup_pruned", "🧹 已清理 {count} 个过期备份"),
+    ("ccr.profile_applied", "✅ 已应用 Router Profile '{name}'"),
+    (
+        "ccr.route_missing_provider",
+        "路由 '{route_name}' 引用了不存在的提供商 '{provider_name}'",
+    ),
+    (
+        "ccr.route_missing_provider_warning",
+        "⚠️  警告: 路由 '{route_name}' 引用了不存在的提供商 '{provider_name}'",
+    ),
+    (
+        "ccr.generated_description",
+        "从 claude-code-router 配置自动生成",
+    ),
+    (
+        "ccr.no_providers",
+        "暂无 Provider 配置，请先使用 'ccode provider add <name>' 添加 Provider",
+    ),
+    (
+        "ccr.sync_providers",
+        "🔄 同步Provider信息: 发现 {count} 个Provider",
+    ),
+    ("ccr.router_updated", "✅ 已更新 CCR Router 配置"),
+    ("ccr.provider_updated", "✅ 已更新 CCR Provider 配置"),
+    ("ccr.providers_updated", "✅ 已更新 CCR Providers 配置"),
+    ("ccr.label", "CCR 配置"),
+    ("ccr.invalid_content", "不是有效的 CCR 配置: {e}"),
+    ("stats.provider_count", "🔗 Provider 数量: {count}"),
+    ("stats.default_route", "🎯 默认路由: {route}"),
+    ("stats.background_route", "🔄 后台路由: ✅"),
+    ("stats.think_route", "💭 思考路由: ✅"),
+    ("stats.long_context_route", "📜 长上下文路由: ✅"),
+    ("stats.web_search_route", "🔍 网络搜索路由: ✅"),
+    ("stats.api_timeout", "⏱️  API 超时: {timeout}ms"),
+    ("stats.log_enabled", "📝 日志记录: 启用"),
+    ("stats.log_disabled", "📝 日志记录: 禁用"),
+    // config.rs
+    ("config.no_config_dir", "无法获取配置目录"),
+    ("config.label", "ccode 配置"),
+    ("config.invalid_content", "不是有效的 ccode 配置: {e}"),
+    ("profile.model", "🤖 模型: {model}"),
+    ("profile.fast_model", "⚡ 快速模型: {model}"),
+    ("profile.env", "🌱 环境变量: {names}"),
+    ("profile.description", "📝 描述: {desc}"),
+    ("profile.created", "📅 创建: {created}"),
+    ("profile.already_exists", "配置 '{name}' 已存在"),
+    ("profile.no_default", "未设置默认Direct配置"),
+    ("profile.empty_token", "认证令牌不能为空"),
+    ("profile.empty_url", "基础URL不能为空"),
+    (
+        "profile.invalid_url",
+        "基础URL格式无效，应以 'http://' 或 'https://' 开头",
+    ),
+    ("profile.invalid", "配置 '{name}' 无效: {e}"),
+    ("router_profile.empty_name", "Router Profile 名称不能为空"),
+    (
+        "router_profile.already_exists",
+        "Router Profile '{name}' 已存在",
+    ),
+    ("router_profile.no_default", "未设置默认 Router Profile"),
+    (
+        "router_profile.invalid",
+        "Router Profile '{name}' 无效: {e}",
+    ),
+    ("router.empty_default", "默认路由配置不能为空"),
+    (
+        "router.invalid_default",
+        "默认路由配置格式无效，应为'provider,model'格式",
+    ),
+    (
+        "router.invalid_route",
+        "{name}路由配置格式无效，应为'provider,model'格式",
+    ),
+    ("ccr.empty_providers", "Providers列表不能为空"),
+    ("provider_type.openai", "OpenAI兼容"),
+    ("provider_type.custom", "自定义"),
+    (
+        "provider_type.unknown",
+        "未知的 Provider 类型 '{other}'，可选: openai, openrouter, deepseek, gemini, qwen, custom",
+    ),
+    (
+        "provider.gemini_url_path",
+        "Gemini API URL应包含'/v1beta/models/'路径",
+    ),
+    (
+        "provider.chat_url_path",
+        "API URL应包含'/chat/completions'路径",
+    ),
+    ("provider.empty_name", "提供商名称不能为空"),
+    ("provider.empty_url", "API URL不能为空"),
+    (
+        "provider.invalid_url",
+        "API URL格式无效，应以'http://'或'https://'开头",
+    ),
+    ("provider.empty_models", "模型列表不能为空"),
+    (
+        "provider.unconverted_key_ref",
+        "Provider '{name}' 的 API Key 引用 '{reference}' 未转换，CCR 配置中只能使用 $VAR 形式的环境变量引用",
+    ),
+    (
+        "provider.invalid_key_env_ref",
+        "Provider '{name}' 的 API Key 环境变量引用 '{reference}' 无效",
+    ),
+    ("provider.already_exists", "Provider '{name}' 已存在"),
+    ("provider.not_found", "Provider '{name}' 不存在"),
+    ("hint.openai.format", "• 标准OpenAI API格式"),
+    ("hint.openai.no_transformer", "• 无需特殊transformer配置"),
+    ("hint.openai.compatible", "• 支持大部分第三方兼容API"),
+    ("hint.openrouter.routing", "• 支持多种AI模型路由"),
+    (
+        "hint.openrouter.transformer",
+        "• 自动添加OpenRouter transformer",
+    ),
+    (
+        "hint.openrouter.online",
+        "• WebSearch功能需要在模型后加':online'后缀",
+    ),
+    ("hint.deepseek.api", "• DeepSeek专用API"),
+    (
+        "hint.deepseek.transformer",
+        "• 自动配置DeepSeek transformer",
+    ),
+    (
+        "hint.deepseek.tooluse",
+        "• deepseek-chat模型自动启用tooluse",
+    ),
+    ("hint.gemini.api", "• Google Gemini API"),
+    ("hint.gemini.path", "• API路径格式: /v1beta/models/"),
+    ("hint.gemini.transformer", "• 自动配置Gemini transformer"),
+    ("hint.qwen.models", "• 通义千问系列模型"),
+    ("hint.qwen.max_tokens", "• 自动配置最大token限制(65536)"),
+    ("hint.qwen.reasoning", "• Thinking模型自动启用reasoning模式"),
+    ("hint.qwen.tooluse", "• 增强的工具调用支持"),
+    ("hint.custom.api", "• 自定义API配置"),
+    (
+        "hint.custom.transformer",
+        "• 需要手动配置transformer(如需要)",
+    ),
+    ("hint.custom.other", "• 适用于其他AI服务提供商"),
+    // commands.rs: 路由推荐与 Direct 配置
+    ("recommend.fast", "🚀 快速响应"),
+    ("recommend.cost_effective", "💰 高性价比"),
+    ("recommend.reasoning", "🧠 强大推理"),
+    ("recommend.chain_of_thought", "🤔 思维链推理"),
+    ("recommend.logic", "🔬 逻辑分析"),
+    ("recommend.long_context", "📜 超长上下文"),
+    ("recommend.massive_input", "🌐 海量信息处理"),
+    ("recommend.documents", "📖 文档分析专家"),
+    ("recommend.live_search", "🔍 实时搜索"),
+    ("recommend.basic_search", "🌐 基础网络查询"),
+    (
+        "input.env_not_set",
+        "环境变量 '{var}' 未设置或不是有效的UTF-8",
+    ),
+    ("input.missing_flag", "非交互模式下缺少必需参数 {flag}"),
+    ("add.token_file_failed", "读取令牌文件 '{path}' 失败: {e}"),
+    ("add.title", "🔧 添加新配置: {name}"),
+    (
+        "add.prompt_token",
+        "🔑 请输入 ANTHROPIC_AUTH_TOKEN (支持各种第三方API格式，或 env:VAR / file:PATH / cmd:COMMAND 引用): ",
+    ),
+    (
+        "add.prompt_base_url",
+        "📍 请输入 ANTHROPIC_BASE_URL (如: https://api.anthropic.com): ",
+    ),
+    (
+        "add.prompt_model",
+        "🤖 请输入 ANTHROPIC_MODEL (可选，直接回车跳过): ",
+    ),
+    (
+        "add.prompt_fast_model",
+        "⚡ 请输入 ANTHROPIC_SMALL_FAST_MODEL (可选，直接回车跳过): ",
+    ),
+    (
+        "add.prompt_description",
+        "📝 请输入描述 (可选，直接回车跳过): ",
+    ),
+    ("add.success", "✅ 配置 '{name}' 添加成功！"),
+    ("add.auto_default", "🎯 已自动设为默认配置"),
+    ("use.success", "✅ 已将 '{name}' 设为默认配置"),
+    ("run.starting", "🚀 使用配置 '{name}' 启动 claude..."),
+    ("run.passthrough", "📄 透传参数: {args}"),
+    (
+        "run.passthrough_hint",
+        "💡 提示: 可以直接在命令后添加参数透传给 claude 命令 (例如: ccode run myprofile --version 或 ccode run myprofile -- --help)",
+    ),
+    ("run.exited", "✅ {program} 程序正常退出"),
+    (
+        "run.exited_abnormally",
+        "⚠️  {program} 程序异常退出，退出码: {code}",
+    ),
+    (
+        "run.killed_by_signal",
+        "⚠️  {program} 程序被信号 {signal} 终止，退出码: {code}",
+    ),
+    (
+        "run.claude_not_found",
+        "找不到 'claude' 程序，请确保 claude 已安装并在 PATH 中",
+    ),
+    ("run.failed", "执行 {program} 失败: {e}"),
+    ("remove.confirm", "⚠️  确定要删除配置 '{name}' 吗？(y/N): "),
+    ("remove.cancelled", "❌ 取消删除"),
+    ("remove.success", "✅ 配置 '{name}' 已删除"),
+    (
+        "remove.current_default_direct",
+        "🎯 当前默认Direct配置: {name}",
+    ),
+    (
+        "remove.current_default_router",
+        "🎯 当前默认Router配置: {name}",
+    ),
+    (
+        "list.empty",
+        "📋 暂无配置，请使用 'ccode add <name>' 添加配置",
+    ),
+    ("group.unknown", "未知的配置组: {group}"),
+    (
+        "launch.env_title",
+        "🌱 {program} 的环境变量（策略: {policy}）:",
+    ),
+    ("launch.env_set", "由配置设置:"),
+    ("launch.env_none", "（无）"),
+    ("launch.env_removed", "从继承的环境中去掉:"),
+    ("launch.env_inherited_rest", "其余变量从当前环境继承"),
+    (
+        "exec.no_command",
+        "缺少要运行的程序，用法: ccode exec <name> -- <command> [args...]",
+    ),
+    ("exec.starting", "🚀 使用配置 '{name}' 运行 {program}..."),
+    (
+        "exec.not_found",
+        "找不到程序 '{program}'，请检查名称或 PATH",
+    ),
+    (
+        "exec.no_ccr_config",
+        "未找到 claude-code-router 配置文件，请先使用 'ccode provider add <name>' 添加 Provider",
+    ),
+    ("exec.restarting_ccr", "🔄 重启 claude-code-router 服务..."),
+    (
+        "exec.ccr_unchanged",
+        "ℹ️  ccr 路由未变化且服务正在运行，不重启（修改 Provider 后需要重新加载时使用 --restart）",
+    ),
+    (
+        "exec.ccr_restart_failed",
+        "重启 claude-code-router 服务失败（{status}）",
+    ),
+    (
+        "exec.ccr_unreachable",
+        "claude-code-router 服务 {endpoint} 未能在启动后接受连接，可运行 'ccr status' 查看",
+    ),
+    // commands.rs: 列表与 CCR 配置
+    (
+        "add.ccr_ignores_flags",
+        "⚠️  注意: --token 等参数仅适用于 Direct 配置，将被忽略",
+    ),
+    ("list.all_title", "📋 所有配置："),
+    ("list.direct_section", "🔗 Direct组配置："),
+    ("list.router_section", "🎯 Router组配置："),
+    (
+        "list.direct_empty",
+        "📋 暂无Direct配置，请使用 'ccode add --group direct <name>' 添加配置",
+    ),
+    ("list.direct_title", "📋 Direct组配置："),
+    ("use.direct_success", "✅ 已将 '{name}' 设为默认Direct配置"),
+    (
+        "remove.direct_confirm",
+        "⚠️  确定要删除Direct配置 '{name}' 吗？(y/N): ",
+    ),
+    ("remove.direct_success", "✅ Direct配置 '{name}' 已删除"),
+    ("list_ccr.title", "📋 CCR配置 (Router Profile) 列表："),
+    ("list_ccr.empty", "❌ 暂无CCR配置"),
+    (
+        "list_ccr.getting_started",
+        "💡 要开始使用CCR，请按以下步骤操作:",
+    ),
+    (
+        "list_ccr.step_add_provider",
+        "1. ccode provider add <name>     # 添加Provider",
+    ),
+    (
+        "list_ccr.step_add_ccr",
+        "2. ccode add-ccr <name>          # 添加CCR配置",
+    ),
+    (
+        "list_ccr.hint_add",
+        "💡 使用 'ccode add-ccr <name>' 添加CCR配置",
+    ),
+    ("list_ccr.active_title", "📊 当前应用的路由配置："),
+    (
+        "list_ccr.no_ccr_config",
+        "⚠️  claude-code-router 配置文件不存在",
+    ),
+    (
+        "hint.add_provider_first",
+        "💡 请先使用 'ccode provider add <name>' 添加 Provider",
+    ),
+    (
+        "add_ccr.title",
+        "🎯 添加新的CCR配置 (Router Profile): {name}",
+    ),
+    (
+        "add_ccr.success",
+        "✅ CCR配置 (Router Profile) '{name}' 添加成功！",
+    ),
+    ("add_ccr.auto_default", "🎯 已自动设为默认CCR配置"),
+    ("providers.available", "📋 可用的 Providers:"),
+    ("providers.features", "💡 特色功能:"),
+    (
+        "add_ccr.default_route_title",
+        "🎯 配置默认路由 (格式: provider,model):",
+    ),
+    ("add_ccr.recommended", "💡 智能推荐路由:"),
+    ("add_ccr.recommended_options", "💡 推荐选项:"),
+    (
+        "add_ccr.invalid_route_skip",
+        "⚠️  路由格式应为'provider,model'，跳过此设置",
+    ),
+    (
+        "add_ccr.prompt_threshold",
+        "⚖️  长上下文阈值 (默认: 60000): ",
+    ),
+    (
+        "add_ccr.invalid_threshold",
+        "⚠️  无效的阈值格式，使用默认值 60000",
+    ),
+    ("add_ccr.prompt_description", "📝 描述 (可选): "),
+    ("use_ccr.title", "🎯 激活CCR配置: {name}"),
+    ("use_ccr.details", "📋 配置信息:"),
+    (
+        "use_ccr.success",
+        "✅ 已激活CCR配置 '{name}' 并同步到 claude-code-router",
+    ),
+    ("list.default_marker", " (默认)"),
+    (
+        "run.ccr_ignores_args",
+        "⚠️  注意: CCR 模式不支持透传参数，将忽略: {args}",
+    ),
+    ("route.default", "🚀 默认路由: {route}"),
+    ("route.background", "🔄 后台路由: {route}"),
+    ("route.think", "💭 思考路由: {route}"),
+    ("route.long_context", "📜 长上下文路由: {route}"),
+    ("route.web_search", "🔍 网络搜索路由: {route}"),
+    ("active_route.default", "🎯 默认: {route}"),
+    ("active_route.background", "🔄 后台: {route}"),
+    ("active_route.think", "💭 思考: {route}"),
+    ("active_route.long_context", "📜 长上下文: {route}"),
+    ("active_route.web_search", "🔍 网络搜索: {route}"),
+    ("list_ccr.available_providers", "🔗 可用 Provider: {count}"),
+    (
+        "route.invalid_format",
+        "{route_desc}格式错误，应为'provider,model'格式",
+    ),
+    ("route_desc.default", "默认路由"),
+    ("route_desc.background", "🔄 后台任务路由"),
+    ("route_desc.think", "💭 思考任务路由"),
+    ("route_desc.long_context", "📜 长上下文路由"),
+    ("route_desc.web_search", "🔍 网络搜索路由"),
+    (
+        "add_ccr.router_file_failed",
+        "读取 Router 配置文件 '{path}' 失败: {e}",
+    ),
+    (
+        "add_ccr.missing_default",
+        "缺少默认路由，请使用 --default 或 --from-file 指定",
+    ),
+    (
+        "add_ccr.no_ccr_config",
+        "未找到 claude-code-router 配置文件，请先使用 'ccode provider add <name>' 添加 Provider",
+    ),
+    (
+        "add_ccr.no_providers",
+        "暂无可用的 Provider，请先使用 'ccode provider add <name>' 添加 Provider",
+    ),
+    ("add_ccr.prompt_route", "配置 {route_desc} (直接回车跳过): "),
+    ("provider_type.unknown_type", "未知类型"),
+    ("providers.model_list", "🤖 模型列表 ({count} 个):"),
+    ("providers.more_models", "... ({count} 个模型)"),
+    ("recommend.openai", "🔑 最稳定兼容"),
+    ("recommend.openrouter", "🌐 多模型支持"),
+    ("recommend.deepseek", "🧠 强大的推理能力"),
+    ("recommend.gemini", "🚀 Google最新技术"),
+    ("recommend.qwen", "🎨 中文优化"),
+    ("recommend.custom", "⚙️ 自定义配置"),
+    ("recommend.generic", "💻 通用类型"),
+    (
+        "route.unknown_model",
+        "路由 '{route}' 的模型 '{model}' 不在提供商 '{provider}' 的模型列表中",
+    ),
+    ("route.issue_warning", "⚠️  警告: {issue}"),
+    (
+        "route.strict_rejected",
+        "{issue}（严格校验模式，可使用 --route-validation warn 放宽）",
+    ),
+    ("add_ccr.route_skipped", "⚠️  {issue}，已跳过此路由"),
+    // commands.rs: CCR 运行与 Provider 管理
+    ("run_ccr.starting", "🚀 启动CCR配置..."),
+    ("run_ccr.native", "💡 使用ccr原生命令管理"),
+    ("run_ccr.no_profiles", "❌ 暂无 Router Profile 配置"),
+    ("run_ccr.setup_steps", "💡 请先使用以下步骤配置:"),
+    (
+        "run_ccr.step_provider",
+        "1. ccode provider add <name>  # 添加 Provider",
+    ),
+    (
+        "run_ccr.step_profile",
+        "2. ccode add-ccr <name>       # 添加 Router Profile",
+    ),
+    (
+        "run_ccr.hint_add",
+        "💡 请使用 'ccode add-ccr <name>' 添加 Router Profile",
+    ),
+    ("run_ccr.no_default", "❌ 未设置默认 Router Profile"),
+    ("run_ccr.available", "💡 可用的 Router Profile:"),
+    ("run_ccr.usage", "使用方法: ccode run-ccr <profile-name>"),
+    (
+        "run_ccr.usage_default",
+        "或者设置默认: ccode use-ccr <profile-name>",
+    ),
+    ("run_ccr.using", "🎯 使用 Router Profile '{profile_name}'"),
+    (
+        "run_ccr.no_ccr_config",
+        "❌ 未找到 claude-code-router 配置文件",
+    ),
+    ("run_ccr.applying", "📄 应用 Router Profile 到配置文件..."),
+    ("run_ccr.launching", "🎯 启动 ccr code..."),
+    ("remove_ccr.title", "🗑️  删除CCR配置: {name}"),
+    ("remove_ccr.details", "📋 将要删除的配置:"),
+    ("remove_ccr.is_default", "⚠️  '{name}' 是当前的默认CCR配置"),
+    ("remove_ccr.reset_default", "删除后需要重新设置默认配置"),
+    (
+        "remove_ccr.confirm",
+        "确定要删除CCR配置 '{name}' 吗？(y/N): ",
+    ),
+    ("remove_ccr.success", "✅ CCR配置 '{name}' 已删除"),
+    ("remove_ccr.current_default", "🎯 当前默认CCR配置: {router}"),
+    (
+        "remove_ccr.no_default",
+        "⚠️  无默认CCR配置，请使用 'ccode use-ccr <name>' 设置",
+    ),
+    (
+        "remove_ccr.empty",
+        "📋 暂无CCR配置，请使用 'ccode add-ccr <name>' 添加配置",
+    ),
+    (
+        "provider_list.no_config",
+        "📋 暂无 claude-code-router 配置文件",
+    ),
+    (
+        "provider_list.hint_first",
+        "💡 使用 'ccode provider add <name>' 添加第一个 Provider",
+    ),
+    ("provider_list.empty", "📋 暂无 Provider 配置"),
+    (
+        "provider_list.hint_add",
+        "💡 使用 'ccode provider add <name>' 添加 Provider",
+    ),
+    ("provider_list.title", "📋 Provider 列表："),
+    ("provider_list.stats", "📊 配置统计："),
+    ("provider_add.select_type", "📋 选择 Provider 类型:"),
+    ("provider_add.prompt_type", "请选择 [1-6]: "),
+    (
+        "provider_add.invalid_type",
+        "❌ 无效选择，默认使用OpenAI兼容类型",
+    ),
+    ("provider_add.title", "🔗 添加新 Provider: {name}"),
+    ("provider_add.prompt_key", "🔑 请输入 API Key: "),
+    ("provider_add.url_title", "📍 API URL 配置:"),
+    ("provider_add.prompt_url", "自定义URL (直接回车使用默认): "),
+    ("provider_add.models_title", "🤖 模型配置:"),
+    (
+        "provider_add.prompt_models",
+        "自定义模型列表 (用逗号分隔，直接回车使用默认): ",
+    ),
+    ("provider_add.creating", "🔧 正在创建 Provider..."),
+    ("provider_add.dry_run", "🔍 预览模式，未写入配置文件:"),
+    ("provider_add.success", "✅ Provider '{name}' 添加成功！"),
+    (
+        "provider_remove.referenced",
+        "⚠️  Provider '{name}' 仍被以下 {count} 条路由引用:",
+    ),
+    (
+        "provider_remove.confirm",
+        "⚠️  确定要删除 Provider '{name}' 吗？(y/N): ",
+    ),
+    ("provider_remove.success", "✅ Provider '{name}' 已删除"),
+    ("provider_show.models", "🤖 模型列表:"),
+    ("provider_show.transformer", "🔄 Transformer 配置:"),
+    (
+        "provider_edit.prompt_key",
+        "新 API Key (直接回车保持不变): ",
+    ),
+    (
+        "provider_edit.prompt_url",
+        "新 API URL (直接回车保持不变): ",
+    ),
+    (
+        "provider_edit.prompt_models",
+        "新模型列表 (用逗号分隔，直接回车保持不变): ",
+    ),
+    ("provider_edit.success", "✅ Provider '{name}' 更新成功！"),
+    (
+        "run_ccr.ccr_not_found",
+        "找不到 'ccr' 程序，请确保 claude-code-router 已安装并在 PATH 中",
+    ),
+    ("provider.model_count", "📊 模型数量: {count}"),
+    ("provider.type", "🏷️  类型: {kind}"),
+    ("provider.models", "🤖 模型: {models}"),
+    (
+        "provider.models_truncated",
+        "🤖 模型: {models} 等 {count} 个",
+    ),
+    (
+        "provider.transformer_file_failed",
+        "读取 transformer 文件 '{path}' 失败: {e}",
+    ),
+    ("provider_add.configure", "🔧 配置 {kind} 类型的Provider:"),
+    ("provider_add.default_url", "默认: {url}"),
+    ("provider_add.default_models", "默认模型: {models}"),
+    ("provider_add.kind", "🔗 类型: {kind}"),
+    ("provider_show.title", "🔗 Provider: {name}"),
+    ("provider_show.type", "🏷️  类型: {kind}"),
+    ("provider_show.model_count", "📊 模型数量: {count}"),
+    ("provider_edit.title", "✏️  编辑 Provider: {name}"),
+    ("provider_edit.current_key", "🔑 当前 API Key: {key}"),
+    ("provider_edit.current_url", "📍 当前 API URL: {url}"),
+    ("provider_edit.current_models", "🤖 当前模型: {models}"),
+    // commands.rs: 备份与密钥
+    ("backup_cmd.diff_title", "📊 对比 {old_id} → {new_id}"),
+    ("backup_cmd.identical", "✅ 两份配置内容相同"),
+    (
+        "backup_cmd.restore_identical",
+        "✅ 当前配置与该备份相同，无需恢复",
+    ),
+    (
+        "backup_cmd.restore_confirm",
+        "确定要恢复此备份吗？当前配置将先被备份 (y/N): ",
+    ),
+    ("backup_cmd.restore_cancelled", "❌ 取消恢复"),
+    ("backup_cmd.nothing_to_prune", "✅ 没有需要清理的备份"),
+    ("backup_cmd.prune_hint", "💡 去掉 --dry-run 以执行清理"),
+    ("backup_cmd.policy_updated", "✅ 备份保留策略已更新"),
+    ("backup_cmd.policy_title", "📋 备份保留策略："),
+    (
+        "backup_cmd.policy_hint",
+        "💡 使用 'ccode backup policy --keep-last <N> --keep-daily-days <M> --max-total-size <SIZE>' 修改",
+    ),
+    ("undo.confirm", "确定要撤销吗？(y/N): "),
+    ("undo.cancelled", "❌ 取消撤销"),
+    (
+        "secrets_cmd.provider_backend_ref",
+        "Provider 的 API Key 不支持直接使用 secret:// 引用",
+    ),
+    ("secrets_cmd.direct_title", "🔗 Direct 配置:"),
+    (
+        "secrets_cmd.migrate_hint",
+        "💡 使用 'ccode secrets migrate --to vault|keyring' 将明文密钥迁移到安全存储",
+    ),
+    (
+        "secrets_cmd.backups_plaintext",
+        "⚠️  仍有 {count} 个配置备份包含明文密钥（已使用 --keep-backups 保留），确认迁移无误后请手动清理:",
+    ),
+    (
+        "secrets_cmd.backups_purged",
+        "🧹 已删除 {count} 个包含明文密钥的配置备份",
+    ),
+    ("backup_cmd.empty", "📋 暂无{label}备份"),
+    ("backup_cmd.list_title", "📦 {label}备份 ({count} 个)："),
+    ("backup_cmd.dir", "📁 目录: {dir}"),
+    ("backup_cmd.latest_marker", " (最新)"),
+    ("backup_cmd.time", "📅 时间: {time}"),
+    ("backup_cmd.size", "📊 大小: {size}"),
+    (
+        "backup_cmd.list_hint",
+        "💡 使用 'ccode backup show <id>{flag}' 查看内容，'ccode backup restore <id>{flag}' 恢复备份",
+    ),
+    ("backup_cmd.show_title", "🗂️  备份: {id}"),
+    ("backup_cmd.file", "📁 文件: {path}"),
+    ("backup_cmd.diff_count", "共 {count} 处差异:"),
+    ("backup_cmd.restore_title", "♻️  恢复{label}备份: {id}"),
+    ("backup_cmd.restore_time", "📅 备份时间: {time}"),
+    (
+        "backup_cmd.restore_changes",
+        "📋 恢复后的变更 ({count} 处):",
+    ),
+    ("backup_cmd.restored", "✅ 已从备份 '{id}' 恢复{label}"),
+    (
+        "backup_cmd.prune_preview",
+        "🧹 将被清理的备份 ({count} 个)：",
+    ),
+    ("backup_cmd.pruned", "🧹 已清理的备份 ({count} 个)："),
+    ("backup_cmd.policy_keep_last", "🔢 保留最近: {count} 份"),
+    (
+        "backup_cmd.policy_keep_daily",
+        "📅 每日保留: 最近 {days} 天",
+    ),
+    ("backup_cmd.policy_max_size", "📊 总大小上限: {size}"),
+    ("backup_cmd.policy_unlimited", "📊 总大小上限: 不限制"),
+    ("undo.nothing", "没有可撤销的修改：ccode 配置暂无备份"),
+    ("undo.title", "↩️  撤销最近一次修改，恢复到 {time} 的配置"),
+    ("undo.changes", "📋 撤销后的变更 ({count} 处):"),
+    (
+        "undo.success",
+        "✅ 已撤销，备份 '{id}' 已恢复并从备份列表中移除",
+    ),
+    ("secrets_cmd.backend", "🔐 密钥存储后端: {backend}"),
+    ("secrets_cmd.storage_env", "🌐 环境变量 {value}"),
+    ("secrets_cmd.storage_external", "🔗 外部引用 {value}"),
+    ("secrets_cmd.storage_plaintext", "⚠️  明文"),
+    ("secrets_cmd.migrate_title", "🔐 迁移密钥: {from} → {to}"),
+    (
+        "secrets_cmd.migrated",
+        "✅ 已迁移 {migrated} 个密钥，当前后端: {backend}",
+    ),
+    (
+        "secrets_cmd.ccr_stop_hint",
+        "💡 Provider 密钥以环境变量形式传给 claude-code-router，如 ccr 服务已在运行，请先执行 'ccr stop'",
+    ),
+    (
+        "secrets_cmd.discard_failed",
+        "⚠️  清理密钥 '{key}' 失败: {e}",
+    ),
+    ("secrets_cmd.retire_failed", "⚠️  记录待清理的密钥失败: {e}"),
+    ("secrets_cmd.sweep_failed", "⚠️  清理已停用的密钥失败: {e}"),
+    // commands.rs: 编辑配置
+    ("profile.updated", "🕒 更新: {updated}"),
+    ("edit.unset", "(未设置)"),
+    ("edit.current_value", "{label}: {value}"),
+    (
+        "edit.prompt_optional",
+        "  新值 (直接回车保持不变，输入 - 清空): ",
+    ),
+    ("edit.prompt_keep", "  新值 (直接回车保持不变): "),
+    (
+        "group.ambiguous",
+        "Direct 组和 Router 组中都存在配置 '{name}'，请使用 --group 指定",
+    ),
+    (
+        "edit.route_flags_for_direct",
+        "Direct 配置不支持路由参数（--default、--think 等）",
+    ),
+    (
+        "edit.direct_flags_for_router",
+        "Router 配置不支持 --token、--base-url、--model 等 Direct 参数",
+    ),
+    ("edit.title", "✏️  编辑配置: {name}"),
+    ("edit.current_token", "🔑 当前 Token: {token}"),
+    ("edit.current_url", "📍 当前 URL: {url}"),
+    ("edit.field_description", "📝 描述"),
+    ("edit.field_threshold", "⚖️  长上下文阈值"),
+    ("edit.invalid_threshold", "⚠️  无效的阈值格式，保持不变"),
+    ("edit.success", "✅ 配置 '{name}' 更新成功！"),
+    ("edit_ccr.title", "✏️  编辑CCR配置 (Router Profile): {name}"),
+    (
+        "edit_ccr.success",
+        "✅ CCR配置 (Router Profile) '{name}' 更新成功！",
+    ),
+    (
+        "edit_ccr.applied",
+        "🔄 已同步到 claude-code-router（当前默认CCR配置）",
+    ),
+    // editor.rs: 外部编辑器
+    (
+        "editor.header",
+        "编辑 {label}，保存并退出编辑器后生效\n以 // 开头的行会被忽略，清空文件内容可放弃编辑",
+    ),
+    (
+        "editor.error_header",
+        "上次保存的内容未通过校验:\n{err}\n请修正后重新保存",
+    ),
+    (
+        "editor.invalid",
+        "⚠️  内容未通过校验，重新打开编辑器: {err}",
+    ),
+    ("editor.cancelled", "❌ 文件内容为空，已放弃编辑"),
+    ("editor.unchanged", "ℹ️  内容未修改，已放弃编辑"),
+    ("editor.launch_failed", "无法启动编辑器 '{editor}': {err}"),
+    (
+        "editor.exit_failed",
+        "编辑器 '{editor}' 异常退出 ({status})，已放弃编辑",
+    ),
+    (
+        "editor.name_changed",
+        "不能在此修改名称，名称必须保持为 '{name}'",
+    ),
+    // commands.rs: 重命名与复制
+    ("rename.invalid_name", "无效的名称 '{name}'"),
+    ("rename.success", "✅ 配置 '{old}' 已重命名为 '{new}'"),
+    ("copy.success", "✅ 已将配置 '{source}' 复制为 '{target}'"),
+    (
+        "provider_rename.title",
+        "📝 将 Provider '{old}' 重命名为 '{new}'",
+    ),
+    (
+        "provider_rename.no_references",
+        "   没有路由引用此 Provider",
+    ),
+    (
+        "provider_rename.references",
+        "🔗 以下 {count} 条路由引用将同步更新:",
+    ),
+    ("route_ref.profile_label", "RouterProfile '{name}'"),
+    ("route_ref.ccr_label", "claude-code-router Router"),
+    ("provider_rename.confirm", "❓ 确认重命名? (y/N): "),
+    ("provider_rename.cancelled", "❌ 取消重命名"),
+    (
+        "provider_rename.success",
+        "✅ Provider '{old}' 已重命名为 '{new}'",
+    ),
+    // commands.rs: 删除 Provider 时的引用检查
+    (
+        "provider_remove.blocked",
+        "Provider '{name}' 仍被路由引用，已阻止删除；请使用 --cascade 清空引用它的可选路由，或 --reassign <provider[,model]> 改用其他 Provider",
+    ),
+    (
+        "provider_remove.default_route",
+        "以下配置的默认路由使用了 Provider '{name}'，默认路由不能清空: {profiles}；请使用 --reassign 改用其他 Provider",
+    ),
+    (
+        "provider_remove.reassign_self",
+        "不能将路由改为使用正在删除的 Provider '{name}'",
+    ),
+    (
+        "provider_remove.unknown_model",
+        "Provider '{provider}' 中没有模型 '{model}'",
+    ),
+    ("provider_remove.choose", "❓ 如何处理这些路由?"),
+    ("provider_remove.option_block", "取消删除"),
+    ("provider_remove.option_cascade", "清空引用它的可选路由"),
+    (
+        "provider_remove.option_cascade_unavailable",
+        "清空引用它的可选路由（不可用：有默认路由引用它）",
+    ),
+    ("provider_remove.option_reassign", "改为使用其他 Provider"),
+    ("provider_remove.prompt_choice", "请选择 (1-3，默认 1): "),
+    ("provider_remove.available", "可用的 Provider: {providers}"),
+    (
+        "provider_remove.prompt_reassign",
+        "新的 Provider (provider 或 provider,model): ",
+    ),
+    (
+        "provider_remove.no_other_provider",
+        "没有其他可用的 Provider",
+    ),
+    ("provider_remove.cleared", "🧹 已清空 {count} 条路由"),
+    (
+        "provider_remove.reassigned",
+        "🔀 {count} 条路由已改为使用 '{provider}'",
+    ),
+    // doctor.rs: 健康检查
+    ("doctor.title", "🩺 ccode 健康检查"),
+    ("doctor.section.tools", "🧰 依赖工具"),
+    ("doctor.section.config", "📄 配置文件"),
+    ("doctor.section.defaults", "🎯 默认配置"),
+    ("doctor.section.routes", "🔀 路由"),
+    ("doctor.section.credentials", "🔑 令牌与密钥"),
+    ("doctor.section.permissions", "🔒 文件权限"),
+    ("doctor.status_pass", "[通过]"),
+    ("doctor.status_warn", "[警告]"),
+    ("doctor.status_fail", "[失败]"),
+    ("doctor.fix_hint", "💡 建议: {fix}"),
+    (
+        "doctor.summary",
+        "📊 {pass} 项通过，{warn} 项警告，{fail} 项失败",
+    ),
+    ("doctor.failed", "健康检查发现 {count} 项失败"),
+    ("doctor.tool_found", "{tool}: {version}"),
+    ("doctor.tool_missing", "在 PATH 中找不到 {tool}"),
+    ("doctor.ccr_not_needed", "ccr: 未安装（仅 Router 模式需要）"),
+    ("doctor.ccode_config_ok", "ccode 配置可以正常解析: {path}"),
+    ("doctor.ccode_config_missing", "尚未创建 ccode 配置文件"),
+    (
+        "doctor.ccode_config_invalid",
+        "ccode 配置文件 {path} 无法解析: {err}",
+    ),
+    (
+        "doctor.ccr_config_unavailable",
+        "无法读取 claude-code-router 配置: {err}",
+    ),
+    (
+        "doctor.ccr_config_ok",
+        "claude-code-router 配置可以正常解析: {path}",
+    ),
+    (
+        "doctor.ccr_config_invalid",
+        "claude-code-router 配置 {path} 无效: {err}",
+    ),
+    (
+        "doctor.ccr_config_missing",
+        "存在 RouterProfile，但 claude-code-router 配置文件不存在",
+    ),
+    (
+        "doctor.ccr_config_not_needed",
+        "claude-code-router 配置: 未创建（仅 Router 模式需要）",
+    ),
+    ("doctor.default_ok", "{group} 组默认配置: {name}"),
+    (
+        "doctor.default_missing",
+        "{group} 组默认配置 '{name}' 不存在",
+    ),
+    ("doctor.default_unset", "{group} 组未设置默认配置"),
+    (
+        "doctor.ccr_router_ok",
+        "claude-code-router Router 节点的路由引用均有效",
+    ),
+    (
+        "doctor.profile_routes_ok",
+        "{count} 个 RouterProfile 的路由均有效",
+    ),
+    (
+        "doctor.route_missing_provider",
+        "{label} {route}: 引用了不存在的 Provider '{provider}'",
+    ),
+    (
+        "doctor.route_unknown_model",
+        "{label} {route}: 模型 '{model}' 不在 Provider '{provider}' 的模型列表中",
+    ),
+    ("doctor.direct_label", "Direct 配置 '{name}'"),
+    ("doctor.provider_label", "Provider '{name}'"),
+    (
+        "doctor.provider_env_unset",
+        "{label}: API Key 引用的环境变量 {var} 未设置",
+    ),
+    ("doctor.credentials_ok", "{count} 个令牌与密钥未发现问题"),
+    ("doctor.secret_invalid", "{label}: {err}"),
+    ("doctor.secret_env_unset", "{label}: 环境变量 {var} 未设置"),
+    ("doctor.secret_file_missing", "{label}: 文件 {path} 不存在"),
+    (
+        "doctor.secret_malformed",
+        "{label}: 令牌格式可疑（{reason}）",
+    ),
+    ("doctor.malformed.empty", "为空"),
+    ("doctor.malformed.bearer", "包含 Bearer 前缀"),
+    ("doctor.malformed.whitespace", "包含空白字符"),
+    ("doctor.malformed.quotes", "包含引号或尖括号"),
+    ("doctor.malformed.non_ascii", "包含非 ASCII 字符"),
+    ("doctor.malformed.too_short", "长度过短"),
+    ("doctor.permissions_ok", "{path} 权限为 {mode}"),
+    (
+        "doctor.permissions_open",
+        "{path} 权限为 {mode}，其他用户可以访问",
+    ),
+    (
+        "doctor.backup_permissions_ok",
+        "{path} 及其中的 {count} 个备份仅当前用户可访问",
+    ),
+    (
+        "doctor.backup_permissions_open",
+        "{path} 中有 {count} 项其他用户可以访问，备份包含配置文件的明文副本",
+    ),
+    (
+        "doctor.fix.install_claude",
+        "npm install -g @anthropic-ai/claude-code",
+    ),
+    (
+        "doctor.fix.install_ccr",
+        "npm install -g @musistudio/claude-code-router",
+    ),
+    (
+        "doctor.fix.add_profile",
+        "使用 'ccode add <name>' 添加第一个配置",
+    ),
+    (
+        "doctor.fix.restore_ccode",
+        "修正文件内容，或使用 'ccode backup --ccode restore latest' 恢复备份",
+    ),
+    (
+        "doctor.fix.restore_ccr",
+        "修正文件内容，或使用 'ccode backup restore latest' 恢复备份",
+    ),
+    (
+        "doctor.fix.provider_add",
+        "使用 'ccode provider add <name>' 添加 Provider",
+    ),
+    ("doctor.fix.set_default", "{command} {name}"),
+    (
+        "doctor.fix.ccr_router",
+        "添加缺失的 Provider，或使用 'ccode use-ccr <name>' 重新应用 RouterProfile",
+    ),
+    ("doctor.fix.edit_profile", "ccode edit {name}"),
+    ("doctor.fix.provider_edit", "ccode provider edit {provider}"),
+    ("doctor.fix.export_env", "export {var}=..."),
+    ("doctor.fix.secret_file", "创建文件 {path}，或修改令牌引用"),
+    ("doctor.fix.chmod", "chmod 600 {path}"),
+    ("doctor.fix.chmod_recursive", "chmod -R go-rwx {path}"),
+    // commands.rs: 配置环境变量
+    (
+        "env.invalid_assignment",
+        "环境变量赋值 '{assignment}' 格式无效，应为 KEY=VALUE",
+    ),
+    (
+        "env.invalid_name",
+        "环境变量名 '{name}' 无效，只能包含字母、数字和下划线，且不能以数字开头",
+    ),
+    (
+        "env.reserved_name",
+        "环境变量 '{name}' 由 ccode 管理，不能在配置的 env 中设置",
+    ),
+    (
+        "env.process_name",
+        "环境变量 '{name}' 决定程序查找或运行环境，不能在配置的 env 中设置",
+    ),
+    ("env.set", "✅ 已为配置 '{name}' 设置环境变量 {key}"),
+    ("env.unset", "✅ 已从配置 '{name}' 删除环境变量 {key}"),
+    ("env.not_set", "⚠️  配置 '{name}' 未设置环境变量 {key}"),
+    ("env.empty", "📋 配置 '{name}' 未设置额外的环境变量"),
+    (
+        "env.shell_ccr_restart",
+        "⚠️  Router Profile 已应用到 ccr 配置，运行中的 ccr 服务需要重启后才会使用新路由（ccr restart）",
+    ),
+    (
+        "env.shell_nothing_saved",
+        "⚠️  当前环境中没有 {var}，没有需要恢复的变量（请在执行过 ccode env 的同一个 shell 中使用 --unset）",
+    ),
+    (
+        "env.shell_secret_unsaved",
+        "⚠️  以下变量原来已设置，但为避免泄露没有保存密钥的原值，已清除，如有需要请重新设置: {names}",
+    ),
+    ("env.shell_saved_invalid", "{var} 的内容无效: {e}"),
+];
+
+/// 中文帮助信息直接使用 main.rs 中的文档注释
+pub(super) const HELP: &[(&str, &str)] = &[];
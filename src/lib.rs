@@ -1,7 +1,17 @@
+pub mod backup;
 pub mod ccr_config;
 pub mod commands;
 pub mod config;
+pub mod console;
+pub mod doctor;
+pub mod editor;
 pub mod error;
+pub mod fs_utils;
+pub mod i18n;
+pub mod launch;
+pub mod output;
+pub mod secrets;
+pub mod shell;
 
 pub use config::{Config, Profile};
 pub use error::{AppError, AppResult};
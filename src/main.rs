@@ -1,19 +1,68 @@
+mod backup;
 mod ccr_config;
 mod commands;
 mod config;
+mod console;
+mod doctor;
+mod editor;
 mod error;
+mod fs_utils;
+mod i18n;
+mod launch;
+mod output;
+mod secrets;
+mod shell;
 
-use clap::{Parser, Subcommand};
-use error::AppResult;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+use config::RouteValidation;
+use console::Console;
+use error::{AppError, AppResult};
+use i18n::Lang;
+use launch::{EnvPolicy, LaunchMode, LaunchOptions};
+use output::{OutputFormat, OutputOptions};
+use shell::Shell;
+use std::ffi::OsString;
+use std::path::PathBuf;
 
 /// ccode - Claude Code 环境切换工具
 ///
 /// 一个用于快速切换不同API服务配置并启动claude程序的命令行工具
+///
+/// 输出、语言等选项只能写在子命令之前（`ccode -v run work`），
+/// 子命令之后的同名参数（如 `ccode run work --verbose`）原样透传给claude。
 #[derive(Parser)]
 #[command(name = "ccode")]
 #[command(about = "Claude Code 环境切换工具", long_about = None)]
 #[command(version = "0.2.0")]
 struct Cli {
+    /// 输出格式（列表与详情命令）
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    output: OutputFormat,
+
+    /// 在结构化输出中显示完整密钥（默认遮蔽）
+    #[arg(long)]
+    show_secrets: bool,
+
+    /// 只输出结果数据、警告和错误
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// 额外输出备份、同步等过程信息
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// 去掉输出中的 emoji 前缀（设置 NO_COLOR 环境变量效果相同）
+    #[arg(long)]
+    no_emoji: bool,
+
+    /// 界面语言（默认读取 CCODE_LANG 或系统 locale）
+    #[arg(long, value_enum)]
+    lang: Option<Lang>,
+
+    /// 路由模型校验模式（默认读取 CCODE_ROUTE_VALIDATION，未设置时为 warn）
+    #[arg(long, value_enum, value_name = "MODE")]
+    route_validation: Option<RouteValidation>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -33,6 +82,30 @@ enum Commands {
         /// 指定配置组 (direct|ccr)
         #[arg(long)]
         group: Option<String>,
+        /// ANTHROPIC_AUTH_TOKEN
+        #[arg(long, conflicts_with_all = ["token_from_env", "token_from_file"])]
+        token: Option<String>,
+        /// 从指定环境变量读取 ANTHROPIC_AUTH_TOKEN
+        #[arg(long, value_name = "VAR", conflicts_with = "token_from_file")]
+        token_from_env: Option<String>,
+        /// 从指定文件读取 ANTHROPIC_AUTH_TOKEN
+        #[arg(long, value_name = "PATH")]
+        token_from_file: Option<PathBuf>,
+        /// ANTHROPIC_BASE_URL
+        #[arg(long)]
+        base_url: Option<String>,
+        /// ANTHROPIC_MODEL
+        #[arg(long)]
+        model: Option<String>,
+        /// ANTHROPIC_SMALL_FAST_MODEL
+        #[arg(long)]
+        small_fast_model: Option<String>,
+        /// 配置描述
+        #[arg(long)]
+        description: Option<String>,
+        /// 禁止交互式提示，缺少必需参数时直接报错
+        #[arg(long)]
+        non_interactive: bool,
     },
     /// 设置默认配置
     Use {
@@ -49,10 +122,48 @@ enum Commands {
         /// 指定配置组 (direct|ccr)
         #[arg(long)]
         group: Option<String>,
+        /// 等待claude退出并打印退出状态，而不是以claude替换当前进程（仅影响Unix）
+        #[arg(long)]
+        no_exec: bool,
+        /// 原样继承当前环境中的 Anthropic/Claude Code 变量（默认去掉配置未设置的）
+        #[arg(long, conflicts_with = "clean_env")]
+        inherit_env: bool,
+        /// 只向claude传递基础变量（PATH、HOME、终端与语言环境等）和配置设置的变量
+        #[arg(long)]
+        clean_env: bool,
+        /// 显示claude将使用的环境变量（密钥已遮蔽），不启动
+        #[arg(long)]
+        show_env: bool,
         /// 透传给claude的参数 (仅Direct模式支持，例如: run myprofile --version 或 run myprofile -- --help)
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         claude_args: Vec<String>,
     },
+    /// 在配置的环境变量下运行任意程序
+    Exec {
+        /// 配置名称
+        name: String,
+        /// 指定配置组 (direct|ccr)，不指定则按名称自动识别
+        #[arg(long)]
+        group: Option<String>,
+        /// 等待程序退出并打印退出状态，而不是以程序替换当前进程（仅影响Unix）
+        #[arg(long)]
+        no_exec: bool,
+        /// 原样继承当前环境中的 Anthropic/Claude Code 变量（默认去掉配置未设置的）
+        #[arg(long, conflicts_with = "clean_env")]
+        inherit_env: bool,
+        /// 只向程序传递基础变量（PATH、HOME、终端与语言环境等）和配置设置的变量
+        #[arg(long)]
+        clean_env: bool,
+        /// 显示程序将使用的环境变量（密钥已遮蔽），不启动
+        #[arg(long)]
+        show_env: bool,
+        /// 即使路由未变化也重启 ccr 服务（例如修改 Provider 之后，仅 RouterProfile）
+        #[arg(long, conflicts_with = "show_env")]
+        restart: bool,
+        /// 要运行的程序及其参数（写在 -- 之后）
+        #[arg(required = true, last = true, value_name = "COMMAND")]
+        command: Vec<String>,
+    },
     /// 删除配置
     Remove {
         /// 配置名称
@@ -61,6 +172,83 @@ enum Commands {
         #[arg(long)]
         group: Option<String>,
     },
+    /// 编辑配置（未指定字段参数时进入交互式编辑）
+    Edit {
+        /// 配置名称
+        name: String,
+        /// 指定配置组 (direct|ccr)，不指定则按名称自动识别
+        #[arg(long)]
+        group: Option<String>,
+        /// ANTHROPIC_AUTH_TOKEN
+        #[arg(long, conflicts_with_all = ["token_from_env", "token_from_file"])]
+        token: Option<String>,
+        /// 从指定环境变量读取 ANTHROPIC_AUTH_TOKEN
+        #[arg(long, value_name = "VAR", conflicts_with = "token_from_file")]
+        token_from_env: Option<String>,
+        /// 从指定文件读取 ANTHROPIC_AUTH_TOKEN
+        #[arg(long, value_name = "PATH")]
+        token_from_file: Option<PathBuf>,
+        /// ANTHROPIC_BASE_URL
+        #[arg(long)]
+        base_url: Option<String>,
+        /// ANTHROPIC_MODEL（空字符串表示清空）
+        #[arg(long)]
+        model: Option<String>,
+        /// ANTHROPIC_SMALL_FAST_MODEL（空字符串表示清空）
+        #[arg(long)]
+        small_fast_model: Option<String>,
+        /// 默认路由 (provider,model)
+        #[arg(long)]
+        default: Option<String>,
+        /// 后台任务路由 (provider,model)，空字符串表示清空
+        #[arg(long)]
+        background: Option<String>,
+        /// 思考任务路由 (provider,model)，空字符串表示清空
+        #[arg(long)]
+        think: Option<String>,
+        /// 长上下文路由 (provider,model)，空字符串表示清空
+        #[arg(long)]
+        long_context: Option<String>,
+        /// 长上下文阈值
+        #[arg(long)]
+        long_context_threshold: Option<u32>,
+        /// 网络搜索路由 (provider,model)，空字符串表示清空
+        #[arg(long)]
+        web_search: Option<String>,
+        /// 从JSON文件读取Router配置（命令行参数优先）
+        #[arg(long, value_name = "PATH")]
+        from_file: Option<PathBuf>,
+        /// 配置描述（空字符串表示清空）
+        #[arg(long)]
+        description: Option<String>,
+        /// 在 $EDITOR 中直接编辑配置的 JSON
+        #[arg(long, conflicts_with_all = [
+            "token", "token_from_env", "token_from_file", "base_url", "model",
+            "small_fast_model", "default", "background", "think", "long_context",
+            "long_context_threshold", "web_search", "from_file", "description",
+        ])]
+        raw: bool,
+    },
+    /// 重命名配置
+    Rename {
+        /// 原配置名称
+        old: String,
+        /// 新配置名称
+        new: String,
+        /// 指定配置组 (direct|ccr)，不指定则按名称自动识别
+        #[arg(long)]
+        group: Option<String>,
+    },
+    /// 复制配置
+    Copy {
+        /// 源配置名称
+        source: String,
+        /// 新配置名称
+        target: String,
+        /// 指定配置组 (direct|ccr)，不指定则按名称自动识别
+        #[arg(long)]
+        group: Option<String>,
+    },
 
     // CCR快捷命令
     /// 添加CCR配置
@@ -68,12 +256,48 @@ enum Commands {
     AddCcr {
         /// 配置名称
         name: String,
+        /// 默认路由 (provider,model)
+        #[arg(long)]
+        default: Option<String>,
+        /// 后台任务路由 (provider,model)
+        #[arg(long)]
+        background: Option<String>,
+        /// 思考任务路由 (provider,model)
+        #[arg(long)]
+        think: Option<String>,
+        /// 长上下文路由 (provider,model)
+        #[arg(long)]
+        long_context: Option<String>,
+        /// 长上下文阈值
+        #[arg(long)]
+        long_context_threshold: Option<u32>,
+        /// 网络搜索路由 (provider,model)
+        #[arg(long)]
+        web_search: Option<String>,
+        /// 配置描述
+        #[arg(long)]
+        description: Option<String>,
+        /// 从JSON文件读取Router配置（命令行参数优先）
+        #[arg(long, value_name = "PATH")]
+        from_file: Option<PathBuf>,
     },
     /// 启动CCR配置
     #[command(name = "run-ccr")]
     RunCcr {
         /// 可选的配置名称，不指定则使用默认CCR配置
         name: Option<String>,
+        /// 等待ccr退出并打印退出状态，而不是以ccr替换当前进程（仅影响Unix）
+        #[arg(long)]
+        no_exec: bool,
+        /// 原样继承当前环境中的 Anthropic/Claude Code 变量（默认去掉配置未设置的）
+        #[arg(long, conflicts_with = "clean_env")]
+        inherit_env: bool,
+        /// 只向ccr传递基础变量（PATH、HOME、终端与语言环境等）和配置设置的变量
+        #[arg(long)]
+        clean_env: bool,
+        /// 显示ccr将使用的环境变量（密钥已遮蔽），不启动
+        #[arg(long)]
+        show_env: bool,
     },
     /// 列出CCR配置
     #[command(name = "list-ccr")]
@@ -97,6 +321,49 @@ enum Commands {
         #[command(subcommand)]
         provider_cmd: ProviderCommands,
     },
+
+    // 备份管理
+    /// 配置备份管理（默认管理CCR配置备份）
+    Backup {
+        /// 管理ccode自身配置的备份
+        #[arg(long, global = true)]
+        ccode: bool,
+        #[command(subcommand)]
+        backup_cmd: BackupCommands,
+    },
+    /// 管理配置的环境变量，或输出在当前shell中设置Direct配置环境变量的语句
+    #[command(args_conflicts_with_subcommands = true)]
+    Env {
+        #[command(subcommand)]
+        env_cmd: Option<EnvCommands>,
+        /// 配置名称（Direct或Router），不指定则使用默认的Direct配置
+        name: Option<String>,
+        /// 指定配置组 (direct|ccr)，不指定则按名称自动识别
+        #[arg(long, requires = "name")]
+        group: Option<String>,
+        /// 输出语句的shell类型，不指定则根据$SHELL判断
+        #[arg(long, value_enum)]
+        shell: Option<Shell>,
+        /// 输出恢复上次ccode env修改前的变量值的语句
+        #[arg(long = "unset")]
+        revert: bool,
+        /// 不输出清除当前环境中其他Anthropic/Claude Code变量的语句
+        #[arg(long)]
+        inherit_env: bool,
+    },
+    /// 密钥存储管理
+    Secrets {
+        #[command(subcommand)]
+        secrets_cmd: SecretsCommands,
+    },
+    /// 撤销对ccode配置的最近一次修改
+    Undo {
+        /// 跳过确认提示
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// 检查运行环境与配置，给出修复建议
+    Doctor,
 }
 
 #[derive(Subcommand)]
@@ -107,53 +374,478 @@ enum ProviderCommands {
     Add {
         /// Provider名称
         name: String,
+        /// Provider类型 (openai|openrouter|deepseek|gemini|qwen|custom)
+        #[arg(long = "type", value_name = "TYPE")]
+        provider_type: Option<String>,
+        /// API Key
+        #[arg(long, conflicts_with = "api_key_env")]
+        api_key: Option<String>,
+        /// 从指定环境变量读取 API Key
+        #[arg(long, value_name = "VAR")]
+        api_key_env: Option<String>,
+        /// API URL，不指定则使用该类型的默认URL
+        #[arg(long)]
+        url: Option<String>,
+        /// 模型列表（用逗号分隔），不指定则使用该类型的默认模型
+        #[arg(long)]
+        models: Option<String>,
+        /// 从JSON文件读取 transformer 配置，覆盖自动生成的配置
+        #[arg(long, value_name = "PATH")]
+        transformer_json: Option<PathBuf>,
+        /// 仅打印生成的 Provider JSON，不写入配置文件
+        #[arg(long)]
+        dry_run: bool,
+        /// 禁止交互式提示，缺少必需参数时直接报错
+        #[arg(long)]
+        non_interactive: bool,
     },
-    /// 删除Provider
+    /// 删除Provider（仍被路由引用时需选择处理方式）
     Remove {
         /// Provider名称
         name: String,
+        /// 跳过确认提示；仍有路由引用时需配合 --cascade 或 --reassign，否则阻止删除
+        #[arg(long)]
+        force: bool,
+        /// 清空引用该Provider的可选路由
+        #[arg(long, conflicts_with = "reassign")]
+        cascade: bool,
+        /// 将引用该Provider的路由改为使用其他Provider (provider 或 provider,model)
+        #[arg(long, value_name = "PROVIDER[,MODEL]")]
+        reassign: Option<String>,
     },
     /// 显示Provider详情
     Show {
         /// Provider名称
         name: String,
     },
+    /// 重命名Provider，并同步更新所有路由引用
+    Rename {
+        /// 原Provider名称
+        old: String,
+        /// 新Provider名称
+        new: String,
+        /// 跳过确认提示
+        #[arg(short, long)]
+        yes: bool,
+    },
     /// 编辑Provider
     Edit {
         /// Provider名称
         name: String,
+        /// 在 $EDITOR 中直接编辑 Provider 的 JSON
+        #[arg(long)]
+        raw: bool,
     },
 }
 
-fn main() -> AppResult<()> {
-    let cli = Cli::parse();
+#[derive(Subcommand)]
+enum EnvCommands {
+    /// 列出配置的环境变量
+    List {
+        /// 配置名称
+        name: String,
+        /// 指定配置组 (direct|ccr)，不指定则按名称自动识别
+        #[arg(long)]
+        group: Option<String>,
+    },
+    /// 设置环境变量
+    Set {
+        /// 配置名称
+        name: String,
+        /// 环境变量赋值
+        #[arg(required = true, value_name = "KEY=VALUE")]
+        vars: Vec<String>,
+        /// 指定配置组 (direct|ccr)，不指定则按名称自动识别
+        #[arg(long)]
+        group: Option<String>,
+    },
+    /// 删除环境变量
+    Unset {
+        /// 配置名称
+        name: String,
+        /// 环境变量名称
+        #[arg(required = true, value_name = "KEY")]
+        keys: Vec<String>,
+        /// 指定配置组 (direct|ccr)，不指定则按名称自动识别
+        #[arg(long)]
+        group: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum SecretsCommands {
+    /// 显示密钥存储后端与各配置的密钥存储方式
+    Status,
+    /// 将所有密钥迁移到指定的存储后端
+    Migrate {
+        /// 目标后端: plaintext, vault, keyring
+        #[arg(long)]
+        to: String,
+        /// 保留已有的包含明文密钥的配置备份（默认在迁移到安全后端后删除）
+        #[arg(long)]
+        keep_backups: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum BackupCommands {
+    /// 列出所有备份
+    List,
+    /// 显示备份内容
+    Show {
+        /// 备份ID（或 latest）
+        id: String,
+    },
+    /// 对比备份差异
+    Diff {
+        /// 备份ID（或 latest）
+        id: String,
+        /// 对比目标：另一个备份ID或 current（默认）
+        other: Option<String>,
+    },
+    /// 从备份恢复配置
+    Restore {
+        /// 备份ID（或 latest）
+        id: String,
+        /// 跳过确认提示
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// 按保留策略清理过期备份
+    Prune {
+        /// 仅显示将被清理的备份
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// 查看或修改备份保留策略
+    Policy {
+        /// 保留最近的备份份数
+        #[arg(long)]
+        keep_last: Option<usize>,
+        /// 保留最近多少天内每天最新的一份备份
+        #[arg(long)]
+        keep_daily_days: Option<u32>,
+        /// 备份总大小上限，如 10MB（none 表示不限制）
+        #[arg(long)]
+        max_total_size: Option<String>,
+    },
+}
+
+fn main() {
+    let args: Vec<OsString> = std::env::args_os().collect();
+    // 解析命令行之前确定语言，使帮助与参数错误信息也能本地化
+    i18n::set_lang(Lang::detect(i18n::lang_from_args(&args)));
+
+    let matches = i18n::localize_command(Cli::command()).get_matches_from(args);
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    console::set_console(Console::from_args(cli.quiet, cli.verbose, cli.no_emoji));
+    config::set_route_validation(RouteValidation::detect(cli.route_validation));
+
+    if let Err(e) = run(cli) {
+        // 子程序的退出状态已经提示过，只传递退出码
+        if let AppError::ChildExit(code) = e {
+            std::process::exit(code);
+        }
+        warn!("{}", t!("error.prefix", err = e));
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> AppResult<()> {
+    let out = OutputOptions {
+        format: cli.output,
+        show_secrets: cli.show_secrets,
+    };
 
     match cli.command {
         // 统一接口命令（支持--group参数）
-        Commands::List { group } => commands::cmd_list_with_group(group),
-        Commands::Add { name, group } => commands::cmd_add_with_group(name, group),
+        Commands::List { group } => commands::cmd_list_with_group(group, out),
+        Commands::Add {
+            name,
+            group,
+            token,
+            token_from_env,
+            token_from_file,
+            base_url,
+            model,
+            small_fast_model,
+            description,
+            non_interactive,
+        } => commands::cmd_add_with_group(
+            name,
+            group,
+            commands::DirectProfileArgs {
+                token,
+                token_from_env,
+                token_from_file,
+                base_url,
+                model,
+                small_fast_model,
+                description,
+                non_interactive,
+            },
+        ),
         Commands::Use { name, group } => commands::cmd_use_with_group(name, group),
         Commands::Run {
             name,
             group,
+            no_exec,
+            inherit_env,
+            clean_env,
+            show_env,
             claude_args,
-        } => commands::cmd_run_with_group(name, group, claude_args),
+        } => commands::cmd_run_with_group(
+            name,
+            group,
+            claude_args,
+            LaunchOptions {
+                mode: LaunchMode::from_no_exec(no_exec),
+                env: EnvPolicy::from_flags(inherit_env, clean_env),
+                show_env,
+            },
+        ),
+        Commands::Exec {
+            name,
+            group,
+            no_exec,
+            inherit_env,
+            clean_env,
+            show_env,
+            restart,
+            command,
+        } => commands::cmd_exec(
+            name,
+            group,
+            command,
+            restart,
+            LaunchOptions {
+                mode: LaunchMode::from_no_exec(no_exec),
+                env: EnvPolicy::from_flags(inherit_env, clean_env),
+                show_env,
+            },
+        ),
         Commands::Remove { name, group } => commands::cmd_remove_with_group(name, group),
+        Commands::Edit {
+            name,
+            group,
+            token,
+            token_from_env,
+            token_from_file,
+            base_url,
+            model,
+            small_fast_model,
+            default,
+            background,
+            think,
+            long_context,
+            long_context_threshold,
+            web_search,
+            from_file,
+            description,
+            raw,
+        } => commands::cmd_edit_with_group(
+            name,
+            group,
+            commands::DirectProfileArgs {
+                token,
+                token_from_env,
+                token_from_file,
+                base_url,
+                model,
+                small_fast_model,
+                description: description.clone(),
+                non_interactive: false,
+            },
+            commands::RouterProfileArgs {
+                default,
+                background,
+                think,
+                long_context,
+                long_context_threshold,
+                web_search,
+                description,
+                from_file,
+            },
+            raw,
+            out,
+        ),
+        Commands::Rename { old, new, group } => commands::cmd_rename_with_group(old, new, group),
+        Commands::Copy {
+            source,
+            target,
+            group,
+        } => commands::cmd_copy_with_group(source, target, group),
 
         // CCR快捷命令
-        Commands::AddCcr { name } => commands::cmd_add_ccr(name),
-        Commands::RunCcr { name } => commands::cmd_run_ccr(name),
-        Commands::ListCcr => commands::cmd_list_ccr(),
+        Commands::AddCcr {
+            name,
+            default,
+            background,
+            think,
+            long_context,
+            long_context_threshold,
+            web_search,
+            description,
+            from_file,
+        } => commands::cmd_add_ccr(
+            name,
+            commands::RouterProfileArgs {
+                default,
+                background,
+                think,
+                long_context,
+                long_context_threshold,
+                web_search,
+                description,
+                from_file,
+            },
+        ),
+        Commands::RunCcr {
+            name,
+            no_exec,
+            inherit_env,
+            clean_env,
+            show_env,
+        } => commands::cmd_run_ccr(
+            name,
+            LaunchOptions {
+                mode: LaunchMode::from_no_exec(no_exec),
+                env: EnvPolicy::from_flags(inherit_env, clean_env),
+                show_env,
+            },
+        ),
+        Commands::ListCcr => commands::cmd_list_ccr(out),
         Commands::UseCcr { name } => commands::cmd_use_ccr(name),
         Commands::RemoveCcr { name } => commands::cmd_remove_ccr(name),
 
         // Provider管理
         Commands::Provider { provider_cmd } => match provider_cmd {
-            ProviderCommands::List => commands::cmd_provider_list(),
-            ProviderCommands::Add { name } => commands::cmd_provider_add(name),
-            ProviderCommands::Remove { name } => commands::cmd_provider_remove(name),
-            ProviderCommands::Show { name } => commands::cmd_provider_show(name),
-            ProviderCommands::Edit { name } => commands::cmd_provider_edit(name),
+            ProviderCommands::List => commands::cmd_provider_list(out),
+            ProviderCommands::Add {
+                name,
+                provider_type,
+                api_key,
+                api_key_env,
+                url,
+                models,
+                transformer_json,
+                dry_run,
+                non_interactive,
+            } => commands::cmd_provider_add(
+                name,
+                commands::ProviderArgs {
+                    provider_type,
+                    api_key,
+                    api_key_env,
+                    url,
+                    models,
+                    transformer_json,
+                    dry_run,
+                    non_interactive,
+                },
+            ),
+            ProviderCommands::Remove {
+                name,
+                force,
+                cascade,
+                reassign,
+            } => commands::cmd_provider_remove(name, force, cascade, reassign),
+            ProviderCommands::Show { name } => commands::cmd_provider_show(name, out),
+            ProviderCommands::Rename { old, new, yes } => {
+                commands::cmd_provider_rename(old, new, yes)
+            }
+            ProviderCommands::Edit { name, raw } => commands::cmd_provider_edit(name, raw, out),
         },
+
+        // 备份管理
+        Commands::Backup { ccode, backup_cmd } => match backup_cmd {
+            BackupCommands::List => commands::cmd_backup_list(ccode),
+            BackupCommands::Show { id } => commands::cmd_backup_show(id, ccode),
+            BackupCommands::Diff { id, other } => commands::cmd_backup_diff(id, other, ccode),
+            BackupCommands::Restore { id, yes } => commands::cmd_backup_restore(id, yes, ccode),
+            BackupCommands::Prune { dry_run } => commands::cmd_backup_prune(dry_run, ccode),
+            BackupCommands::Policy {
+                keep_last,
+                keep_daily_days,
+                max_total_size,
+            } => commands::cmd_backup_policy(keep_last, keep_daily_days, max_total_size),
+        },
+        Commands::Secrets { secrets_cmd } => match secrets_cmd {
+            SecretsCommands::Status => commands::cmd_secrets_status(),
+            SecretsCommands::Migrate { to, keep_backups } => {
+                commands::cmd_secrets_migrate(to, keep_backups)
+            }
+        },
+        Commands::Undo { yes } => commands::cmd_undo(yes),
+        Commands::Env {
+            env_cmd,
+            name,
+            group,
+            shell,
+            revert,
+            inherit_env,
+        } => match env_cmd {
+            Some(EnvCommands::List { name, group }) => commands::cmd_env_list(name, group, out),
+            Some(EnvCommands::Set { name, vars, group }) => {
+                commands::cmd_env_set(name, group, vars)
+            }
+            Some(EnvCommands::Unset { name, keys, group }) => {
+                commands::cmd_env_unset(name, group, keys)
+            }
+            None => commands::cmd_env_shell(
+                name,
+                group,
+                shell,
+                revert,
+                EnvPolicy::from_flags(inherit_env, false),
+            ),
+        },
+        Commands::Doctor => commands::cmd_doctor(out),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    /// 收集命令树中所有需要帮助文本的键
+    fn help_keys(cmd: &Command, path: &str, keys: &mut Vec<String>) {
+        keys.push(path.to_string());
+        for arg in cmd.get_arguments() {
+            let id = arg.get_id().as_str();
+            if id != "help" && id != "version" {
+                keys.push(format!("{path}.{id}"));
+            }
+        }
+        for sub in cmd.get_subcommands() {
+            help_keys(sub, &format!("{path}.{}", sub.get_name()), keys);
+        }
+    }
+
+    #[test]
+    fn test_root_options_not_taken_from_claude_args() {
+        let cli = Cli::try_parse_from(["ccode", "-v", "run", "work", "--verbose", "-q"]).unwrap();
+        assert!(cli.verbose);
+        assert!(!cli.quiet);
+        match cli.command {
+            Commands::Run {
+                name, claude_args, ..
+            } => {
+                assert_eq!(name.as_deref(), Some("work"));
+                assert_eq!(claude_args, ["--verbose", "-q"]);
+            }
+            _ => panic!("应解析为 run 命令"),
+        }
+    }
+
+    #[test]
+    fn test_english_help_complete() {
+        let mut keys = Vec::new();
+        help_keys(&Cli::command(), "cli", &mut keys);
+        for key in keys {
+            assert!(i18n::has_help(Lang::En, &key), "英文帮助缺少 {key}");
+        }
     }
 }
@@ -0,0 +1,846 @@
+use crate::error::{AppError, AppResult};
+use crate::fs_utils::{atomic_write, atomic_write_with};
+use crate::t;
+use age::secrecy::SecretString;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cell::{OnceCell, RefCell};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+/// 密钥引用前缀，例如 `secret://ccode/myapi`
+pub const SECRET_REF_PREFIX: &str = "secret://ccode/";
+
+/// 密钥库密码的环境变量
+pub const VAULT_PASSPHRASE_ENV: &str = "CCODE_VAULT_PASSPHRASE";
+
+/// 密钥存储后端类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SecretBackendKind {
+    /// 明文保存在配置文件中（默认）
+    #[default]
+    Plaintext,
+    /// 使用密码加密的本地密钥库文件（age 格式）
+    Vault,
+    /// 系统密钥环（Linux Secret Service）
+    Keyring,
+}
+
+impl SecretBackendKind {
+    /// 获取后端显示名称
+    pub fn display_name(&self) -> String {
+        match self {
+            SecretBackendKind::Plaintext => t!("secrets.backend.plaintext"),
+            SecretBackendKind::Vault => t!("secrets.backend.vault"),
+            SecretBackendKind::Keyring => t!("secrets.backend.keyring"),
+        }
+    }
+}
+
+impl fmt::Display for SecretBackendKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SecretBackendKind::Plaintext => "plaintext",
+            SecretBackendKind::Vault => "vault",
+            SecretBackendKind::Keyring => "keyring",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for SecretBackendKind {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "plaintext" => Ok(SecretBackendKind::Plaintext),
+            "vault" => Ok(SecretBackendKind::Vault),
+            "keyring" => Ok(SecretBackendKind::Keyring),
+            other => Err(AppError::InvalidConfig(t!(
+                "secrets.unknown_backend",
+                other
+            ))),
+        }
+    }
+}
+
+/// 密钥存储后端
+pub trait SecretBackend {
+    /// 后端类型
+    fn kind(&self) -> SecretBackendKind;
+
+    /// 保存密钥，返回应写入配置文件的值
+    ///
+    /// 明文后端直接返回原值，其余后端返回 `secret://ccode/<key>` 引用
+    fn store(&self, key: &str, value: &str) -> AppResult<String>;
+
+    /// 读取密钥
+    fn load(&self, key: &str) -> AppResult<String>;
+
+    /// 删除密钥，密钥不存在时不报错
+    fn delete(&self, key: &str) -> AppResult<()>;
+}
+
+/// 生成密钥引用
+pub fn secret_ref(key: &str) -> String {
+    format!("{SECRET_REF_PREFIX}{key}")
+}
+
+/// 解析密钥引用，返回密钥名；非引用返回 None
+pub fn parse_secret_ref(value: &str) -> Option<&str> {
+    value
+        .strip_prefix(SECRET_REF_PREFIX)
+        .filter(|key| !key.is_empty())
+}
+
+/// 配置值中的间接引用
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretRef<'a> {
+    /// `secret://ccode/<key>`：保存在密钥存储后端中
+    Backend(&'a str),
+    /// `env:VAR`：从环境变量读取
+    Env(&'a str),
+    /// `file:PATH`：从文件读取（支持 `~`）
+    File(&'a str),
+    /// `cmd:COMMAND`：执行命令并读取标准输出
+    Cmd(&'a str),
+}
+
+impl<'a> SecretRef<'a> {
+    /// 解析配置值，普通值返回 None
+    pub fn parse(value: &'a str) -> Option<Self> {
+        let value = value.trim();
+        if let Some(key) = value.strip_prefix(SECRET_REF_PREFIX) {
+            Some(SecretRef::Backend(key))
+        } else if let Some(var) = value.strip_prefix("env:") {
+            Some(SecretRef::Env(var.trim()))
+        } else if let Some(path) = value.strip_prefix("file:") {
+            Some(SecretRef::File(path.trim()))
+        } else {
+            value
+                .strip_prefix("cmd:")
+                .map(|command| SecretRef::Cmd(command.trim()))
+        }
+    }
+
+    /// 是否为由用户自行维护的外部引用（env/file/cmd）
+    pub fn is_external(&self) -> bool {
+        !matches!(self, SecretRef::Backend(_))
+    }
+
+    /// 检查引用格式
+    pub fn validate(&self) -> AppResult<()> {
+        match self {
+            SecretRef::Backend("") => Err(AppError::InvalidConfig(t!("secrets.ref.missing_key"))),
+            SecretRef::Env(var) if !is_valid_env_name(var) => {
+                Err(AppError::InvalidConfig(t!("secrets.ref.invalid_env", var)))
+            }
+            SecretRef::File("") => Err(AppError::InvalidConfig(t!("secrets.ref.missing_path"))),
+            SecretRef::Cmd("") => Err(AppError::InvalidConfig(t!("secrets.ref.missing_command"))),
+            _ => Ok(()),
+        }
+    }
+
+    /// 读取引用指向的值
+    pub fn resolve(&self, backend: &dyn SecretBackend) -> AppResult<String> {
+        self.validate()?;
+        match self {
+            SecretRef::Backend(key) => backend.load(key),
+            SecretRef::Env(var) => std::env::var(var)
+                .ok()
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| AppError::Secret(t!("secrets.env_not_set", var))),
+            SecretRef::File(path) => {
+                let path = expand_home(path);
+                let content = fs::read_to_string(&path).map_err(|e| {
+                    AppError::Secret(t!("secrets.file_read_failed", path = path.display(), e))
+                })?;
+                non_empty(content.trim().to_string(), || {
+                    t!("secrets.file_empty", path = path.display())
+                })
+            }
+            SecretRef::Cmd(command) => run_secret_command(command),
+        }
+    }
+}
+
+/// 检查配置值中的引用格式，普通值直接通过
+pub fn validate_reference(value: &str) -> AppResult<()> {
+    match SecretRef::parse(value) {
+        Some(reference) => reference.validate(),
+        None => Ok(()),
+    }
+}
+
+/// 环境变量名是否有效
+pub fn is_valid_env_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Direct 配置认证令牌的密钥名，例如 `myapi@20250101120000123`
+///
+/// 每次保存令牌都使用新的密钥名，备份中的旧引用始终指向当时的令牌
+pub fn direct_secret_key(profile: &str) -> String {
+    format!("{profile}@{}", Utc::now().format("%Y%m%d%H%M%S%3f"))
+}
+
+/// Provider API 密钥的密钥名
+pub fn provider_secret_key(provider: &str) -> String {
+    format!("providers/{provider}")
+}
+
+/// Provider API 密钥传给 claude-code-router 时使用的环境变量名，例如 `CCODE_DEEPSEEK_API_KEY`
+pub fn provider_env_var(provider: &str) -> String {
+    let name: String = provider
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("CCODE_{name}_API_KEY")
+}
+
+/// claude-code-router 配置中引用 Provider 密钥环境变量的写法，例如 `$CCODE_DEEPSEEK_API_KEY`
+pub fn provider_env_ref(provider: &str) -> String {
+    format!("${}", provider_env_var(provider))
+}
+
+/// 已停用但可能仍被配置备份引用的密钥
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetiredSecret {
+    pub backend: SecretBackendKind,
+    pub key: String,
+}
+
+impl RetiredSecret {
+    /// 是否仍被配置引用
+    ///
+    /// `ccode_configs` 为 ccode 配置（当前配置与备份），`ccr_configs` 为 CCR 配置（当前配置与备份）。
+    /// Direct 令牌需要有使用同一后端的 ccode 配置引用该密钥；Provider 密钥需要有 CCR 配置
+    /// 以 `$CCODE_<NAME>_API_KEY` 引用该 Provider，并且有 ccode 配置使用同一后端。
+    pub fn is_referenced(&self, ccode_configs: &[Value], ccr_configs: &[Value]) -> bool {
+        let uses_backend = |config: &&Value| {
+            config["secret_backend"]
+                .as_str()
+                .and_then(|kind| SecretBackendKind::from_str(kind).ok())
+                .unwrap_or_default()
+                == self.backend
+        };
+
+        match self.key.strip_prefix("providers/") {
+            Some(provider) => {
+                let env_ref = provider_env_ref(provider);
+                ccode_configs.iter().any(|config| uses_backend(&config))
+                    && ccr_configs.iter().any(|config| {
+                        config["Providers"].as_array().is_some_and(|providers| {
+                            providers
+                                .iter()
+                                .any(|p| p["name"] == provider && p["api_key"] == env_ref.as_str())
+                        })
+                    })
+            }
+            None => {
+                let reference = secret_ref(&self.key);
+                ccode_configs.iter().filter(uses_backend).any(|config| {
+                    config["groups"]["direct"]
+                        .as_object()
+                        .is_some_and(|profiles| {
+                            profiles
+                                .values()
+                                .any(|p| p["ANTHROPIC_AUTH_TOKEN"] == reference.as_str())
+                        })
+                })
+            }
+        }
+    }
+}
+
+/// 已停用的密钥列表，保存在 `~/.config/ccode/retired_secrets.json`（只记录密钥名）
+///
+/// 删除、重命名或替换配置时旧密钥先记录在这里，等引用它的配置备份全部清理后再从后端删除，
+/// 使 `ccode undo` 与 `ccode backup restore` 恢复的引用仍然有效
+#[derive(Debug)]
+pub struct RetiredSecrets {
+    path: PathBuf,
+    entries: Vec<RetiredSecret>,
+}
+
+impl RetiredSecrets {
+    /// 默认列表路径 `~/.config/ccode/retired_secrets.json`
+    pub fn default_path() -> AppResult<PathBuf> {
+        let config_dir =
+            dirs::config_dir().ok_or_else(|| AppError::Config(t!("config.no_config_dir")))?;
+        Ok(config_dir.join("ccode").join("retired_secrets.json"))
+    }
+
+    /// 读取默认路径下的列表
+    pub fn load() -> AppResult<Self> {
+        Self::load_from(Self::default_path()?)
+    }
+
+    fn load_from(path: PathBuf) -> AppResult<Self> {
+        let entries = if path.exists() {
+            serde_json::from_str(&fs::read_to_string(&path)?)?
+        } else {
+            Vec::new()
+        };
+        Ok(Self { path, entries })
+    }
+
+    /// 所有已停用的密钥
+    pub fn entries(&self) -> &[RetiredSecret] {
+        &self.entries
+    }
+
+    /// 记录已停用的密钥，重复记录时忽略
+    pub fn retire(&mut self, backend: SecretBackendKind, key: &str) {
+        let secret = RetiredSecret {
+            backend,
+            key: key.to_string(),
+        };
+        if !self.entries.contains(&secret) {
+            self.entries.push(secret);
+        }
+    }
+
+    /// 只保留满足条件的记录
+    pub fn retain(&mut self, keep: impl FnMut(&RetiredSecret) -> bool) {
+        self.entries.retain(keep);
+    }
+
+    /// 保存列表，列表为空时删除文件
+    pub fn save(&self) -> AppResult<()> {
+        if self.entries.is_empty() {
+            if self.path.exists() {
+                fs::remove_file(&self.path)?;
+            }
+            return Ok(());
+        }
+
+        let content = serde_json::to_string_pretty(&self.entries)?;
+        atomic_write(&self.path, content.as_bytes())
+    }
+}
+
+/// 创建指定类型的密钥存储后端
+pub fn open_backend(kind: SecretBackendKind) -> AppResult<Box<dyn SecretBackend>> {
+    Ok(match kind {
+        SecretBackendKind::Plaintext => Box::new(PlaintextBackend),
+        SecretBackendKind::Vault => Box::new(VaultBackend::new(VaultBackend::default_path()?)),
+        SecretBackendKind::Keyring => Box::new(KeyringBackend),
+    })
+}
+
+/// 解析配置值：引用从对应来源读取，其余原样返回
+pub fn resolve(value: &str, backend: &dyn SecretBackend) -> AppResult<String> {
+    match SecretRef::parse(value) {
+        Some(reference) => reference.resolve(backend),
+        None => Ok(value.to_string()),
+    }
+}
+
+/// 展开路径开头的 `~`
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ if path == "~" => dirs::home_dir().unwrap_or_else(|| PathBuf::from(path)),
+        _ => PathBuf::from(path),
+    }
+}
+
+fn non_empty(value: String, message: impl FnOnce() -> String) -> AppResult<String> {
+    if value.is_empty() {
+        Err(AppError::Secret(message()))
+    } else {
+        Ok(value)
+    }
+}
+
+/// 执行命令引用并返回标准输出的第一行
+///
+/// 标准输入与标准错误继承自当前进程，以便 pass、op 等工具进行交互式解锁
+fn run_secret_command(command: &str) -> AppResult<String> {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    };
+
+    let output = cmd
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| AppError::Secret(t!("secrets.cmd_spawn_failed", command, e)))?;
+
+    if !output.status.success() {
+        return Err(AppError::Secret(t!(
+            "secrets.cmd_failed",
+            command,
+            code = format!("{:?}", output.status.code())
+        )));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let value = stdout.lines().next().unwrap_or_default().trim().to_string();
+    non_empty(value, || t!("secrets.cmd_no_output", command))
+}
+
+/// 明文后端：密钥直接保存在配置文件中
+pub struct PlaintextBackend;
+
+impl SecretBackend for PlaintextBackend {
+    fn kind(&self) -> SecretBackendKind {
+        SecretBackendKind::Plaintext
+    }
+
+    fn store(&self, _key: &str, value: &str) -> AppResult<String> {
+        Ok(value.to_string())
+    }
+
+    fn load(&self, key: &str) -> AppResult<String> {
+        Err(AppError::Secret(t!(
+            "secrets.plaintext_cannot_resolve",
+            reference = secret_ref(key)
+        )))
+    }
+
+    fn delete(&self, _key: &str) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+/// 加密密钥库后端：所有密钥以 JSON 形式保存在使用密码加密的 age 文件中
+///
+/// 密码从 `CCODE_VAULT_PASSPHRASE` 读取，未设置时交互式输入
+pub struct VaultBackend {
+    path: PathBuf,
+    passphrase: OnceCell<String>,
+    /// 已解密的密钥库内容，避免同一进程内重复解密
+    cache: RefCell<Option<BTreeMap<String, String>>>,
+    work_factor: Option<u8>,
+}
+
+impl VaultBackend {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            passphrase: OnceCell::new(),
+            cache: RefCell::new(None),
+            work_factor: None,
+        }
+    }
+
+    /// 使用指定密码创建密钥库后端
+    #[cfg(test)]
+    fn with_passphrase(path: PathBuf, passphrase: String) -> Self {
+        let backend = Self::new(path);
+        let _ = backend.passphrase.set(passphrase);
+        backend
+    }
+
+    /// 默认密钥库路径 `~/.config/ccode/secrets.age`
+    pub fn default_path() -> AppResult<PathBuf> {
+        let config_dir =
+            dirs::config_dir().ok_or_else(|| AppError::Config(t!("config.no_config_dir")))?;
+        Ok(config_dir.join("ccode").join("secrets.age"))
+    }
+
+    /// 设置 scrypt 工作因子（默认根据机器性能自动选择）
+    #[cfg(test)]
+    fn with_work_factor(mut self, work_factor: u8) -> Self {
+        self.work_factor = Some(work_factor);
+        self
+    }
+
+    fn passphrase(&self) -> AppResult<SecretString> {
+        if let Some(passphrase) = self.passphrase.get() {
+            return Ok(SecretString::from(passphrase.clone()));
+        }
+
+        let passphrase = match std::env::var(VAULT_PASSPHRASE_ENV) {
+            Ok(passphrase) if !passphrase.is_empty() => passphrase,
+            _ => self.prompt_passphrase()?,
+        };
+        let _ = self.passphrase.set(passphrase.clone());
+        Ok(SecretString::from(passphrase))
+    }
+
+    fn prompt_passphrase(&self) -> AppResult<String> {
+        let passphrase = rpassword::prompt_password(t!("secrets.vault.prompt"))?;
+        if passphrase.is_empty() {
+            return Err(AppError::Secret(t!("secrets.vault.empty_passphrase")));
+        }
+
+        // 首次创建密钥库时确认密码
+        if !self.path.exists() {
+            let confirm = rpassword::prompt_password(t!("secrets.vault.confirm"))?;
+            if confirm != passphrase {
+                return Err(AppError::Secret(t!("secrets.vault.mismatch")));
+            }
+        }
+
+        Ok(passphrase)
+    }
+
+    fn read_all(&self) -> AppResult<BTreeMap<String, String>> {
+        if let Some(secrets) = self.cache.borrow().as_ref() {
+            return Ok(secrets.clone());
+        }
+
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        let encrypted = fs::read(&self.path)?;
+        let identity = age::scrypt::Identity::new(self.passphrase()?);
+        let decrypted = age::decrypt(&identity, &encrypted)
+            .map_err(|e| AppError::Secret(t!("secrets.vault.decrypt_failed", e)))?;
+
+        let secrets: BTreeMap<String, String> = serde_json::from_slice(&decrypted)
+            .map_err(|e| AppError::Secret(t!("secrets.vault.corrupted", e)))?;
+        *self.cache.borrow_mut() = Some(secrets.clone());
+        Ok(secrets)
+    }
+
+    fn write_all(&self, secrets: &BTreeMap<String, String>) -> AppResult<()> {
+        let plaintext = serde_json::to_vec(secrets)?;
+        let mut recipient = age::scrypt::Recipient::new(self.passphrase()?);
+        if let Some(work_factor) = self.work_factor {
+            recipient.set_work_factor(work_factor);
+        }
+        let encrypted = age::encrypt(&recipient, &plaintext)
+            .map_err(|e| AppError::Secret(t!("secrets.vault.encrypt_failed", e)))?;
+
+        write_private(&self.path, &encrypted)?;
+        *self.cache.borrow_mut() = Some(secrets.clone());
+        Ok(())
+    }
+}
+
+impl SecretBackend for VaultBackend {
+    fn kind(&self) -> SecretBackendKind {
+        SecretBackendKind::Vault
+    }
+
+    fn store(&self, key: &str, value: &str) -> AppResult<String> {
+        let mut secrets = self.read_all()?;
+        secrets.insert(key.to_string(), value.to_string());
+        self.write_all(&secrets)?;
+        Ok(secret_ref(key))
+    }
+
+    fn load(&self, key: &str) -> AppResult<String> {
+        self.read_all()?
+            .remove(key)
+            .ok_or_else(|| AppError::Secret(t!("secrets.vault.not_found", key)))
+    }
+
+    fn delete(&self, key: &str) -> AppResult<()> {
+        let mut secrets = self.read_all()?;
+        if secrets.remove(key).is_some() {
+            self.write_all(&secrets)?;
+        }
+        Ok(())
+    }
+}
+
+/// 系统密钥环后端：通过 `secret-tool` 访问 Secret Service（GNOME Keyring、KWallet 等）
+pub struct KeyringBackend;
+
+impl KeyringBackend {
+    /// 密钥环中的 service 属性
+    const SERVICE: &'static str = "ccode";
+
+    fn secret_tool(args: &[&str], input: Option<&str>) -> AppResult<String> {
+        let mut child = Command::new("secret-tool")
+            .args(args)
+            .stdin(if input.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| {
+                if e.kind() == io::ErrorKind::NotFound {
+                    AppError::Secret(t!("secrets.keyring.not_installed"))
+                } else {
+                    AppError::Secret(t!("secrets.keyring.spawn_failed", e))
+                }
+            })?;
+
+        if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+            stdin.write_all(input.as_bytes())?;
+        }
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(AppError::Secret(t!(
+                "secrets.keyring.failed",
+                stderr = stderr.trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+impl SecretBackend for KeyringBackend {
+    fn kind(&self) -> SecretBackendKind {
+        SecretBackendKind::Keyring
+    }
+
+    fn store(&self, key: &str, value: &str) -> AppResult<String> {
+        let label = format!("ccode: {key}");
+        Self::secret_tool(
+            &[
+                "store",
+                "--label",
+                &label,
+                "service",
+                Self::SERVICE,
+                "account",
+                key,
+            ],
+            Some(value),
+        )?;
+        Ok(secret_ref(key))
+    }
+
+    fn load(&self, key: &str) -> AppResult<String> {
+        // 密钥不存在时 secret-tool 以非零状态退出且没有输出
+        let value = Self::secret_tool(&["lookup", "service", Self::SERVICE, "account", key], None)
+            .ok()
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| AppError::Secret(t!("secrets.keyring.not_found", key)))?;
+        Ok(value.trim_end_matches('\n').to_string())
+    }
+
+    fn delete(&self, key: &str) -> AppResult<()> {
+        // 密钥不存在时忽略错误
+        let _ = Self::secret_tool(&["clear", "service", Self::SERVICE, "account", key], None);
+        Ok(())
+    }
+}
+
+/// 原子写入仅当前用户可读写的文件
+fn write_private(path: &Path, content: &[u8]) -> AppResult<()> {
+    if let Some(dir) = path.parent()
+        && !dir.exists()
+    {
+        fs::create_dir_all(dir)?;
+    }
+
+    atomic_write_with(path, |file| {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600))?;
+        }
+        file.write_all(content)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_refs() {
+        assert_eq!(secret_ref("myapi"), "secret://ccode/myapi");
+        assert_eq!(parse_secret_ref("secret://ccode/myapi"), Some("myapi"));
+        assert_eq!(
+            parse_secret_ref("secret://ccode/providers/deepseek"),
+            Some("providers/deepseek")
+        );
+        assert_eq!(parse_secret_ref("secret://ccode/"), None);
+        assert_eq!(parse_secret_ref("sk-ant-123"), None);
+
+        assert_eq!(
+            provider_env_var("open-router.v2"),
+            "CCODE_OPEN_ROUTER_V2_API_KEY"
+        );
+        assert_eq!(provider_env_ref("deepseek"), "$CCODE_DEEPSEEK_API_KEY");
+
+        assert_eq!(
+            "Vault".parse::<SecretBackendKind>().unwrap(),
+            SecretBackendKind::Vault
+        );
+        assert!("kms".parse::<SecretBackendKind>().is_err());
+
+        let key = direct_secret_key("myapi");
+        assert!(key.starts_with("myapi@"));
+        assert_ne!(parse_secret_ref(&secret_ref(&key)), Some("myapi"));
+    }
+
+    #[test]
+    fn test_retired_secrets() {
+        use serde_json::json;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("retired_secrets.json");
+        let mut retired = RetiredSecrets::load_from(path.clone()).unwrap();
+        retired.retire(SecretBackendKind::Vault, "work@20250101120000000");
+        retired.retire(SecretBackendKind::Vault, "work@20250101120000000");
+        retired.retire(SecretBackendKind::Keyring, "providers/deepseek");
+        retired.save().unwrap();
+
+        let mut reloaded = RetiredSecrets::load_from(path.clone()).unwrap();
+        assert_eq!(reloaded.entries().len(), 2);
+        let (direct, provider) = (&reloaded.entries()[0], &reloaded.entries()[1]);
+
+        let vault_config = json!({
+            "secret_backend": "vault",
+            "groups": {"direct": {"work": {
+                "ANTHROPIC_AUTH_TOKEN": "secret://ccode/work@20250101120000000"
+            }}}
+        });
+        let plaintext_config = json!({
+            "groups": {"direct": {"work": {
+                "ANTHROPIC_AUTH_TOKEN": "secret://ccode/work@20250101120000000"
+            }}}
+        });
+        assert!(direct.is_referenced(std::slice::from_ref(&vault_config), &[]));
+        assert!(!direct.is_referenced(&[plaintext_config], &[]));
+        assert!(!direct.is_referenced(&[json!({"secret_backend": "vault"})], &[]));
+
+        let ccr_config = json!({"Providers": [
+            {"name": "deepseek", "api_key": "$CCODE_DEEPSEEK_API_KEY"}
+        ]});
+        let keyring_config = json!({"secret_backend": "keyring"});
+        assert!(provider.is_referenced(
+            std::slice::from_ref(&keyring_config),
+            std::slice::from_ref(&ccr_config),
+        ));
+        assert!(!provider.is_referenced(&[vault_config], &[ccr_config]));
+        assert!(!provider.is_referenced(
+            &[keyring_config],
+            &[json!({"Providers": [{"name": "deepseek", "api_key": "sk-test"}]})]
+        ));
+
+        reloaded.retain(|_| false);
+        reloaded.save().unwrap();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_parse_references() {
+        assert_eq!(
+            SecretRef::parse("env:MY_KEY"),
+            Some(SecretRef::Env("MY_KEY"))
+        );
+        assert_eq!(
+            SecretRef::parse("file:~/.keys/ds"),
+            Some(SecretRef::File("~/.keys/ds"))
+        );
+        assert_eq!(
+            SecretRef::parse("cmd:pass show deepseek"),
+            Some(SecretRef::Cmd("pass show deepseek"))
+        );
+        assert_eq!(
+            SecretRef::parse("secret://ccode/work"),
+            Some(SecretRef::Backend("work"))
+        );
+        assert_eq!(SecretRef::parse("sk-ant-123"), None);
+
+        assert!(validate_reference("env:MY_KEY").is_ok());
+        assert!(validate_reference("env:1BAD").is_err());
+        assert!(validate_reference("env:MY-KEY").is_err());
+        assert!(validate_reference("file:").is_err());
+        assert!(validate_reference("cmd: ").is_err());
+        assert!(validate_reference("secret://ccode/").is_err());
+        assert!(validate_reference("sk-plain").is_ok());
+    }
+
+    #[test]
+    fn test_resolve_external_references() {
+        let backend = PlaintextBackend;
+        let dir = tempfile::tempdir().unwrap();
+        let key_file = dir.path().join("key");
+        fs::write(&key_file, "sk-from-file\n").unwrap();
+
+        let file_ref = format!("file:{}", key_file.display());
+        assert_eq!(resolve(&file_ref, &backend).unwrap(), "sk-from-file");
+        assert!(resolve("file:/nonexistent/ccode-key", &backend).is_err());
+
+        // 错误信息随界面语言变化，只检查错误类型和变量名
+        match resolve("env:CCODE_TEST_UNSET_SECRET_VAR", &backend) {
+            Err(AppError::Secret(msg)) => assert!(msg.contains("CCODE_TEST_UNSET_SECRET_VAR")),
+            other => panic!("应返回密钥存储错误: {other:?}"),
+        }
+
+        #[cfg(unix)]
+        {
+            assert_eq!(
+                resolve("cmd:echo sk-from-cmd", &backend).unwrap(),
+                "sk-from-cmd"
+            );
+            assert!(resolve("cmd:exit 3", &backend).is_err());
+            assert!(resolve("cmd:true", &backend).is_err());
+        }
+    }
+
+    #[test]
+    fn test_plaintext_backend() {
+        let backend = PlaintextBackend;
+        assert_eq!(backend.store("work", "sk-123").unwrap(), "sk-123");
+        assert_eq!(resolve("sk-123", &backend).unwrap(), "sk-123");
+        assert!(resolve("secret://ccode/work", &backend).is_err());
+    }
+
+    #[test]
+    fn test_vault_backend_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secrets.age");
+        let vault = VaultBackend::with_passphrase(path.clone(), "correct horse".to_string())
+            .with_work_factor(2);
+
+        let reference = vault.store("work", "sk-ant-secret").unwrap();
+        assert_eq!(reference, "secret://ccode/work");
+        vault.store("providers/deepseek", "sk-ds").unwrap();
+
+        // 密钥库文件中不包含明文
+        let raw = fs::read(&path).unwrap();
+        assert!(!String::from_utf8_lossy(&raw).contains("sk-ant-secret"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(
+                fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+                0o600
+            );
+        }
+
+        let reopened = VaultBackend::with_passphrase(path.clone(), "correct horse".to_string());
+        assert_eq!(resolve(&reference, &reopened).unwrap(), "sk-ant-secret");
+        assert_eq!(reopened.load("providers/deepseek").unwrap(), "sk-ds");
+
+        vault.delete("work").unwrap();
+        assert!(vault.load("work").is_err());
+        let reopened = VaultBackend::with_passphrase(path.clone(), "correct horse".to_string());
+        assert!(reopened.load("work").is_err());
+        assert_eq!(reopened.load("providers/deepseek").unwrap(), "sk-ds");
+
+        let wrong = VaultBackend::with_passphrase(path, "wrong".to_string());
+        assert!(wrong.load("providers/deepseek").is_err());
+    }
+}
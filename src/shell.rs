@@ -0,0 +1,135 @@
+//! 生成设置/清除环境变量的 shell 语句
+//!
+//! `ccode env <name> --shell <SHELL>` 输出的语句供 `eval` 在当前 shell 中执行，
+//! 值一律按各 shell 的字面量规则转义，不会被展开或执行。
+
+use clap::ValueEnum;
+use std::path::Path;
+
+/// 支持的 shell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    #[value(alias = "pwsh")]
+    Powershell,
+    #[value(alias = "nu")]
+    Nushell,
+}
+
+impl Shell {
+    /// 未指定 `--shell` 时根据 `$SHELL` 判断，无法判断时 Windows 上为 PowerShell，其他平台为 bash
+    pub fn detect() -> Shell {
+        std::env::var("SHELL")
+            .ok()
+            .and_then(|path| Shell::from_program(&path))
+            .unwrap_or(if cfg!(windows) {
+                Shell::Powershell
+            } else {
+                Shell::Bash
+            })
+    }
+
+    /// 根据 shell 程序路径判断类型
+    fn from_program(path: &str) -> Option<Shell> {
+        let name = Path::new(path).file_stem()?.to_str()?;
+        match name {
+            "bash" | "sh" | "dash" | "ksh" => Some(Shell::Bash),
+            "zsh" => Some(Shell::Zsh),
+            "fish" => Some(Shell::Fish),
+            "pwsh" | "powershell" => Some(Shell::Powershell),
+            "nu" => Some(Shell::Nushell),
+            _ => None,
+        }
+    }
+
+    /// 设置环境变量的语句
+    pub fn export(self, name: &str, value: &str) -> String {
+        match self {
+            Shell::Bash | Shell::Zsh => format!("export {name}={}", posix_quote(value)),
+            Shell::Fish => format!("set -gx {name} {}", fish_quote(value)),
+            Shell::Powershell => format!("$env:{name} = {}", powershell_quote(value)),
+            Shell::Nushell => format!("$env.{name} = {}", nushell_quote(value)),
+        }
+    }
+
+    /// 清除环境变量的语句（变量不存在时不报错）
+    pub fn unset(self, name: &str) -> String {
+        match self {
+            Shell::Bash | Shell::Zsh => format!("unset {name}"),
+            Shell::Fish => format!("set -e {name}"),
+            Shell::Powershell => {
+                format!("Remove-Item Env:{name} -ErrorAction SilentlyContinue")
+            }
+            Shell::Nushell => format!("hide-env -i {name}"),
+        }
+    }
+}
+
+/// 单引号字符串，内部的单引号写作 `'\''`
+fn posix_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// fish 单引号字符串只识别 `\\` 和 `\'` 两种转义
+fn fish_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', r"\\").replace('\'', r"\'"))
+}
+
+/// PowerShell 单引号字符串，内部的单引号写两次
+fn powershell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// nushell 单引号字符串不支持转义，统一使用双引号字符串
+fn nushell_quote(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_statements() {
+        let value = r"it's $HOME \n";
+        assert_eq!(
+            Shell::Bash.export("A", value),
+            r"export A='it'\''s $HOME \n'"
+        );
+        assert_eq!(
+            Shell::Fish.export("A", value),
+            r"set -gx A 'it\'s $HOME \\n'"
+        );
+        assert_eq!(
+            Shell::Powershell.export("A", value),
+            "$env:A = 'it''s $HOME \\n'"
+        );
+        assert_eq!(
+            Shell::Nushell.export("A", "say \"hi\"\n"),
+            r#"$env.A = "say \"hi\"\n""#
+        );
+        assert_eq!(Shell::Zsh.unset("A"), "unset A");
+        assert_eq!(Shell::Nushell.unset("A"), "hide-env -i A");
+
+        assert_eq!(Shell::from_program("/usr/bin/fish"), Some(Shell::Fish));
+        assert_eq!(
+            Shell::from_program("/opt/homebrew/bin/nu"),
+            Some(Shell::Nushell)
+        );
+        assert_eq!(Shell::from_program("/usr/bin/xonsh"), None);
+    }
+}